@@ -0,0 +1,125 @@
+/*!
+ * Per-hop cost of detectors
+ *
+ * Each iteration processes single hop of synthetic signal, so the results
+ * are directly comparable with the duration of hop at given sampling rate.
+ *
+ * Usage: cargo bench --bench detectors
+ */
+
+use aubio::{Onset, OnsetMode, PVoc, Pitch, PitchMode, Smpl, Tempo, MFCC};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::f64::consts::PI;
+
+const SAMPLE_RATE: u32 = 44100;
+const SIZES: [usize; 3] = [512, 1024, 2048];
+
+/**
+ * Generate the mixture of tones with periodic clicks
+ */
+fn signal(length: usize) -> Vec<Smpl> {
+    (0..length)
+        .map(|index| {
+            let time = index as f64 / SAMPLE_RATE as f64;
+            let tone =
+                0.3 * (2.0 * PI * 440.0 * time).sin() + 0.2 * (2.0 * PI * 660.0 * time).sin();
+            let click = if index % 11025 < 64 { 0.5 } else { 0.0 };
+            (tone + click) as Smpl
+        })
+        .collect()
+}
+
+/**
+ * Benchmark processing of hops by detector for each buffer size
+ */
+fn bench_hops<D, C, F>(criterion: &mut Criterion, name: &str, create: C, mut process: F)
+where
+    C: Fn(usize, usize) -> D,
+    F: FnMut(&mut D, &[Smpl]),
+{
+    let mut group = criterion.benchmark_group(name);
+
+    for &buf_size in SIZES.iter() {
+        let hop_size = buf_size / 2;
+        let input = signal(SAMPLE_RATE as usize);
+        let mut detector = create(buf_size, hop_size);
+        let mut hops = input.chunks_exact(hop_size).cycle();
+
+        group.throughput(Throughput::Elements(hop_size as u64));
+        group.bench_function(BenchmarkId::from_parameter(buf_size), |bencher| {
+            bencher.iter(|| process(&mut detector, black_box(hops.next().unwrap())))
+        });
+    }
+
+    group.finish();
+}
+
+fn onset(criterion: &mut Criterion) {
+    for &mode in [OnsetMode::Hfc, OnsetMode::SpecFlux].iter() {
+        bench_hops(
+            criterion,
+            &format!("onset/{}", mode.as_ref()),
+            |buf_size, hop_size| Onset::new(mode, buf_size, hop_size, SAMPLE_RATE).unwrap(),
+            |onset, hop| {
+                black_box(onset.do_result(hop).unwrap());
+            },
+        );
+    }
+}
+
+fn pitch(criterion: &mut Criterion) {
+    bench_hops(
+        criterion,
+        "pitch/yinfft",
+        |buf_size, hop_size| {
+            Pitch::new(PitchMode::Yinfft, buf_size, hop_size, SAMPLE_RATE).unwrap()
+        },
+        |pitch, hop| {
+            black_box(pitch.do_result(hop).unwrap());
+        },
+    );
+}
+
+fn tempo(criterion: &mut Criterion) {
+    bench_hops(
+        criterion,
+        "tempo/specflux",
+        |buf_size, hop_size| {
+            Tempo::new(OnsetMode::SpecFlux, buf_size, hop_size, SAMPLE_RATE).unwrap()
+        },
+        |tempo, hop| {
+            black_box(tempo.do_result(hop).unwrap());
+        },
+    );
+}
+
+fn mfcc(criterion: &mut Criterion) {
+    struct State {
+        pvoc: PVoc,
+        mfcc: MFCC,
+        grain: Vec<Smpl>,
+        coeffs: Vec<Smpl>,
+    }
+
+    bench_hops(
+        criterion,
+        "mfcc/40x13",
+        |buf_size, hop_size| State {
+            pvoc: PVoc::new(buf_size, hop_size).unwrap(),
+            mfcc: MFCC::new(buf_size, 40, 13, SAMPLE_RATE).unwrap(),
+            grain: vec![0.0; buf_size + 2],
+            coeffs: vec![0.0; 13],
+        },
+        |state, hop| {
+            state.pvoc.do_(hop, state.grain.as_mut_slice()).unwrap();
+            state
+                .mfcc
+                .do_(state.grain.as_slice(), state.coeffs.as_mut_slice())
+                .unwrap();
+            black_box(&state.coeffs);
+        },
+    );
+}
+
+criterion_group!(detectors, onset, pitch, tempo, mfcc);
+criterion_main!(detectors);
@@ -0,0 +1,69 @@
+/*!
+ * Overhead of conversions between Rust slices and _aubio_ vectors
+ *
+ * The wrapping of slices should not depend on size of buffer,
+ * the owned buffers are measured to compare with copying.
+ *
+ * Usage: cargo bench --bench vec
+ */
+
+use aubio::{
+    vec::{CVec, FMatBuf, FVec, FVecBuf, FVecMut},
+    Smpl,
+};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+const SIZES: [usize; 4] = [64, 512, 4096, 32768];
+
+fn wrap(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("wrap");
+
+    for &size in SIZES.iter() {
+        let mut data = vec![0.5 as Smpl; size];
+
+        group.bench_function(BenchmarkId::new("fvec", size), |bencher| {
+            bencher.iter(|| FVec::from(black_box(data.as_slice())).size())
+        });
+
+        group.bench_function(BenchmarkId::new("fvec_mut", size), |bencher| {
+            bencher.iter(|| FVecMut::from(black_box(data.as_mut_slice())).size())
+        });
+
+        group.bench_function(BenchmarkId::new("cvec", size), |bencher| {
+            bencher.iter(|| {
+                let (norm, phas) = data.split_at(size / 2);
+                black_box(CVec::from_parts(black_box(norm), black_box(phas)).unwrap());
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn copy(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("copy");
+
+    for &size in SIZES.iter() {
+        let data = vec![0.5 as Smpl; size];
+        let ints = vec![0x4000i16; size];
+
+        group.throughput(Throughput::Elements(size as u64));
+
+        group.bench_function(BenchmarkId::new("fvec_buf_collect", size), |bencher| {
+            bencher.iter(|| black_box(data.iter().collect::<FVecBuf>()))
+        });
+
+        group.bench_function(BenchmarkId::new("fvec_buf_from_i16", size), |bencher| {
+            bencher.iter(|| black_box(FVecBuf::from_i16(black_box(&ints))))
+        });
+
+        group.bench_function(BenchmarkId::new("fmat_buf_from_vec", size), |bencher| {
+            bencher.iter(|| black_box(FMatBuf::from_vec(size / 64, 64, data.clone()).unwrap()))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(vec, wrap, copy);
+criterion_main!(vec);
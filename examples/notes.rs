@@ -8,15 +8,13 @@
  * Only signed 16-bit mono wav files supported.
  */
 
-use aubio::{Notes, Smpl};
+use aubio::{vec::FVecBuf, Notes, Smpl};
 use hound::WavReader;
 use std::io::stdin;
 
 const BUF_SIZE: usize = 512;
 const HOP_SIZE: usize = 256;
 
-const I16_TO_SMPL: Smpl = 1.0 / (1 << 16) as Smpl;
-
 fn main() {
     let input = stdin();
     let mut reader = WavReader::new(input).unwrap();
@@ -33,13 +31,12 @@ fn main() {
     loop {
         let block = samples
             .by_ref()
-            .map(|sample| sample.map(|sample: i16| sample as Smpl * I16_TO_SMPL))
             .take(HOP_SIZE)
-            .collect::<Result<Vec<Smpl>, _>>()
+            .collect::<Result<Vec<i16>, _>>()
             .unwrap();
 
         if block.len() == HOP_SIZE {
-            for note in notes.do_result(block.as_slice().as_ref()).unwrap() {
+            for note in notes.do_result(&FVecBuf::from_i16(&block)).unwrap() {
                 if note.velocity > 0.0 {
                     print!("{}\t{}\t", note.pitch, time);
                 } else {
@@ -0,0 +1,179 @@
+use crate::{vec::FMatBuf, Smpl};
+
+use core::ops::Range;
+
+use alloc::{vec, vec::Vec};
+
+/**
+ * Statistics of features over segment of frames
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeatureStats {
+    /// Range of frames (rows of feature matrix) of segment
+    pub frames: Range<usize>,
+    /// Mean of each feature
+    pub mean: Vec<Smpl>,
+    /// Variance of each feature
+    pub variance: Vec<Smpl>,
+    /// Minimum of each feature
+    pub min: Vec<Smpl>,
+    /// Maximum of each feature
+    pub max: Vec<Smpl>,
+}
+
+impl FeatureStats {
+    /**
+     * Compute statistics over range of frames of feature matrix
+     *
+     * - `features` Feature matrix which has a row for each frame (see `Pipeline::process()`)
+     * - `frames` Range of rows, should not be empty
+     *
+     * Returns `None` when the range is empty or out of matrix.
+     */
+    pub fn new(features: &FMatBuf, frames: Range<usize>) -> Option<Self> {
+        if frames.start >= frames.end || frames.end > features.height() {
+            return None;
+        }
+
+        let size = features.length();
+        let count = frames.len() as Smpl;
+        let rows = || frames.clone().map(|index| features.row(index).unwrap());
+
+        let mut mean = vec![0.; size];
+        let mut min = vec![Smpl::INFINITY; size];
+        let mut max = vec![Smpl::NEG_INFINITY; size];
+
+        for row in rows() {
+            for (index, &value) in row.iter().enumerate() {
+                mean[index] += value;
+                min[index] = min[index].min(value);
+                max[index] = max[index].max(value);
+            }
+        }
+
+        for value in &mut mean {
+            *value /= count;
+        }
+
+        let mut variance = vec![0.; size];
+
+        for row in rows() {
+            for (index, &value) in row.iter().enumerate() {
+                let delta = value - mean[index];
+                variance[index] += delta * delta;
+            }
+        }
+
+        for value in &mut variance {
+            *value /= count;
+        }
+
+        Some(Self {
+            frames,
+            mean,
+            variance,
+            min,
+            max,
+        })
+    }
+
+    /**
+     * Get statistics as single vector of `mean`, `variance`, `min` and `max` values
+     */
+    pub fn to_vec(&self) -> Vec<Smpl> {
+        let mut data = Vec::with_capacity(self.mean.len() * 4);
+        data.extend_from_slice(&self.mean);
+        data.extend_from_slice(&self.variance);
+        data.extend_from_slice(&self.min);
+        data.extend_from_slice(&self.max);
+        data
+    }
+}
+
+/**
+ * Compute feature statistics over fixed windows
+ *
+ * - `features` Feature matrix which has a row for each frame
+ * - `window` Number of frames in window
+ * - `hop` Number of frames between the starts of windows
+ *
+ * The last window may be shorter. Returns nothing when `window` or `hop` is zero.
+ */
+pub fn aggregate_windows(features: &FMatBuf, window: usize, hop: usize) -> Vec<FeatureStats> {
+    if window == 0 || hop == 0 {
+        return Vec::new();
+    }
+
+    (0..features.height())
+        .step_by(hop)
+        .filter_map(|start| {
+            let end = (start + window).min(features.height());
+            FeatureStats::new(features, start..end)
+        })
+        .collect()
+}
+
+/**
+ * Compute feature statistics between boundaries
+ *
+ * - `features` Feature matrix which has a row for each frame
+ * - `boundaries` Ascending frame indexes of segment starts (like onsets divided by hop size)
+ *
+ * The frames before the first boundary form a segment too. The empty segments are skipped.
+ */
+pub fn aggregate_segments(features: &FMatBuf, boundaries: &[usize]) -> Vec<FeatureStats> {
+    let height = features.height();
+
+    core::iter::once(0)
+        .chain(boundaries.iter().map(|&frame| frame.min(height)))
+        .zip(
+            boundaries
+                .iter()
+                .map(|&frame| frame.min(height))
+                .chain(core::iter::once(height)),
+        )
+        .filter_map(|(start, end)| FeatureStats::new(features, start..end))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{vec::FMatBuf, *};
+
+    fn features() -> FMatBuf {
+        FMatBuf::from_vec(4, 2, vec![1.0, 10.0, 3.0, 20.0, 5.0, 30.0, 7.0, 40.0]).unwrap()
+    }
+
+    #[test]
+    fn test_feature_stats() {
+        let stats = FeatureStats::new(&features(), 0..4).unwrap();
+
+        assert_eq!(stats.mean, vec![4.0, 25.0]);
+        assert_eq!(stats.variance, vec![5.0, 125.0]);
+        assert_eq!(stats.min, vec![1.0, 10.0]);
+        assert_eq!(stats.max, vec![7.0, 40.0]);
+        assert_eq!(stats.to_vec().len(), 8);
+
+        assert!(FeatureStats::new(&features(), 2..2).is_none());
+        assert!(FeatureStats::new(&features(), 2..5).is_none());
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let windows = aggregate_windows(&features(), 3, 2);
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[0].frames, 0..3);
+        assert_eq!(windows[1].frames, 2..4);
+        assert_eq!(windows[1].mean, vec![6.0, 35.0]);
+
+        let segments = aggregate_segments(&features(), &[0, 1, 3, 8]);
+        assert_eq!(
+            segments
+                .iter()
+                .map(|stats| stats.frames.clone())
+                .collect::<Vec<_>>(),
+            vec![0..1, 1..3, 3..4]
+        );
+    }
+}
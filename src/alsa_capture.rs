@@ -0,0 +1,299 @@
+use crate::{vec::downmix_into, Analyzer, Smpl, StreamingAnalyzer};
+
+use alsa::{
+    pcm::{Access, Format, HwParams, PCM},
+    Direction, ValueOr,
+};
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use alloc::{vec, vec::Vec};
+
+/**
+ * ALSA capture error
+ */
+#[derive(Debug)]
+pub enum AlsaError {
+    /**
+     * Error reported by ALSA
+     */
+    Alsa(alsa::Error),
+
+    /**
+     * Failed to analyze samples
+     */
+    Analyzer(crate::Error),
+}
+
+impl StdError for AlsaError {}
+
+impl Display for AlsaError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        use self::AlsaError::*;
+        match self {
+            Alsa(error) => write!(f, "ALSA error: {}", error),
+            Analyzer(error) => write!(f, "analyzer error: {}", error),
+        }
+    }
+}
+
+impl From<alsa::Error> for AlsaError {
+    fn from(error: alsa::Error) -> Self {
+        AlsaError::Alsa(error)
+    }
+}
+
+impl From<crate::Error> for AlsaError {
+    fn from(error: crate::Error) -> Self {
+        AlsaError::Analyzer(error)
+    }
+}
+
+/**
+ * Sample format of ALSA capture
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlsaFormat {
+    /**
+     * Signed 16-bit integer in native byte order
+     */
+    #[default]
+    S16,
+
+    /**
+     * Signed 32-bit integer in native byte order
+     */
+    S32,
+}
+
+impl AlsaFormat {
+    fn to_alsa(self) -> Format {
+        match self {
+            AlsaFormat::S16 => Format::s16(),
+            AlsaFormat::S32 => Format::s32(),
+        }
+    }
+}
+
+/**
+ * Direct audio capture using _ALSA_
+ *
+ * Minimal blocking capture for headless Linux systems: opens the capture PCM
+ * with interleaved access, reads periods of integer frames, converts and
+ * down-mixes it to mono `Smpl` samples. No audio server, worker threads or
+ * channels are involved, so the caller drives the analysis from its own loop.
+ *
+ * The hardware may not support the requested parameters exactly, so use
+ * `AlsaCapture::get_samplerate()` to create the analyzer.
+
+```no_run
+use aubio::{AlsaCapture, AlsaFormat, Onset, OnsetMode, StreamingAnalyzer};
+
+let mut capture = AlsaCapture::open("default", 44100, 1, AlsaFormat::S16, 512).unwrap();
+let onset = Onset::new(OnsetMode::Hfc, 1024, 512, capture.get_samplerate()).unwrap();
+let mut analyzer = StreamingAnalyzer::new(onset);
+
+loop {
+    capture.analyze(&mut analyzer, |position, value| {
+        if value > 0.0 {
+            println!("onset at {}", position);
+        }
+    }).unwrap();
+}
+```
+ */
+pub struct AlsaCapture {
+    pcm: PCM,
+    format: AlsaFormat,
+    sample_rate: u32,
+    channels: usize,
+    period_size: usize,
+    buffer_s16: Vec<i16>,
+    buffer_s32: Vec<i32>,
+    samples: Vec<Smpl>,
+    mono: Vec<Smpl>,
+}
+
+impl AlsaCapture {
+    /**
+     * Open capture device and start capture
+     *
+     * - `device` Name of PCM device (like `default` or `hw:0,0`)
+     * - `sample_rate` Requested sampling rate
+     * - `channels` Requested number of channels
+     * - `format` Sample format
+     * - `period_size` Requested number of frames per read
+     */
+    pub fn open(
+        device: &str,
+        sample_rate: u32,
+        channels: u32,
+        format: AlsaFormat,
+        period_size: usize,
+    ) -> Result<Self, AlsaError> {
+        let pcm = PCM::new(device, Direction::Capture, false)?;
+
+        let (sample_rate, channels, period_size) = {
+            let params = HwParams::any(&pcm)?;
+            params.set_access(Access::RWInterleaved)?;
+            params.set_format(format.to_alsa())?;
+            params.set_channels(channels)?;
+            params.set_rate(sample_rate, ValueOr::Nearest)?;
+            params.set_period_size_near(period_size as _, ValueOr::Nearest)?;
+            pcm.hw_params(&params)?;
+
+            (
+                params.get_rate()?,
+                params.get_channels()? as usize,
+                params.get_period_size()? as usize,
+            )
+        };
+
+        pcm.start()?;
+
+        let length = period_size * channels;
+
+        Ok(Self {
+            pcm,
+            format,
+            sample_rate,
+            channels,
+            period_size,
+            buffer_s16: if format == AlsaFormat::S16 {
+                vec![0; length]
+            } else {
+                Vec::new()
+            },
+            buffer_s32: if format == AlsaFormat::S32 {
+                vec![0; length]
+            } else {
+                Vec::new()
+            },
+            samples: Vec::with_capacity(length),
+            mono: Vec::with_capacity(period_size),
+        })
+    }
+
+    /**
+     * Get actual sampling rate
+     */
+    pub fn get_samplerate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /**
+     * Get actual number of channels
+     */
+    pub fn get_channels(&self) -> usize {
+        self.channels
+    }
+
+    /**
+     * Get actual number of frames per read
+     */
+    pub fn get_period_size(&self) -> usize {
+        self.period_size
+    }
+
+    /**
+     * Get sample format
+     */
+    pub fn get_format(&self) -> AlsaFormat {
+        self.format
+    }
+
+    /**
+     * Read next period of down-mixed samples
+     *
+     * Blocks until the samples is available. After overrun the capture is
+     * recovered and the empty slice is returned.
+     */
+    pub fn read(&mut self) -> Result<&[Smpl], AlsaError> {
+        let frames = match self.format {
+            AlsaFormat::S16 => self.pcm.io_i16()?.readi(&mut self.buffer_s16),
+            AlsaFormat::S32 => self.pcm.io_i32()?.readi(&mut self.buffer_s32),
+        };
+
+        let frames = match frames {
+            Ok(frames) => frames,
+            Err(error) => {
+                self.pcm.try_recover(error, true)?;
+                self.pcm.start()?;
+                0
+            }
+        };
+
+        let length = frames * self.channels;
+
+        self.samples.clear();
+        match self.format {
+            AlsaFormat::S16 => to_smpl(&self.buffer_s16[..length], &mut self.samples),
+            AlsaFormat::S32 => to_smpl(&self.buffer_s32[..length], &mut self.samples),
+        }
+
+        self.mono.resize(frames, 0.0);
+        downmix_into(&self.samples, self.channels, &mut self.mono)?;
+
+        Ok(&self.mono)
+    }
+
+    /**
+     * Read next period and feed it to analyzer
+     *
+     * - `analyzer` Analyzer to process the samples
+     * - `callback` Function which receives the position of frame and result of analysis
+     */
+    pub fn analyze<A, F>(
+        &mut self,
+        analyzer: &mut StreamingAnalyzer<A>,
+        callback: F,
+    ) -> Result<(), AlsaError>
+    where
+        A: Analyzer,
+        F: FnMut(usize, A::Output),
+    {
+        analyzer.push_with_position(self.read()?, callback)?;
+
+        Ok(())
+    }
+}
+
+/**
+ * Integer sample which can be converted to `Smpl`
+ */
+trait IntSample: Copy {
+    fn to_smpl(self) -> Smpl;
+}
+
+impl IntSample for i16 {
+    fn to_smpl(self) -> Smpl {
+        self as Smpl / 32768.0
+    }
+}
+
+impl IntSample for i32 {
+    fn to_smpl(self) -> Smpl {
+        self as Smpl / 2147483648.0
+    }
+}
+
+fn to_smpl<S: IntSample>(data: &[S], output: &mut Vec<Smpl>) {
+    output.extend(data.iter().map(|sample| sample.to_smpl()));
+}
+
+#[cfg(test)]
+mod test {
+    use super::to_smpl;
+
+    #[test]
+    fn test_to_smpl() {
+        let mut output = Vec::new();
+
+        to_smpl(&[16384i16, 0, -32768], &mut output);
+        to_smpl(&[1073741824i32, -1073741824], &mut output);
+        assert_eq!(output, vec![0.5, 0.0, -1.0, 0.5, -0.5]);
+    }
+}
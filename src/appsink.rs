@@ -0,0 +1,234 @@
+use crate::{vec::downmix_into, Analyzer, Error, Smpl, StreamingAnalyzer};
+
+use gstreamer::{glib::BoolError, FlowError, FlowSuccess, Sample};
+use gstreamer_app::{AppSink, AppSinkCallbacks};
+use gstreamer_audio::{AudioCapsBuilder, AudioInfo, AudioLayout};
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "double"))]
+const AUDIO_FORMAT: gstreamer_audio::AudioFormat = gstreamer_audio::AUDIO_FORMAT_F32;
+
+#[cfg(feature = "double")]
+const AUDIO_FORMAT: gstreamer_audio::AudioFormat = gstreamer_audio::AUDIO_FORMAT_F64;
+
+/**
+ * GStreamer sample processing error
+ */
+#[derive(Debug)]
+pub enum AppSinkError {
+    /**
+     * Sample has no caps or caps is not raw audio
+     */
+    Caps(Option<BoolError>),
+
+    /**
+     * Sample has no buffer or buffer is not readable
+     */
+    Buffer(Option<BoolError>),
+
+    /**
+     * Failed to create analyzer or to analyze samples
+     */
+    Analyzer(Error),
+}
+
+impl StdError for AppSinkError {}
+
+impl Display for AppSinkError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        use self::AppSinkError::*;
+        match self {
+            Caps(Some(error)) => write!(f, "invalid caps: {}", error),
+            Caps(None) => "missing caps".fmt(f),
+            Buffer(Some(error)) => write!(f, "invalid buffer: {}", error),
+            Buffer(None) => "missing buffer".fmt(f),
+            Analyzer(error) => write!(f, "analyzer error: {}", error),
+        }
+    }
+}
+
+impl From<Error> for AppSinkError {
+    fn from(error: Error) -> Self {
+        AppSinkError::Analyzer(error)
+    }
+}
+
+/**
+ * Analyzer of samples pulled from _GStreamer_ `appsink`
+ *
+ * Maps the caps of each sample to sampling rate and number of channels,
+ * down-mixes interleaved audio and feeds the analyzer. The analyzer is created
+ * by factory when the first sample arrives and created again when the sampling
+ * rate changes, so it can be attached to any branch of pipeline before
+ * negotiation. The positions of frames are counted in samples since the
+ * analyzer was created.
+ *
+ * Use `attach_appsink()` to set up the sink, or call `AppSinkAnalyzer::process()`
+ * with samples pulled manually.
+ */
+pub struct AppSinkAnalyzer<A, G> {
+    factory: G,
+    analyzer: Option<StreamingAnalyzer<A>>,
+    sample_rate: u32,
+    channels: usize,
+    mono: Vec<Smpl>,
+}
+
+impl<A, G> AppSinkAnalyzer<A, G>
+where
+    A: Analyzer,
+    G: FnMut(u32) -> crate::Result<A>,
+{
+    /**
+     * Create analyzer
+     *
+     * - `factory` Function which creates the analyzer for given sampling rate
+     */
+    pub fn new(factory: G) -> Self {
+        Self {
+            factory,
+            analyzer: None,
+            sample_rate: 0,
+            channels: 0,
+            mono: Vec::new(),
+        }
+    }
+
+    /**
+     * Get sampling rate of the last processed sample
+     */
+    pub fn get_samplerate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /**
+     * Get number of channels of the last processed sample
+     */
+    pub fn get_channels(&self) -> usize {
+        self.channels
+    }
+
+    /**
+     * Get reference to analyzer if it was created
+     */
+    pub fn get_analyzer(&self) -> Option<&A> {
+        self.analyzer.as_ref().map(StreamingAnalyzer::get_ref)
+    }
+
+    /**
+     * Process the sample
+     *
+     * The caps of sample should describe interleaved raw audio in format of `Smpl`
+     * (`F32` or `F64` with __double__ feature) in native byte order.
+     *
+     * - `sample` Sample pulled from sink
+     * - `callback` Function which receives the position of frame and result of analysis
+     */
+    pub fn process<F>(&mut self, sample: &Sample, callback: F) -> Result<(), AppSinkError>
+    where
+        F: FnMut(usize, A::Output),
+    {
+        let caps = sample.caps().ok_or(AppSinkError::Caps(None))?;
+        let info = AudioInfo::from_caps(caps).map_err(|error| AppSinkError::Caps(Some(error)))?;
+
+        if info.format() != AUDIO_FORMAT || info.layout() != AudioLayout::Interleaved {
+            return Err(AppSinkError::Caps(None));
+        }
+
+        if self.analyzer.is_none() || info.rate() != self.sample_rate {
+            self.sample_rate = info.rate();
+            self.analyzer = Some(StreamingAnalyzer::new((self.factory)(self.sample_rate)?));
+        }
+        self.channels = (info.channels() as usize).max(1);
+
+        let buffer = sample.buffer().ok_or(AppSinkError::Buffer(None))?;
+        let map = buffer
+            .map_readable()
+            .map_err(|error| AppSinkError::Buffer(Some(error)))?;
+        let data = map
+            .as_slice_of::<Smpl>()
+            .map_err(|error| AppSinkError::Buffer(Some(error)))?;
+
+        let input = if self.channels > 1 {
+            self.mono.resize(data.len() / self.channels, 0.0);
+            downmix_into(data, self.channels, &mut self.mono)?;
+            &self.mono[..]
+        } else {
+            data
+        };
+
+        let analyzer = self.analyzer.as_mut().unwrap();
+        analyzer.push_with_position(input, callback)?;
+
+        Ok(())
+    }
+}
+
+/**
+ * Attach analysis to _GStreamer_ `appsink`
+ *
+ * Restricts the caps of sink to interleaved raw audio in format of `Smpl` and
+ * installs the callbacks which pull the samples and pass the results to `callback`.
+ * The sink is expected to be preceded by `audioconvert` and optionally
+ * `audioresample` to be able to negotiate the caps.
+ *
+ * Processing errors are reported as flow errors, which causes the pipeline to post
+ * error message on its bus.
+
+```ignore
+use aubio::{attach_appsink, Onset, OnsetMode};
+use gstreamer::prelude::*;
+
+gstreamer::init().unwrap();
+
+let pipeline = gstreamer::parse_launch(
+    "uridecodebin uri=file:///music.ogg ! audioconvert ! appsink name=sink",
+).unwrap();
+let sink = pipeline
+    .downcast_ref::<gstreamer::Bin>()
+    .unwrap()
+    .by_name("sink")
+    .unwrap()
+    .downcast::<gstreamer_app::AppSink>()
+    .unwrap();
+
+attach_appsink(
+    &sink,
+    |sample_rate| Onset::new(OnsetMode::Hfc, 1024, 512, sample_rate),
+    |position, onset| if onset > 0.0 { println!("onset at {}", position) },
+);
+```
+ */
+pub fn attach_appsink<A, G, F>(appsink: &AppSink, factory: G, mut callback: F)
+where
+    A: Analyzer + Send + 'static,
+    G: FnMut(u32) -> crate::Result<A> + Send + 'static,
+    F: FnMut(usize, A::Output) + Send + 'static,
+{
+    let caps = AudioCapsBuilder::new_interleaved()
+        .format(AUDIO_FORMAT)
+        .build();
+    appsink.set_caps(Some(&caps));
+
+    let mut analyzer = AppSinkAnalyzer::new(factory);
+
+    appsink.set_callbacks(
+        AppSinkCallbacks::builder()
+            .new_sample(move |sink| {
+                let sample = sink.pull_sample().map_err(|_| FlowError::Eos)?;
+
+                analyzer
+                    .process(&sample, &mut callback)
+                    .map_err(|_| FlowError::Error)?;
+
+                Ok(FlowSuccess::Ok)
+            })
+            .build(),
+    );
+}
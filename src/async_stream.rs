@@ -0,0 +1,212 @@
+use crate::{Analyzer, Error, Smpl, StreamingAnalyzer};
+
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+
+use alloc::collections::VecDeque;
+
+/**
+ * Event produced by analysis stream
+ */
+#[derive(Debug)]
+pub enum StreamEvent<T> {
+    /**
+     * Result of analysis of single frame
+     */
+    Frame {
+        /// Position of frame in samples from the start of input
+        position: usize,
+        /// Analysis result
+        output: T,
+    },
+
+    /**
+     * Analysis failed, the stream ends after this event
+     */
+    Error(Error),
+}
+
+/**
+ * Asynchronous analysis of stream of samples
+ *
+ * Polls the input stream of sample chunks of any length, slices it into frames
+ * of `hop_size` samples and yields the results of analysis of each frame.
+ * The incomplete last frame is padded by zeros when the input ends.
+ *
+ * The analysis is done in place while polling, so the hop size should be kept
+ * reasonably small to avoid blocking the executor for too long.
+ *
+ * Both input stream and analyzer should be `Unpin` (use `Box::pin()` to wrap
+ * the streams which is not).
+ */
+pub struct AnalyzerStream<S, A: Analyzer> {
+    input: S,
+    analyzer: StreamingAnalyzer<A>,
+    events: VecDeque<StreamEvent<A::Output>>,
+    finished: bool,
+}
+
+impl<S, A> AnalyzerStream<S, A>
+where
+    S: Stream + Unpin,
+    S::Item: AsRef<[Smpl]>,
+    A: Analyzer + Unpin,
+{
+    /**
+     * Create analysis stream
+     *
+     * - `input` Stream of chunks of samples
+     * - `analyzer` Analyzer to process samples
+     */
+    pub fn new(input: S, analyzer: A) -> Self {
+        Self {
+            input,
+            analyzer: StreamingAnalyzer::new(analyzer),
+            events: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    /**
+     * Get reference to wrapped analyzer
+     */
+    pub fn get_ref(&self) -> &A {
+        self.analyzer.get_ref()
+    }
+
+    /**
+     * Get mutable reference to wrapped analyzer
+     */
+    pub fn get_mut(&mut self) -> &mut A {
+        self.analyzer.get_mut()
+    }
+
+    /**
+     * Unwrap input stream and analyzer
+     */
+    pub fn into_inner(self) -> (S, A) {
+        (self.input, self.analyzer.into_inner())
+    }
+
+    fn push(&mut self, samples: &[Smpl]) {
+        let events = &mut self.events;
+
+        let result = self
+            .analyzer
+            .push_with_position(samples, |position, output| {
+                events.push_back(StreamEvent::Frame { position, output });
+            });
+
+        if let Err(error) = result {
+            self.fail(error);
+        }
+    }
+
+    fn flush(&mut self) {
+        let position = self.analyzer.get_position();
+
+        match self.analyzer.flush() {
+            Ok(Some(output)) => {
+                self.events
+                    .push_back(StreamEvent::Frame { position, output });
+            }
+            Ok(None) => {}
+            Err(error) => self.fail(error),
+        }
+
+        self.finished = true;
+    }
+
+    fn fail(&mut self, error: Error) {
+        self.events.push_back(StreamEvent::Error(error));
+        self.finished = true;
+    }
+}
+
+// results are never pinned, so only input and analyzer are required to be `Unpin`
+impl<S: Unpin, A: Analyzer + Unpin> Unpin for AnalyzerStream<S, A> {}
+
+impl<S, A> Stream for AnalyzerStream<S, A>
+where
+    S: Stream + Unpin,
+    S::Item: AsRef<[Smpl]>,
+    A: Analyzer + Unpin,
+{
+    type Item = StreamEvent<A::Output>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(event) = this.events.pop_front() {
+                return Poll::Ready(Some(event));
+            }
+
+            if this.finished {
+                return Poll::Ready(None);
+            }
+
+            match Pin::new(&mut this.input).poll_next(cx) {
+                Poll::Ready(Some(samples)) => this.push(samples.as_ref()),
+                Poll::Ready(None) => this.flush(),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (self.events.len(), Some(self.events.len()))
+        } else {
+            (self.events.len(), None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::testing::Sum;
+    use crate::*;
+    use futures::{executor::block_on_stream, stream};
+
+    fn frames(
+        input: Vec<Vec<Smpl>>,
+        hop_size: usize,
+    ) -> Vec<core::result::Result<(usize, Smpl), Error>> {
+        let stream = AnalyzerStream::new(stream::iter(input), Sum(hop_size));
+
+        block_on_stream(stream)
+            .map(|event| match event {
+                StreamEvent::Frame { position, output } => Ok((position, output)),
+                StreamEvent::Error(error) => Err(error),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(
+            frames(
+                vec![vec![1.0, 1.0, 1.0], vec![1.0, 2.0, 2.0, 2.0, 2.0, 3.0]],
+                4
+            ),
+            vec![Ok((0, 4.0)), Ok((4, 8.0)), Ok((8, 3.0))]
+        );
+        assert_eq!(frames(vec![], 4), vec![]);
+    }
+
+    #[test]
+    fn test_stream_error() {
+        assert_eq!(
+            frames(
+                vec![vec![1.0, 1.0], vec![Smpl::NAN, 1.0], vec![1.0, 1.0]],
+                2
+            ),
+            vec![Ok((0, 2.0)), Err(Error::InvalidArg)]
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{
+    ring::{Consumer, Producer},
+    vec::downmix_into,
+    worker::{spawn_worker, WorkerEvent},
+    Analyzer, Error, Smpl,
+};
+
+use cpal::{
+    traits::{DeviceTrait, HostTrait, StreamTrait},
+    FromSample, SampleFormat, SizedSample, StreamConfig,
+};
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::mpsc::{channel, Receiver, Sender},
+};
+
+use alloc::vec::Vec;
+
+/**
+ * Audio capture error
+ */
+#[derive(Debug)]
+pub enum CaptureError {
+    /**
+     * No default input device
+     */
+    NoDevice,
+
+    /**
+     * Failed to get device configuration
+     */
+    Config(cpal::DefaultStreamConfigError),
+
+    /**
+     * Failed to create input stream
+     */
+    Build(cpal::BuildStreamError),
+
+    /**
+     * Failed to start input stream
+     */
+    Play(cpal::PlayStreamError),
+
+    /**
+     * Failed to pause input stream
+     */
+    Pause(cpal::PauseStreamError),
+
+    /**
+     * Sample format of device is not supported
+     */
+    Format(SampleFormat),
+
+    /**
+     * Failed to create analyzer
+     */
+    Analyzer(Error),
+}
+
+impl StdError for CaptureError {}
+
+impl Display for CaptureError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        use self::CaptureError::*;
+        match self {
+            NoDevice => "no input device".fmt(f),
+            Config(error) => write!(f, "device configuration error: {}", error),
+            Build(error) => write!(f, "stream creation error: {}", error),
+            Play(error) => write!(f, "stream start error: {}", error),
+            Pause(error) => write!(f, "stream pause error: {}", error),
+            Format(format) => write!(f, "unsupported sample format: {:?}", format),
+            Analyzer(error) => write!(f, "analyzer error: {}", error),
+        }
+    }
+}
+
+/**
+ * Event delivered by audio capture
+ */
+#[derive(Debug)]
+pub enum CaptureEvent<T> {
+    /**
+     * Result of analysis of single frame
+     */
+    Frame {
+        /// Position of frame in samples from the start of capture
+        position: usize,
+        /// Analysis result
+        output: T,
+    },
+
+    /**
+     * Analysis failed, no more frames will be delivered
+     */
+    Error(Error),
+
+    /**
+     * Audio stream error reported by device
+     */
+    Stream(cpal::StreamError),
+}
+
+impl<T> WorkerEvent<T> for CaptureEvent<T> {
+    fn frame(position: usize, output: T) -> Self {
+        CaptureEvent::Frame { position, output }
+    }
+
+    fn error(error: Error) -> Self {
+        CaptureEvent::Error(error)
+    }
+}
+
+/**
+ * Real-time audio input analyzer
+ *
+ * Owns the _cpal_ input stream, converts the samples to `Smpl`, down-mixes channels
+ * and feeds the frames of `hop_size` samples to the analyzer. The results are
+ * delivered through the channel (see `CpalCapture::events()`).
+ *
+ * The audio callback passes the samples through the preallocated buffer which keeps
+ * at least one second of signal. The samples which does not fit into it when
+ * the analysis is too slow are dropped.
+ *
+ * The analyzer is created by factory on the dedicated worker thread, so the audio
+ * callback does not run the analysis and the analyzer does not need to be `Send`.
+ */
+pub struct CpalCapture<T> {
+    stream: cpal::Stream,
+    events: Receiver<CaptureEvent<T>>,
+    sample_rate: u32,
+    channels: usize,
+}
+
+impl<T: Send + 'static> CpalCapture<T> {
+    /**
+     * Start analysis of default input device of default host
+     *
+     * - `factory` Function which creates the analyzer for given sampling rate
+     */
+    pub fn with_default_device<A, F>(factory: F) -> Result<Self, CaptureError>
+    where
+        A: Analyzer<Output = T>,
+        F: FnOnce(u32) -> crate::Result<A> + Send + 'static,
+    {
+        let device = cpal::default_host()
+            .default_input_device()
+            .ok_or(CaptureError::NoDevice)?;
+
+        Self::new(&device, factory)
+    }
+
+    /**
+     * Start analysis of input device
+     *
+     * - `device` Input device
+     * - `factory` Function which creates the analyzer for given sampling rate
+     */
+    pub fn new<A, F>(device: &cpal::Device, factory: F) -> Result<Self, CaptureError>
+    where
+        A: Analyzer<Output = T>,
+        F: FnOnce(u32) -> crate::Result<A> + Send + 'static,
+    {
+        let supported = device
+            .default_input_config()
+            .map_err(CaptureError::Config)?;
+        let format = supported.sample_format();
+        let config: StreamConfig = supported.into();
+        let sample_rate = config.sample_rate.0;
+        let channels = config.channels as usize;
+
+        let (events_tx, events) = channel();
+        let (samples_tx, _) =
+            spawn_worker::<_, _, Consumer, _>(sample_rate, factory, events_tx.clone())
+                .map_err(CaptureError::Analyzer)?;
+
+        let stream = match format {
+            SampleFormat::I8 => build_stream::<i8, T>(device, &config, samples_tx, events_tx),
+            SampleFormat::I16 => build_stream::<i16, T>(device, &config, samples_tx, events_tx),
+            SampleFormat::I32 => build_stream::<i32, T>(device, &config, samples_tx, events_tx),
+            SampleFormat::I64 => build_stream::<i64, T>(device, &config, samples_tx, events_tx),
+            SampleFormat::U8 => build_stream::<u8, T>(device, &config, samples_tx, events_tx),
+            SampleFormat::U16 => build_stream::<u16, T>(device, &config, samples_tx, events_tx),
+            SampleFormat::U32 => build_stream::<u32, T>(device, &config, samples_tx, events_tx),
+            SampleFormat::U64 => build_stream::<u64, T>(device, &config, samples_tx, events_tx),
+            SampleFormat::F32 => build_stream::<f32, T>(device, &config, samples_tx, events_tx),
+            SampleFormat::F64 => build_stream::<f64, T>(device, &config, samples_tx, events_tx),
+            format => Err(CaptureError::Format(format)),
+        }?;
+
+        stream.play().map_err(CaptureError::Play)?;
+
+        Ok(Self {
+            stream,
+            events,
+            sample_rate,
+            channels,
+        })
+    }
+}
+
+impl<T> CpalCapture<T> {
+    /**
+     * Get sampling rate of input stream
+     */
+    pub fn get_samplerate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /**
+     * Get number of channels of input stream
+     */
+    pub fn get_channels(&self) -> usize {
+        self.channels
+    }
+
+    /**
+     * Get receiver of analysis events
+     */
+    pub fn events(&self) -> &Receiver<CaptureEvent<T>> {
+        &self.events
+    }
+
+    /**
+     * Resume capture
+     */
+    pub fn play(&self) -> Result<(), CaptureError> {
+        self.stream.play().map_err(CaptureError::Play)
+    }
+
+    /**
+     * Pause capture
+     */
+    pub fn pause(&self) -> Result<(), CaptureError> {
+        self.stream.pause().map_err(CaptureError::Pause)
+    }
+}
+
+/**
+ * Build input stream which down-mixes samples and passes it to worker
+ */
+fn build_stream<S, T>(
+    device: &cpal::Device,
+    config: &StreamConfig,
+    samples: Producer,
+    events: Sender<CaptureEvent<T>>,
+) -> Result<cpal::Stream, CaptureError>
+where
+    S: SizedSample,
+    Smpl: FromSample<S>,
+    T: Send + 'static,
+{
+    let channels = (config.channels as usize).max(1);
+    // the buffers grow on first callbacks only
+    let mut interleaved = Vec::new();
+    let mut mono = Vec::new();
+
+    device
+        .build_input_stream(
+            config,
+            move |data: &[S], _: &cpal::InputCallbackInfo| {
+                let length = data.len() - data.len() % channels;
+                interleaved.clear();
+                interleaved.extend(
+                    data[..length]
+                        .iter()
+                        .map(|sample| sample.to_sample::<Smpl>()),
+                );
+                mono.resize(length / channels, 0.0);
+
+                if downmix_into(&interleaved, channels, &mut mono).is_ok() {
+                    samples.push(&mono);
+                }
+            },
+            move |error| {
+                let _ = events.send(CaptureEvent::Stream(error));
+            },
+            None,
+        )
+        .map_err(CaptureError::Build)
+}
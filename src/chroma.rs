@@ -0,0 +1,121 @@
+use crate::{vec::FVec, PVoc, Result, Smpl, WindowType};
+
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/// Number of pitch classes
+pub const CHROMA_SIZE: usize = 12;
+
+/// Lowest frequency which contributes to chroma
+const MIN_FREQ: Smpl = 55.0;
+
+/// Highest frequency which contributes to chroma
+const MAX_FREQ: Smpl = 5000.0;
+
+/**
+ * Chroma features extractor
+ *
+ * Computes the energy of spectrum folded into 12 pitch classes starting from C.
+ * The bins from 55 to 5000 Hz are mapped to the nearest equal-tempered note
+ * relative to A4 at 440 Hz.
+ */
+pub struct Chroma {
+    pvoc: PVoc,
+    grain: Vec<Smpl>,
+    classes: Vec<Option<usize>>,
+}
+
+impl Chroma {
+    /**
+     * Create chroma features extractor
+     *
+     * - `buf_size` Size of analysis buffer (and length the FFT transform)
+     * - `hop_size` Step size between two consecutive analysis instant
+     * - `sample_rate` Sampling rate of the signal
+     */
+    pub fn new(buf_size: usize, hop_size: usize, sample_rate: u32) -> Result<Self> {
+        let pvoc = PVoc::new(buf_size, hop_size)?.with_window(WindowType::Hanningz)?;
+        let bin_freq = sample_rate as Smpl / buf_size as Smpl;
+
+        let classes = (0..buf_size / 2 + 1)
+            .map(|bin| {
+                let freq = bin as Smpl * bin_freq;
+                if (MIN_FREQ..=MAX_FREQ).contains(&freq) {
+                    let midi = (69.0 + 12.0 * (freq / 440.0).log2()).round() as i32;
+                    Some(midi.rem_euclid(CHROMA_SIZE as i32) as usize)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            pvoc,
+            grain: vec![0.; buf_size + 2],
+            classes,
+        })
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.pvoc.get_hop()
+    }
+
+    /**
+     * Compute chroma of an input signal frame
+     *
+     * - `input` Input signal of size `hop_size`
+     *
+     * Returns the energy of each pitch class.
+     */
+    pub fn do_result<'i, I>(&mut self, input: I) -> Result<[Smpl; CHROMA_SIZE]>
+    where
+        I: Into<FVec<'i>>,
+    {
+        self.pvoc.do_(input, self.grain.as_mut_slice())?;
+
+        let mut chroma = [0.; CHROMA_SIZE];
+
+        for (class, norm) in self.classes.iter().zip(&self.grain) {
+            if let Some(class) = class {
+                chroma[*class] += norm * norm;
+            }
+        }
+
+        Ok(chroma)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_chroma() {
+        let sample_rate = 44100;
+        let hop_size = 1024;
+        let mut chroma = Chroma::new(4096, hop_size, sample_rate).unwrap();
+
+        let samples = (0..hop_size * 8)
+            .map(|index| {
+                let phase = 2.0 * std::f64::consts::PI as Smpl * 440.0 * index as Smpl;
+                (phase / sample_rate as Smpl).sin() * 0.5
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = [0.; CHROMA_SIZE];
+        for frame in samples.chunks_exact(hop_size) {
+            result = chroma.do_result(frame).unwrap();
+        }
+
+        let class = (0..CHROMA_SIZE)
+            .max_by(|a, b| result[*a].partial_cmp(&result[*b]).unwrap())
+            .unwrap();
+        assert_eq!(class, 9);
+    }
+}
@@ -0,0 +1,309 @@
+use crate::{Analyzer, Error, Result, Smpl, StreamEvent, StreamingAnalyzer};
+
+use bytes::{Buf, BytesMut};
+use tokio_util::codec::Decoder;
+
+use std::io::Error as IoError;
+
+use alloc::{collections::VecDeque, vec::Vec};
+
+/**
+ * Encoding of raw PCM samples
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PcmFormat {
+    /// Unsigned 8-bit
+    U8,
+    /// Signed 16-bit little-endian
+    I16Le,
+    /// Signed 16-bit big-endian
+    I16Be,
+    /// Signed 32-bit little-endian
+    I32Le,
+    /// Signed 32-bit big-endian
+    I32Be,
+    /// 32-bit float little-endian
+    F32Le,
+    /// 32-bit float big-endian
+    F32Be,
+}
+
+impl PcmFormat {
+    /**
+     * Get size of single sample in bytes
+     */
+    pub fn get_size(&self) -> usize {
+        use self::PcmFormat::*;
+        match self {
+            U8 => 1,
+            I16Le | I16Be => 2,
+            I32Le | I32Be | F32Le | F32Be => 4,
+        }
+    }
+
+    /**
+     * Convert sample from bytes
+     *
+     * Integer samples are scaled to the `[-1, 1)` range.
+     */
+    fn decode(&self, data: &[u8]) -> Smpl {
+        use self::PcmFormat::*;
+        match self {
+            U8 => (data[0] as Smpl - 128.0) / 128.0,
+            I16Le => i16::from_le_bytes([data[0], data[1]]) as Smpl / 32768.0,
+            I16Be => i16::from_be_bytes([data[0], data[1]]) as Smpl / 32768.0,
+            I32Le => {
+                (i32::from_le_bytes([data[0], data[1], data[2], data[3]]) as f64 / 2147483648.0)
+                    as Smpl
+            }
+            I32Be => {
+                (i32::from_be_bytes([data[0], data[1], data[2], data[3]]) as f64 / 2147483648.0)
+                    as Smpl
+            }
+            F32Le => f32::from_le_bytes([data[0], data[1], data[2], data[3]]) as Smpl,
+            F32Be => f32::from_be_bytes([data[0], data[1], data[2], data[3]]) as Smpl,
+        }
+    }
+}
+
+/**
+ * Format of raw PCM stream
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PcmSpec {
+    /// Encoding of samples
+    pub format: PcmFormat,
+    /// Number of interleaved channels
+    pub channels: usize,
+}
+
+impl PcmSpec {
+    pub fn new(format: PcmFormat, channels: usize) -> Self {
+        Self { format, channels }
+    }
+
+    /**
+     * Get size of single interleaved frame in bytes
+     */
+    pub fn get_frame_size(&self) -> usize {
+        self.format.get_size() * self.channels
+    }
+}
+
+/**
+ * Codec which analyzes raw PCM bytes
+ *
+ * Converts the bytes to samples according to `PcmSpec`, down-mixes channels
+ * and passes the signal to analyzer in frames of `hop_size` samples.
+ * Intended to be used with `tokio_util::codec::FramedRead` to analyze the data
+ * from any `AsyncRead` like network socket or pipe.
+ *
+ * ```ignore
+ * let codec = PcmAnalyzerCodec::new(PcmSpec::new(PcmFormat::I16Le, 2), onset)?;
+ * let mut events = FramedRead::new(socket, codec);
+ *
+ * while let Some(event) = events.next().await {
+ *     // ...
+ * }
+ * ```
+ *
+ * When the input ends the last incomplete frame is padded by zeros.
+ * After analysis error no more events is produced and the input is discarded.
+ */
+pub struct PcmAnalyzerCodec<A: Analyzer> {
+    spec: PcmSpec,
+    analyzer: StreamingAnalyzer<A>,
+    samples: Vec<Smpl>,
+    events: VecDeque<StreamEvent<A::Output>>,
+    finished: bool,
+}
+
+impl<A: Analyzer> PcmAnalyzerCodec<A> {
+    /**
+     * Create codec
+     *
+     * - `spec` Format of input data
+     * - `analyzer` Analyzer to process samples
+     */
+    pub fn new(spec: PcmSpec, analyzer: A) -> Result<Self> {
+        if spec.channels == 0 {
+            return Err(Error::InvalidArg);
+        }
+
+        Ok(Self {
+            spec,
+            analyzer: StreamingAnalyzer::new(analyzer),
+            samples: Vec::new(),
+            events: VecDeque::new(),
+            finished: false,
+        })
+    }
+
+    /**
+     * Get format of input data
+     */
+    pub fn get_spec(&self) -> PcmSpec {
+        self.spec
+    }
+
+    /**
+     * Get reference to wrapped analyzer
+     */
+    pub fn get_ref(&self) -> &A {
+        self.analyzer.get_ref()
+    }
+
+    /**
+     * Get mutable reference to wrapped analyzer
+     */
+    pub fn get_mut(&mut self) -> &mut A {
+        self.analyzer.get_mut()
+    }
+
+    /**
+     * Unwrap analyzer
+     */
+    pub fn into_inner(self) -> A {
+        self.analyzer.into_inner()
+    }
+
+    fn process(&mut self, src: &mut BytesMut) {
+        if self.finished {
+            src.clear();
+            return;
+        }
+
+        let frame_size = self.spec.get_frame_size();
+        let length = src.len() / frame_size * frame_size;
+
+        if length == 0 {
+            return;
+        }
+
+        let format = self.spec.format;
+        let sample_size = format.get_size();
+        let channels = self.spec.channels as Smpl;
+
+        self.samples.clear();
+        self.samples
+            .extend(src[..length].chunks_exact(frame_size).map(|frame| {
+                frame
+                    .chunks_exact(sample_size)
+                    .map(|sample| format.decode(sample))
+                    .sum::<Smpl>()
+                    / channels
+            }));
+        src.advance(length);
+
+        let events = &mut self.events;
+
+        let result = self
+            .analyzer
+            .push_with_position(&self.samples, |position, output| {
+                events.push_back(StreamEvent::Frame { position, output });
+            });
+
+        if let Err(error) = result {
+            self.fail(error);
+        }
+    }
+
+    fn flush(&mut self) {
+        if self.finished {
+            return;
+        }
+
+        let position = self.analyzer.get_position();
+
+        match self.analyzer.flush() {
+            Ok(Some(output)) => self
+                .events
+                .push_back(StreamEvent::Frame { position, output }),
+            Ok(None) => {}
+            Err(error) => self.fail(error),
+        }
+
+        self.finished = true;
+    }
+
+    fn fail(&mut self, error: Error) {
+        self.events.push_back(StreamEvent::Error(error));
+        self.finished = true;
+    }
+}
+
+impl<A: Analyzer> Decoder for PcmAnalyzerCodec<A> {
+    type Item = StreamEvent<A::Output>;
+    type Error = IoError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> core::result::Result<Option<Self::Item>, IoError> {
+        if self.events.is_empty() {
+            self.process(src);
+        }
+
+        Ok(self.events.pop_front())
+    }
+
+    fn decode_eof(
+        &mut self,
+        src: &mut BytesMut,
+    ) -> core::result::Result<Option<Self::Item>, IoError> {
+        if let Some(event) = self.decode(src)? {
+            return Ok(Some(event));
+        }
+
+        // the trailing incomplete interleaved frame is dropped
+        src.clear();
+        self.flush();
+
+        Ok(self.events.pop_front())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::testing::Sum;
+    use crate::*;
+    use bytes::BytesMut;
+    use tokio_util::codec::Decoder;
+
+    fn frame(event: Option<StreamEvent<Smpl>>) -> Option<(usize, Smpl)> {
+        event.map(|event| match event {
+            StreamEvent::Frame { position, output } => (position, output),
+            StreamEvent::Error(error) => panic!("{}", error),
+        })
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(PcmFormat::U8.decode(&[64]), -0.5);
+        assert_eq!(PcmFormat::I16Le.decode(&[0x00, 0x40]), 0.5);
+        assert_eq!(PcmFormat::I16Be.decode(&[0x80, 0x00]), -1.0);
+        assert_eq!(PcmFormat::I32Le.decode(&[0, 0, 0, 0xc0]), -0.5);
+        assert_eq!(PcmFormat::F32Be.decode(&0.25f32.to_be_bytes()), 0.25);
+    }
+
+    #[test]
+    fn test_codec() {
+        let mut codec = PcmAnalyzerCodec::new(PcmSpec::new(PcmFormat::U8, 2), Sum(2)).unwrap();
+        let mut src = BytesMut::new();
+
+        // 0.5, 0.0 | 0.5, 0.5 | 0.0, -0.5 | incomplete
+        src.extend_from_slice(&[192, 128, 192, 192, 128]);
+        assert_eq!(frame(codec.decode(&mut src).unwrap()), Some((0, 0.75)));
+        assert_eq!(frame(codec.decode(&mut src).unwrap()), None);
+        assert_eq!(src.len(), 1);
+
+        src.extend_from_slice(&[64, 255]);
+        assert_eq!(frame(codec.decode(&mut src).unwrap()), None);
+        assert_eq!(frame(codec.decode_eof(&mut src).unwrap()), Some((2, -0.25)));
+        assert_eq!(frame(codec.decode_eof(&mut src).unwrap()), None);
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn test_codec_spec() {
+        assert_eq!(PcmSpec::new(PcmFormat::I16Be, 2).get_frame_size(), 4);
+        assert!(PcmAnalyzerCodec::new(PcmSpec::new(PcmFormat::F32Le, 0), Sum(2)).is_err());
+    }
+}
@@ -0,0 +1,351 @@
+use crate::{vec::downmix_into, Smpl};
+
+use symphonia::core::{
+    audio::{SampleBuffer, SignalSpec},
+    codecs::{Decoder, DecoderOptions, CODEC_TYPE_NULL},
+    errors::Error as SymphoniaError,
+    formats::{FormatOptions, FormatReader},
+    io::{MediaSource, MediaSourceStream},
+    meta::MetadataOptions,
+    probe::Hint,
+};
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs::File,
+    io::ErrorKind,
+    path::Path,
+};
+
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/**
+ * Decoding error
+ */
+#[derive(Debug)]
+pub enum DecodeError {
+    /**
+     * Failed to open file
+     */
+    Io(std::io::Error),
+
+    /**
+     * Error reported by decoder
+     */
+    Symphonia(SymphoniaError),
+
+    /**
+     * No audio tracks in media
+     */
+    NoTrack,
+
+    /**
+     * Sampling rate of track is unknown
+     */
+    NoSampleRate,
+
+    /**
+     * Frames size should be greater than zero
+     */
+    InvalidHop,
+}
+
+impl StdError for DecodeError {}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        use self::DecodeError::*;
+        match self {
+            Io(error) => write!(f, "input error: {}", error),
+            Symphonia(error) => write!(f, "decoding error: {}", error),
+            NoTrack => "no audio track".fmt(f),
+            NoSampleRate => "unknown sampling rate".fmt(f),
+            InvalidHop => "invalid hop size".fmt(f),
+        }
+    }
+}
+
+impl From<std::io::Error> for DecodeError {
+    fn from(error: std::io::Error) -> Self {
+        DecodeError::Io(error)
+    }
+}
+
+impl From<SymphoniaError> for DecodeError {
+    fn from(error: SymphoniaError) -> Self {
+        DecodeError::Symphonia(error)
+    }
+}
+
+/**
+ * Media decoder based on _symphonia_
+ *
+ * Decodes the first audio track packet by packet, converts samples to `Smpl`,
+ * down-mixes channels and slices the signal into frames of `hop_size` samples
+ * ready to be passed to detectors.
+ */
+pub struct SymphoniaSource {
+    format: Box<dyn FormatReader>,
+    decoder: Box<dyn Decoder>,
+    track_id: u32,
+    sample_rate: u32,
+    channels: usize,
+    buffer: Option<(SampleBuffer<Smpl>, SignalSpec)>,
+    pending: Vec<Smpl>,
+    offset: usize,
+    frame: Vec<Smpl>,
+    frames: usize,
+    finished: bool,
+    error: Option<DecodeError>,
+}
+
+impl SymphoniaSource {
+    /**
+     * Open media file
+     *
+     * - `path` Path to media file, the extension is used as format hint
+     * - `hop_size` Number of samples in each frame
+     */
+    pub fn open(path: impl AsRef<Path>, hop_size: usize) -> Result<Self, DecodeError> {
+        let path = path.as_ref();
+        let file = File::open(path)?;
+
+        let mut hint = Hint::new();
+        if let Some(extension) = path.extension().and_then(|extension| extension.to_str()) {
+            hint.with_extension(extension);
+        }
+
+        Self::from_source(Box::new(file), &hint, hop_size)
+    }
+
+    /**
+     * Open media source
+     *
+     * - `source` Media source like file or in-memory cursor
+     * - `hint` Format hint (like file extension or mime type)
+     * - `hop_size` Number of samples in each frame
+     */
+    pub fn from_source(
+        source: Box<dyn MediaSource>,
+        hint: &Hint,
+        hop_size: usize,
+    ) -> Result<Self, DecodeError> {
+        let stream = MediaSourceStream::new(source, Default::default());
+
+        let probed = symphonia::default::get_probe().format(
+            hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )?;
+
+        Self::new(probed.format, hop_size)
+    }
+
+    /**
+     * Create decoder for the first audio track of media
+     *
+     * - `format` Format reader
+     * - `hop_size` Number of samples in each frame
+     */
+    pub fn new(format: Box<dyn FormatReader>, hop_size: usize) -> Result<Self, DecodeError> {
+        if hop_size == 0 {
+            return Err(DecodeError::InvalidHop);
+        }
+
+        let track = format
+            .tracks()
+            .iter()
+            .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+            .ok_or(DecodeError::NoTrack)?;
+
+        let track_id = track.id;
+        let sample_rate = track
+            .codec_params
+            .sample_rate
+            .ok_or(DecodeError::NoSampleRate)?;
+        let channels = track
+            .codec_params
+            .channels
+            .map(|channels| channels.count())
+            .unwrap_or(1);
+
+        let decoder = symphonia::default::get_codecs()
+            .make(&track.codec_params, &DecoderOptions::default())?;
+
+        Ok(Self {
+            format,
+            decoder,
+            track_id,
+            sample_rate,
+            channels,
+            buffer: None,
+            pending: Vec::new(),
+            offset: 0,
+            frame: vec![0.; hop_size],
+            frames: 0,
+            finished: false,
+            error: None,
+        })
+    }
+
+    /**
+     * Get sampling rate of decoded track
+     */
+    pub fn get_samplerate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /**
+     * Get number of channels of decoded track
+     */
+    pub fn get_channels(&self) -> usize {
+        self.channels
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.frame.len()
+    }
+
+    /**
+     * Get position of the next frame in samples
+     */
+    pub fn get_position(&self) -> usize {
+        self.frames * self.frame.len()
+    }
+
+    /**
+     * Get the next frame of mono samples
+     *
+     * The last frame is padded by zeros. Returns `None` at the end of stream
+     * or when decoding failed (see `SymphoniaSource::take_error()`).
+     */
+    pub fn next_frame(&mut self) -> Option<&[Smpl]> {
+        let hop_size = self.frame.len();
+        let mut filled = 0;
+
+        while filled < hop_size {
+            if self.offset >= self.pending.len() && !self.decode_packet() {
+                break;
+            }
+
+            let count = (hop_size - filled).min(self.pending.len() - self.offset);
+            self.frame[filled..filled + count]
+                .copy_from_slice(&self.pending[self.offset..self.offset + count]);
+            filled += count;
+            self.offset += count;
+        }
+
+        if filled == 0 {
+            return None;
+        }
+
+        for sample in &mut self.frame[filled..] {
+            *sample = 0.0;
+        }
+        self.frames += 1;
+
+        Some(&self.frame)
+    }
+
+    /**
+     * Take the error which stopped decoding
+     */
+    pub fn take_error(&mut self) -> Option<DecodeError> {
+        self.error.take()
+    }
+
+    /**
+     * Decode the next packet of track into pending samples
+     *
+     * Returns `false` when no more samples can be decoded.
+     */
+    fn decode_packet(&mut self) -> bool {
+        while !self.finished {
+            let packet = match self.format.next_packet() {
+                Ok(packet) => packet,
+                Err(SymphoniaError::IoError(error)) if error.kind() == ErrorKind::UnexpectedEof => {
+                    self.finished = true;
+                    break;
+                }
+                Err(error) => {
+                    self.fail(error);
+                    break;
+                }
+            };
+
+            if packet.track_id() != self.track_id {
+                continue;
+            }
+
+            let decoded = match self.decoder.decode(&packet) {
+                Ok(decoded) => decoded,
+                // the malformed packets are skipped
+                Err(SymphoniaError::DecodeError(_)) => continue,
+                Err(error) => {
+                    self.fail(error);
+                    break;
+                }
+            };
+
+            let spec = *decoded.spec();
+            let channels = spec.channels.count().max(1);
+            let capacity = decoded.capacity() * channels;
+
+            let reuse = matches!(
+                &self.buffer,
+                Some((buffer, current)) if *current == spec && buffer.capacity() >= capacity
+            );
+            if !reuse {
+                self.buffer = Some((SampleBuffer::new(decoded.capacity() as u64, spec), spec));
+            }
+
+            let (buffer, _) = self.buffer.as_mut().unwrap();
+            buffer.copy_interleaved_ref(decoded);
+
+            let samples = buffer.samples();
+            self.pending.resize(samples.len() / channels, 0.0);
+            downmix_into(samples, channels, &mut self.pending).expect("samples are interleaved");
+            self.offset = 0;
+
+            if !self.pending.is_empty() {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn fail(&mut self, error: SymphoniaError) {
+        self.error = Some(error.into());
+        self.finished = true;
+    }
+}
+
+impl crate::MediaSource for SymphoniaSource {
+    type Error = DecodeError;
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /**
+     * Read the next frame of `hop_size` samples
+     *
+     * The last frame is padded by zeros.
+     */
+    fn read_frame(&mut self) -> Result<Option<&[Smpl]>, Self::Error> {
+        if self.next_frame().is_none() {
+            return self.take_error().map_or(Ok(None), Err);
+        }
+        Ok(Some(&self.frame))
+    }
+}
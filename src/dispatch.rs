@@ -0,0 +1,205 @@
+use crate::{
+    offline::{BeatEvent, OnsetEvent},
+    vec::FVec,
+    Error, MidiEvent, Notes, Onset, Smpl, Status, Tempo,
+};
+
+use alloc::boxed::Box;
+
+type Callback<'a, T> = Box<dyn FnMut(&T) + 'a>;
+
+/**
+ * Event dispatcher for detection objects
+ *
+ * Owns the subscribed detectors, runs them on each input frame and invokes
+ * the registered closures with timestamped events, so the processing loop
+ * does not need to poll the detectors after each frame.
+ *
+ * All the detectors should have the same hop size as dispatcher.
+ * Use `StreamingAnalyzer` to feed dispatcher with chunks of any length.
+ */
+pub struct Dispatcher<'a> {
+    hop_size: usize,
+    sample_rate: u32,
+    onset: Option<(Onset, Callback<'a, OnsetEvent>)>,
+    tempo: Option<(Tempo, Callback<'a, BeatEvent>)>,
+    notes: Option<(Notes, Callback<'a, MidiEvent>)>,
+}
+
+impl<'a> Dispatcher<'a> {
+    /**
+     * Create event dispatcher
+     *
+     * - `hop_size` Number of samples in each frame
+     * - `sample_rate` Sampling rate of the input signal
+     */
+    pub fn new(hop_size: usize, sample_rate: u32) -> Self {
+        Self {
+            hop_size,
+            sample_rate,
+            onset: None,
+            tempo: None,
+            notes: None,
+        }
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.hop_size
+    }
+
+    /**
+     * Subscribe to onsets
+     *
+     * Replaces previous onset subscription.
+     */
+    pub fn on_onset<F>(&mut self, onset: Onset, func: F) -> Status
+    where
+        F: FnMut(&OnsetEvent) + 'a,
+    {
+        self.check_hop(onset.get_hop())?;
+        self.onset = Some((onset, Box::new(func)));
+        Ok(())
+    }
+
+    /**
+     * Subscribe to beats
+     *
+     * Replaces previous beat subscription.
+     */
+    pub fn on_beat<F>(&mut self, tempo: Tempo, func: F) -> Status
+    where
+        F: FnMut(&BeatEvent) + 'a,
+    {
+        self.check_hop(tempo.get_hop())?;
+        self.tempo = Some((tempo, Box::new(func)));
+        Ok(())
+    }
+
+    /**
+     * Subscribe to notes
+     *
+     * Replaces previous note subscription.
+     */
+    pub fn on_note<F>(&mut self, notes: Notes, func: F) -> Status
+    where
+        F: FnMut(&MidiEvent) + 'a,
+    {
+        self.check_hop(notes.get_hop())?;
+        self.notes = Some((notes, Box::new(func)));
+        Ok(())
+    }
+
+    /**
+     * Run subscribed detectors on an input signal frame
+     *
+     * - `input` Input signal of size `hop_size`
+     */
+    pub fn do_<'i, I>(&mut self, input: I) -> Status
+    where
+        I: Into<FVec<'i>>,
+    {
+        let input = input.into();
+        let input = input.as_slice();
+        let sample_rate = self.sample_rate as Smpl;
+
+        if input.len() != self.hop_size {
+            return Err(Error::MismatchSize);
+        }
+
+        if let Some((onset, func)) = &mut self.onset {
+            if onset.do_result(input)? > 0.0 {
+                let position = onset.get_last();
+                func(&OnsetEvent {
+                    position,
+                    time: position as Smpl / sample_rate,
+                    descriptor: onset.get_descriptor(),
+                });
+            }
+        }
+
+        if let Some((tempo, func)) = &mut self.tempo {
+            if tempo.do_result(input)? > 0.0 {
+                let position = tempo.get_last();
+                func(&BeatEvent {
+                    position,
+                    time: position as Smpl / sample_rate,
+                    confidence: tempo.get_confidence(),
+                });
+            }
+        }
+
+        if let Some((notes, func)) = &mut self.notes {
+            notes.do_events(input)?;
+            for event in notes.poll_events() {
+                func(&event);
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Reset subscribed detectors to start with new signal
+     */
+    pub fn reset(&mut self) -> Status {
+        if let Some((onset, _)) = &mut self.onset {
+            onset.reset();
+        }
+        if let Some((tempo, _)) = &mut self.tempo {
+            tempo.reset()?;
+        }
+        if let Some((notes, _)) = &mut self.notes {
+            notes.reset()?;
+        }
+        Ok(())
+    }
+
+    fn check_hop(&self, hop_size: usize) -> Status {
+        if hop_size == self.hop_size {
+            Ok(())
+        } else {
+            Err(Error::MismatchSize)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_dispatcher() {
+        let sample_rate = 44100;
+        let hop_size = 256;
+
+        let mut onsets = Vec::new();
+
+        {
+            let mut dispatcher = Dispatcher::new(hop_size, sample_rate);
+
+            let onset = Onset::new(OnsetMode::default(), 512, hop_size, sample_rate).unwrap();
+            dispatcher
+                .on_onset(onset, |event| onsets.push(event.position))
+                .unwrap();
+
+            let tempo = Tempo::new(OnsetMode::default(), 1024, hop_size, sample_rate).unwrap();
+            dispatcher.on_beat(tempo, |_| {}).unwrap();
+
+            let notes = Notes::new(512, 512, sample_rate).unwrap();
+            assert!(dispatcher.on_note(notes, |_| {}).is_err());
+
+            let mut stream = StreamingAnalyzer::new(dispatcher);
+            let mut samples = vec![0.0; sample_rate as usize];
+            for sample in &mut samples[22050..22100] {
+                *sample = 1.0;
+            }
+            stream.push(&samples).unwrap();
+        }
+
+        assert!(!onsets.is_empty());
+        assert!(onsets.iter().any(|&position| position >= 22050 - hop_size));
+    }
+}
@@ -0,0 +1,277 @@
+use crate::{vec::FVec, Error, FilterBank, OnsetMode, PVoc, Result, Smpl, Status, Tempo};
+
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/// Lowest center frequency of energy bands
+const MIN_FREQ: Smpl = 40.0;
+
+/// Number of octave energy bands
+const N_BANDS: usize = 7;
+
+/**
+ * Beat labeled with its position in bar
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BarBeat {
+    /// Position of beat in samples
+    pub position: usize,
+    /// Index of beat in bar, `0` for downbeats
+    pub index: usize,
+}
+
+/**
+ * Downbeat estimation object
+ *
+ * Tracks beats using `Tempo` and collects the spectral energy in octave bands
+ * between consecutive beats. The beats where the low-frequency energy changes most
+ * are treated as bar starts: the phase of the meter which maximizes the mean
+ * change is selected over the whole analyzed signal.
+ */
+pub struct Downbeat {
+    tempo: Tempo,
+    pvoc: PVoc,
+    filterbank: FilterBank,
+    grain: Vec<Smpl>,
+    bands: Vec<Smpl>,
+    energy: Vec<Smpl>,
+    hops: usize,
+    beats: Vec<(usize, Vec<Smpl>)>,
+    meter: usize,
+}
+
+impl Downbeat {
+    /**
+     * Create downbeat estimation object
+     *
+     * - `buf_size` Length of FFT
+     * - `hop_size` Number of frames between two consecutive runs
+     * - `sample_rate` Sampling rate of the signal to analyze
+     */
+    pub fn new(buf_size: usize, hop_size: usize, sample_rate: u32) -> Result<Self> {
+        let tempo = Tempo::new(OnsetMode::default(), buf_size, hop_size, sample_rate)?;
+        let pvoc = PVoc::new(buf_size, hop_size)?;
+
+        let fmax = (MIN_FREQ * (1 << N_BANDS) as Smpl).min(sample_rate as Smpl / 2.0);
+        let filterbank = FilterBank::new_log_bands(MIN_FREQ, fmax, 1, buf_size, sample_rate as _)?;
+        let n_bands = filterbank.get_coeffs().height();
+
+        Ok(Self {
+            tempo,
+            pvoc,
+            filterbank,
+            grain: vec![0.; buf_size + 2],
+            bands: vec![0.; n_bands],
+            energy: vec![0.; n_bands],
+            hops: 0,
+            beats: Vec::new(),
+            meter: 4,
+        })
+    }
+
+    /**
+     * Set number of beats in bar
+     */
+    pub fn with_meter(mut self, meter: usize) -> Result<Self> {
+        self.set_meter(meter).map(|_| self)
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.tempo.get_hop()
+    }
+
+    /**
+     * Set number of beats in bar
+     *
+     * Default is `4`.
+     */
+    pub fn set_meter(&mut self, meter: usize) -> Status {
+        if meter == 0 {
+            return Err(Error::InvalidArg);
+        }
+        self.meter = meter;
+        Ok(())
+    }
+
+    /**
+     * Get number of beats in bar
+     */
+    pub fn get_meter(&self) -> usize {
+        self.meter
+    }
+
+    /**
+     * Analyze an input signal frame
+     *
+     * - `input` Input signal of size `hop_size`
+     *
+     * Returns `true` when the beat was detected in this frame.
+     */
+    pub fn do_result<'i, I>(&mut self, input: I) -> Result<bool>
+    where
+        I: Into<FVec<'i>>,
+    {
+        let input = input.into();
+        let input = input.as_slice();
+
+        let beat = self.tempo.do_result(input)? > 0.0;
+
+        self.pvoc.do_(input, self.grain.as_mut_slice())?;
+        self.filterbank
+            .do_(self.grain.as_slice(), self.bands.as_mut_slice())?;
+
+        if beat {
+            self.finish_beat();
+            self.beats.push((self.tempo.get_last(), Vec::new()));
+        }
+
+        for (energy, band) in self.energy.iter_mut().zip(&self.bands) {
+            *energy += band;
+        }
+        self.hops += 1;
+
+        Ok(beat)
+    }
+
+    /**
+     * Get the beats labeled with its positions in bar
+     */
+    pub fn beats(&self) -> Vec<BarBeat> {
+        let phase = self.phase();
+
+        self.beats
+            .iter()
+            .enumerate()
+            .map(|(index, (position, _))| BarBeat {
+                position: *position,
+                index: (index + self.meter - phase) % self.meter,
+            })
+            .collect()
+    }
+
+    /**
+     * Get the positions of bar starts in samples
+     */
+    pub fn bars(&self) -> Vec<usize> {
+        self.beats()
+            .into_iter()
+            .filter(|beat| beat.index == 0)
+            .map(|beat| beat.position)
+            .collect()
+    }
+
+    /**
+     * Forget the collected beats
+     */
+    pub fn reset(&mut self) {
+        self.beats.clear();
+        self.energy.iter_mut().for_each(|energy| *energy = 0.0);
+        self.hops = 0;
+    }
+
+    fn finish_beat(&mut self) {
+        if let Some((_, bands)) = self.beats.last_mut() {
+            let hops = self.hops.max(1) as Smpl;
+            *bands = self.energy.iter().map(|energy| energy / hops).collect();
+        }
+        self.energy.iter_mut().for_each(|energy| *energy = 0.0);
+        self.hops = 0;
+    }
+
+    /// Find the index of first downbeat
+    fn phase(&self) -> usize {
+        let hops = self.hops.max(1) as Smpl;
+        let current = self.energy.iter().map(|energy| energy / hops).collect();
+
+        let bands = self
+            .beats
+            .iter()
+            .enumerate()
+            .map(|(index, (_, bands))| {
+                if index + 1 == self.beats.len() {
+                    &current
+                } else {
+                    bands
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut scores = vec![(0.0, 0); self.meter];
+
+        for (index, pair) in bands.windows(2).enumerate() {
+            let (score, count) = &mut scores[(index + 1) % self.meter];
+            *score += novelty(pair[0], pair[1]);
+            *count += 1;
+        }
+
+        scores
+            .iter()
+            .map(|&(score, count)| {
+                if count > 0 {
+                    score / count as Smpl
+                } else {
+                    0.0
+                }
+            })
+            .enumerate()
+            .fold((0, Smpl::NEG_INFINITY), |best, (phase, score)| {
+                if score > best.1 {
+                    (phase, score)
+                } else {
+                    best
+                }
+            })
+            .0
+    }
+}
+
+/**
+ * Rise of log energy between beats weighted toward low bands
+ */
+fn novelty(previous: &[Smpl], current: &[Smpl]) -> Smpl {
+    previous
+        .iter()
+        .zip(current)
+        .enumerate()
+        .map(|(band, (previous, current))| {
+            let rise = (current + 1e-6).ln() - (previous + 1e-6).ln();
+            rise.max(0.0) / (band + 1) as Smpl
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::novelty;
+    use crate::*;
+
+    #[test]
+    fn test_novelty() {
+        assert_eq!(novelty(&[1.0, 1.0], &[0.5, 0.5]), 0.0);
+        assert!(novelty(&[1.0, 1.0], &[2.0, 1.0]) > novelty(&[1.0, 1.0], &[1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_downbeat() {
+        let mut downbeat = Downbeat::new(1024, 512, 44100)
+            .unwrap()
+            .with_meter(3)
+            .unwrap();
+
+        assert!(downbeat.set_meter(0).is_err());
+        assert_eq!(downbeat.get_meter(), 3);
+
+        let frame = [0.0; 512];
+        for _ in 0..100 {
+            assert!(!downbeat.do_result(frame.as_ref()).unwrap());
+        }
+        assert!(downbeat.bars().is_empty());
+    }
+}
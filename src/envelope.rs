@@ -0,0 +1,246 @@
+use crate::{level_lin, vec::FVec, Filter, Result, Smpl};
+
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/// The lowest level in dB scale
+const MIN_DB: Smpl = -120.0;
+
+/**
+ * Envelope values of single frame
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnvelopeFrame {
+    /// Smoothed root mean square of signal
+    pub rms: Smpl,
+    /// Smoothed level in dB SPL
+    pub db_spl: Smpl,
+    /// Smoothed A-weighted loudness in dB when weighting is enabled
+    pub loudness: Option<Smpl>,
+}
+
+/**
+ * Loudness envelope extractor
+ *
+ * Computes RMS level of each hop (see `level_lin()`), optionally
+ * the level of signal filtered by A-weighting filter, and smooths
+ * the values using the attack/release follower.
+ */
+pub struct Envelope {
+    hop_size: usize,
+    sample_rate: u32,
+    attack: Smpl,
+    release: Smpl,
+    weighting: Option<(Filter, Vec<Smpl>)>,
+    rms: Smpl,
+    weighted: Smpl,
+}
+
+impl Envelope {
+    /**
+     * Create envelope extractor
+     *
+     * - `hop_size` Number of samples in each frame
+     * - `sample_rate` Sampling rate of the input signal
+     */
+    pub fn new(hop_size: usize, sample_rate: u32) -> Self {
+        Self {
+            hop_size,
+            sample_rate,
+            attack: 0.0,
+            release: 0.0,
+            weighting: None,
+            rms: 0.0,
+            weighted: 0.0,
+        }
+    }
+
+    /**
+     * Set attack time, in millisecond
+     */
+    pub fn with_attack_ms(mut self, attack: Smpl) -> Self {
+        self.set_attack_ms(attack);
+        self
+    }
+
+    /**
+     * Set release time, in millisecond
+     */
+    pub fn with_release_ms(mut self, release: Smpl) -> Self {
+        self.set_release_ms(release);
+        self
+    }
+
+    /**
+     * Enable A-weighted loudness
+     */
+    pub fn with_a_weighting(mut self) -> Result<Self> {
+        self.set_a_weighting(true).map(|_| self)
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.hop_size
+    }
+
+    /**
+     * Set attack time, in millisecond
+     *
+     * The time needed to follow rising level. Default is `0` which means no smoothing.
+     */
+    pub fn set_attack_ms(&mut self, attack: Smpl) {
+        self.attack = attack;
+    }
+
+    /**
+     * Get attack time, in millisecond
+     */
+    pub fn get_attack_ms(&self) -> Smpl {
+        self.attack
+    }
+
+    /**
+     * Set release time, in millisecond
+     *
+     * The time needed to follow falling level. Default is `0` which means no smoothing.
+     */
+    pub fn set_release_ms(&mut self, release: Smpl) {
+        self.release = release;
+    }
+
+    /**
+     * Get release time, in millisecond
+     */
+    pub fn get_release_ms(&self) -> Smpl {
+        self.release
+    }
+
+    /**
+     * Enable or disable A-weighted loudness
+     *
+     * The sample rate should be supported by `Filter::new_a_weighting()`.
+     */
+    pub fn set_a_weighting(&mut self, enable: bool) -> Result<()> {
+        self.weighting = if enable {
+            let filter = Filter::new_a_weighting(self.sample_rate)?;
+            Some((filter, vec![0.; self.hop_size]))
+        } else {
+            None
+        };
+        self.weighted = 0.0;
+        Ok(())
+    }
+
+    /**
+     * Get A-weighted loudness state
+     */
+    pub fn get_a_weighting(&self) -> bool {
+        self.weighting.is_some()
+    }
+
+    /**
+     * Compute envelope of an input signal frame
+     *
+     * - `input` Input signal of size `hop_size`
+     */
+    pub fn do_result<'i, I>(&mut self, input: I) -> Result<EnvelopeFrame>
+    where
+        I: Into<FVec<'i>>,
+    {
+        let input = input.into();
+        input.check_size(self.hop_size)?;
+
+        self.rms = self.follow(self.rms, level_lin(input.as_slice()).sqrt());
+
+        let loudness = if let Some((filter, buffer)) = &mut self.weighting {
+            filter.do_outplace(input, buffer.as_mut_slice())?;
+            let level = level_lin(buffer.as_slice()).sqrt();
+            self.weighted = self.follow(self.weighted, level);
+            Some(to_db(self.weighted))
+        } else {
+            None
+        };
+
+        Ok(EnvelopeFrame {
+            rms: self.rms,
+            db_spl: to_db(self.rms),
+            loudness,
+        })
+    }
+
+    /**
+     * Reset the smoothing and filter state
+     */
+    pub fn reset(&mut self) {
+        self.rms = 0.0;
+        self.weighted = 0.0;
+        if let Some((filter, _)) = &mut self.weighting {
+            filter.reset();
+        }
+    }
+
+    fn follow(&self, state: Smpl, level: Smpl) -> Smpl {
+        let time = if level > state {
+            self.attack
+        } else {
+            self.release
+        };
+
+        if time <= 0.0 {
+            return level;
+        }
+
+        let coef = (-1000.0 * self.hop_size as Smpl / (time * self.sample_rate as Smpl)).exp();
+        coef * state + (1.0 - coef) * level
+    }
+}
+
+fn to_db(rms: Smpl) -> Smpl {
+    (20.0 * rms.log10()).max(MIN_DB)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_envelope() {
+        let mut envelope = Envelope::new(4, 44100);
+
+        let frame = envelope.do_result([0.5, -0.5, 0.5, -0.5].as_ref()).unwrap();
+        assert_eq!(frame.rms, 0.5);
+        assert!((frame.db_spl + 6.0206).abs() < 1e-3);
+        assert_eq!(frame.loudness, None);
+
+        let frame = envelope.do_result([0.0; 4].as_ref()).unwrap();
+        assert_eq!(frame.db_spl, -120.0);
+
+        assert!(envelope.do_result([0.0; 3].as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_envelope_smoothing() {
+        let mut envelope = Envelope::new(441, 44100)
+            .with_attack_ms(10.0)
+            .with_release_ms(100.0)
+            .with_a_weighting()
+            .unwrap();
+
+        let loud = vec![1.0; 441];
+        let quiet = vec![0.0; 441];
+
+        let attack = envelope.do_result(&loud).unwrap();
+        assert!((attack.rms - (1.0 - (-1.0 as Smpl).exp())).abs() < 1e-4);
+        assert!(attack.loudness.is_some());
+
+        let release = envelope.do_result(&quiet).unwrap();
+        assert!(release.rms > 0.5 * attack.rms);
+        assert!(release.rms < attack.rms);
+    }
+}
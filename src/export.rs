@@ -0,0 +1,945 @@
+/*!
+ * Export of analysis results
+ *
+ * The writers of onsets, beats, notes and pitches use layouts of _aubio_ command line tools.
+ * The text layout matches the output of `aubioonset`, `aubiotrack`,
+ * `aubionotes` and `aubiopitch` (times in seconds, `%f` formatting),
+ * so the scripts which parse that output can consume these results as is.
+ *
+ * The JSON layout contains the same rows as the text one: the plain arrays
+ * of times for onsets and beats, and the arrays of columns for notes and pitches.
+ *
+ * The `CsvWriter` streams arbitrary per-hop features as CSV table.
+ *
+ * The `JsonLinesWriter` streams detected events as JSON objects (one per line)
+ * for piping into other processes during live analysis.
+ *
+ * With __arrow__ feature the feature matrices and event tables can be converted
+ * to _Arrow_ record batches (and written to _Parquet_ files with __parquet__ feature).
+ *
+ * With __npy__ feature the matrices can be saved as _NumPy_ `.npy` files.
+ */
+
+use crate::{
+    offline::{BeatEvent, NoteEvent, OnsetEvent},
+    Smpl,
+};
+
+use core::fmt::{Result, Write};
+
+#[cfg(not(feature = "no_std"))]
+use std::io::{Error as IoError, ErrorKind, Result as IoResult, Write as IoWrite};
+
+#[cfg(not(feature = "no_std"))]
+use alloc::{format, string::String, vec::Vec};
+
+/**
+ * Output layout
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum OutputFormat {
+    /**
+     * Text lines like produced by command line tools
+     */
+    #[default]
+    Text,
+
+    /**
+     * JSON array of rows
+     */
+    Json,
+}
+
+/**
+ * Write rows of values in given layout
+ */
+fn write_rows<W, I, R>(out: &mut W, format: OutputFormat, separator: &str, rows: I) -> Result
+where
+    W: Write,
+    I: IntoIterator<Item = R>,
+    R: AsRef<[Smpl]>,
+{
+    match format {
+        OutputFormat::Text => {
+            for row in rows {
+                for (index, value) in row.as_ref().iter().enumerate() {
+                    if index > 0 {
+                        out.write_str(separator)?;
+                    }
+                    write!(out, "{:.6}", value)?;
+                }
+                out.write_char('\n')?;
+            }
+        }
+        OutputFormat::Json => {
+            out.write_char('[')?;
+            for (index, row) in rows.into_iter().enumerate() {
+                if index > 0 {
+                    out.write_str(", ")?;
+                }
+                let row = row.as_ref();
+                if row.len() != 1 {
+                    out.write_char('[')?;
+                }
+                for (index, value) in row.iter().enumerate() {
+                    if index > 0 {
+                        out.write_str(", ")?;
+                    }
+                    write!(out, "{:.6}", value)?;
+                }
+                if row.len() != 1 {
+                    out.write_char(']')?;
+                }
+            }
+            out.write_str("]\n")?;
+        }
+    }
+    Ok(())
+}
+
+/**
+ * Write onset times like `aubioonset`
+ *
+ * One time in seconds per line.
+ */
+pub fn write_onsets<W: Write>(out: &mut W, onsets: &[OnsetEvent], format: OutputFormat) -> Result {
+    write_rows(out, format, "", onsets.iter().map(|onset| [onset.time]))
+}
+
+/**
+ * Write beat times like `aubiotrack`
+ *
+ * One time in seconds per line.
+ */
+pub fn write_beats<W: Write>(out: &mut W, beats: &[BeatEvent], format: OutputFormat) -> Result {
+    write_rows(out, format, "", beats.iter().map(|beat| [beat.time]))
+}
+
+/**
+ * Write notes like `aubionotes`
+ *
+ * The MIDI pitch, start and end times in seconds separated by tabs per line.
+ */
+pub fn write_notes<W: Write>(out: &mut W, notes: &[NoteEvent], format: OutputFormat) -> Result {
+    write_rows(
+        out,
+        format,
+        "\t",
+        notes
+            .iter()
+            .map(|note| [note.pitch, note.time, note.time + note.duration]),
+    )
+}
+
+/**
+ * Write pitch track like `aubiopitch`
+ *
+ * - `pitches` Pitch values per hop
+ * - `hop_size` Number of samples between pitch values
+ * - `sample_rate` Sampling rate of signal
+ *
+ * The time in seconds and pitch separated by space per line.
+ */
+pub fn write_pitches<W: Write>(
+    out: &mut W,
+    pitches: &[Smpl],
+    hop_size: usize,
+    sample_rate: u32,
+    format: OutputFormat,
+) -> Result {
+    let period = hop_size as Smpl / sample_rate as Smpl;
+
+    write_rows(
+        out,
+        format,
+        " ",
+        pitches
+            .iter()
+            .enumerate()
+            .map(|(index, &pitch)| [index as Smpl * period, pitch]),
+    )
+}
+
+/**
+ * Column group of CSV table
+ */
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CsvColumn {
+    name: String,
+    width: usize,
+}
+
+/**
+ * Streaming CSV writer of per-hop features
+
+```
+use aubio::export::CsvWriter;
+
+let mut csv = CsvWriter::new(Vec::new())
+    .with_column("time")
+    .with_column("pitch")
+    .with_columns("mfcc", 3);
+
+csv.write_row(&[&[0.0], &[440.0], &[1.0, 0.5, 0.25]]).unwrap();
+
+assert_eq!(
+    String::from_utf8(csv.into_inner()).unwrap(),
+    "time,pitch,mfcc0,mfcc1,mfcc2\n0.000000,440.000000,1.000000,0.500000,0.250000\n"
+);
+```
+
+ * Each column group can hold single value (like time or pitch) or several
+ * values (like MFCC coefficients or band energies) which are numbered in header.
+ * The header is written before the first row.
+ */
+#[cfg(not(feature = "no_std"))]
+pub struct CsvWriter<W> {
+    out: W,
+    columns: Vec<CsvColumn>,
+    separator: char,
+    precision: usize,
+    header: bool,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<W: IoWrite> CsvWriter<W> {
+    /**
+     * Create writer without columns
+     */
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            columns: Vec::new(),
+            separator: ',',
+            precision: 6,
+            header: false,
+        }
+    }
+
+    /**
+     * Add column with single value
+     */
+    pub fn with_column(self, name: impl Into<String>) -> Self {
+        self.with_columns(name, 1)
+    }
+
+    /**
+     * Add group of `width` columns
+     *
+     * The columns are named by `name` followed by index.
+     */
+    pub fn with_columns(mut self, name: impl Into<String>, width: usize) -> Self {
+        self.add_columns(name, width);
+        self
+    }
+
+    /**
+     * Set separator of values
+     */
+    pub fn with_separator(mut self, separator: char) -> Self {
+        self.set_separator(separator);
+        self
+    }
+
+    /**
+     * Set number of digits after decimal point
+     */
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.set_precision(precision);
+        self
+    }
+
+    /**
+     * Add group of `width` columns
+     *
+     * The columns cannot be added after the header is written.
+     */
+    pub fn add_columns(&mut self, name: impl Into<String>, width: usize) {
+        assert!(!self.header, "columns cannot be added after the first row");
+        self.columns.push(CsvColumn {
+            name: name.into(),
+            width,
+        });
+    }
+
+    /**
+     * Set separator of values
+     */
+    pub fn set_separator(&mut self, separator: char) {
+        self.separator = separator;
+    }
+
+    /**
+     * Get separator of values
+     */
+    pub fn get_separator(&self) -> char {
+        self.separator
+    }
+
+    /**
+     * Set number of digits after decimal point
+     */
+    pub fn set_precision(&mut self, precision: usize) {
+        self.precision = precision;
+    }
+
+    /**
+     * Get number of digits after decimal point
+     */
+    pub fn get_precision(&self) -> usize {
+        self.precision
+    }
+
+    /**
+     * Get names of columns as written to header
+     */
+    pub fn get_header(&self) -> Vec<String> {
+        self.columns
+            .iter()
+            .flat_map(|column| {
+                (0..column.width).map(move |index| {
+                    if column.width == 1 {
+                        column.name.clone()
+                    } else {
+                        format!("{}{}", column.name, index)
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /**
+     * Write row of values
+     *
+     * - `fields` Values for each column group in order of adding
+     *
+     * The number of fields and its lengths should match the columns.
+     */
+    pub fn write_row(&mut self, fields: &[&[Smpl]]) -> IoResult<()> {
+        if fields.len() != self.columns.len()
+            || fields
+                .iter()
+                .zip(&self.columns)
+                .any(|(values, column)| values.len() != column.width)
+        {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "row does not match columns",
+            ));
+        }
+
+        if !self.header {
+            self.write_header()?;
+        }
+
+        let mut first = true;
+
+        for value in fields.iter().flat_map(|values| values.iter()) {
+            if !first {
+                write!(self.out, "{}", self.separator)?;
+            }
+            first = false;
+            write!(self.out, "{:.*}", self.precision, value)?;
+        }
+
+        writeln!(self.out)
+    }
+
+    /**
+     * Flush output
+     */
+    pub fn flush(&mut self) -> IoResult<()> {
+        self.out.flush()
+    }
+
+    /**
+     * Unwrap output
+     */
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    fn write_header(&mut self) -> IoResult<()> {
+        let mut separator = [0u8; 4];
+        let separator = &*self.separator.encode_utf8(&mut separator);
+
+        writeln!(self.out, "{}", self.get_header().join(separator))?;
+        self.header = true;
+
+        Ok(())
+    }
+}
+
+/**
+ * Streaming JSON-lines writer of events
+
+```
+use aubio::{export::JsonLinesWriter, offline::OnsetEvent};
+
+let mut json = JsonLinesWriter::new(Vec::new());
+
+json.write_onset(&OnsetEvent { position: 22050, time: 0.5, descriptor: 1.0 }).unwrap();
+json.write_event(1.0, "level", &[("rms", 0.25)]).unwrap();
+
+assert_eq!(
+    String::from_utf8(json.into_inner()).unwrap(),
+    "{\"time\":0.500000,\"kind\":\"onset\",\"payload\":{\"position\":22050,\"descriptor\":1}}\n\
+     {\"time\":1,\"kind\":\"level\",\"payload\":{\"rms\":0.250000}}\n"
+);
+```
+
+ * Each event is written as single line object with `time` (seconds), `kind`
+ * and `payload` fields, so the output can be consumed by tools like `jq`
+ * or log collectors while the analysis is running. The integral values is
+ * written without fraction part and the non-finite values is written as `null`.
+ *
+ * The output is flushed after each event by default, disable auto-flush when
+ * the output is file or buffered writer.
+ */
+#[cfg(not(feature = "no_std"))]
+pub struct JsonLinesWriter<W> {
+    out: W,
+    precision: usize,
+    autoflush: bool,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<W: IoWrite> JsonLinesWriter<W> {
+    /**
+     * Create writer
+     */
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            precision: 6,
+            autoflush: true,
+        }
+    }
+
+    /**
+     * Set number of digits after decimal point
+     */
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.set_precision(precision);
+        self
+    }
+
+    /**
+     * Enable or disable flushing after each event
+     */
+    pub fn with_autoflush(mut self, autoflush: bool) -> Self {
+        self.set_autoflush(autoflush);
+        self
+    }
+
+    /**
+     * Set number of digits after decimal point
+     */
+    pub fn set_precision(&mut self, precision: usize) {
+        self.precision = precision;
+    }
+
+    /**
+     * Get number of digits after decimal point
+     */
+    pub fn get_precision(&self) -> usize {
+        self.precision
+    }
+
+    /**
+     * Enable or disable flushing after each event
+     */
+    pub fn set_autoflush(&mut self, autoflush: bool) {
+        self.autoflush = autoflush;
+    }
+
+    /**
+     * Get flushing after each event
+     */
+    pub fn get_autoflush(&self) -> bool {
+        self.autoflush
+    }
+
+    /**
+     * Write event
+     *
+     * - `time` Time of event in seconds
+     * - `kind` Kind of event (like `onset` or `beat`)
+     * - `payload` Named values of event
+     */
+    #[allow(clippy::unnecessary_cast)]
+    pub fn write_event(&mut self, time: Smpl, kind: &str, payload: &[(&str, f64)]) -> IoResult<()> {
+        let mut line = String::from("{\"time\":");
+        write_json_number(&mut line, time as f64, self.precision);
+        line.push_str(",\"kind\":");
+        write_json_string(&mut line, kind);
+        line.push_str(",\"payload\":{");
+
+        for (index, (name, value)) in payload.iter().enumerate() {
+            if index > 0 {
+                line.push(',');
+            }
+            write_json_string(&mut line, name);
+            line.push(':');
+            write_json_number(&mut line, *value, self.precision);
+        }
+
+        line.push_str("}}\n");
+
+        self.out.write_all(line.as_bytes())?;
+
+        if self.autoflush {
+            self.out.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Write onset event with `position` and `descriptor` values
+     */
+    #[allow(clippy::unnecessary_cast)]
+    pub fn write_onset(&mut self, onset: &OnsetEvent) -> IoResult<()> {
+        self.write_event(
+            onset.time,
+            "onset",
+            &[
+                ("position", onset.position as f64),
+                ("descriptor", onset.descriptor as f64),
+            ],
+        )
+    }
+
+    /**
+     * Write beat event with `position` and `confidence` values
+     */
+    #[allow(clippy::unnecessary_cast)]
+    pub fn write_beat(&mut self, beat: &BeatEvent) -> IoResult<()> {
+        self.write_event(
+            beat.time,
+            "beat",
+            &[
+                ("position", beat.position as f64),
+                ("confidence", beat.confidence as f64),
+            ],
+        )
+    }
+
+    /**
+     * Write note event with `pitch`, `velocity` and `duration` values
+     */
+    #[allow(clippy::unnecessary_cast)]
+    pub fn write_note(&mut self, note: &NoteEvent) -> IoResult<()> {
+        self.write_event(
+            note.time,
+            "note",
+            &[
+                ("pitch", note.pitch as f64),
+                ("velocity", note.velocity as f64),
+                ("duration", note.duration as f64),
+            ],
+        )
+    }
+
+    /**
+     * Flush output
+     */
+    pub fn flush(&mut self) -> IoResult<()> {
+        self.out.flush()
+    }
+
+    /**
+     * Unwrap output
+     */
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+/**
+ * Write number as JSON value
+ *
+ * The integers is written without fraction part.
+ */
+#[cfg(not(feature = "no_std"))]
+fn write_json_number(out: &mut String, value: f64, precision: usize) {
+    use core::fmt::Write;
+
+    if !value.is_finite() {
+        out.push_str("null");
+    } else if value.fract() == 0.0 && value.abs() < 1e15 {
+        let _ = write!(out, "{}", value as i64);
+    } else {
+        let _ = write!(out, "{:.*}", precision, value);
+    }
+}
+
+/**
+ * Write string as JSON value with escaping
+ */
+#[cfg(not(feature = "no_std"))]
+fn write_json_string(out: &mut String, value: &str) {
+    use core::fmt::Write;
+
+    out.push('"');
+    for chr in value.chars() {
+        match chr {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            chr if (chr as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", chr as u32);
+            }
+            chr => out.push(chr),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(feature = "arrow")]
+pub use arrow_impl::*;
+
+#[cfg(feature = "arrow")]
+mod arrow_impl {
+    use crate::{
+        offline::{BeatEvent, NoteEvent, OnsetEvent},
+        vec::FMatBuf,
+    };
+    use alloc::{format, sync::Arc, vec, vec::Vec};
+    use arrow_array::{ArrayRef, RecordBatch, UInt64Array};
+    use arrow_schema::{ArrowError, DataType, Field, Schema};
+
+    #[cfg(not(feature = "double"))]
+    use arrow_array::Float32Array as SmplArray;
+    #[cfg(feature = "double")]
+    use arrow_array::Float64Array as SmplArray;
+
+    #[cfg(not(feature = "double"))]
+    const SMPL_TYPE: DataType = DataType::Float32;
+    #[cfg(feature = "double")]
+    const SMPL_TYPE: DataType = DataType::Float64;
+
+    fn batch(columns: Vec<(Field, ArrayRef)>) -> Result<RecordBatch, ArrowError> {
+        let (fields, arrays): (Vec<_>, Vec<_>) = columns.into_iter().unzip();
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)
+    }
+
+    fn smpl_column(name: &str, values: impl Iterator<Item = crate::Smpl>) -> (Field, ArrayRef) {
+        (
+            Field::new(name, SMPL_TYPE, false),
+            Arc::new(SmplArray::from_iter_values(values)),
+        )
+    }
+
+    fn position_column(values: impl Iterator<Item = usize>) -> (Field, ArrayRef) {
+        (
+            Field::new("position", DataType::UInt64, false),
+            Arc::new(UInt64Array::from_iter_values(
+                values.map(|value| value as u64),
+            )),
+        )
+    }
+
+    /**
+     * Convert feature matrix to record batch
+     *
+     * - `matrix` Features with frame per row (like spectrogram or MFCC matrix)
+     * - `prefix` Prefix of column names, the columns are named by prefix followed by index
+     *
+     * Each row of matrix becomes row of batch.
+     */
+    pub fn matrix_to_batch(matrix: &FMatBuf, prefix: &str) -> Result<RecordBatch, ArrowError> {
+        batch(
+            (0..matrix.length())
+                .map(|index| {
+                    smpl_column(
+                        &format!("{}{}", prefix, index),
+                        matrix.rows().map(|row| row[index]),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /**
+     * Convert onsets to record batch with `position`, `time` and `descriptor` columns
+     */
+    pub fn onsets_to_batch(onsets: &[OnsetEvent]) -> Result<RecordBatch, ArrowError> {
+        batch(vec![
+            position_column(onsets.iter().map(|onset| onset.position)),
+            smpl_column("time", onsets.iter().map(|onset| onset.time)),
+            smpl_column("descriptor", onsets.iter().map(|onset| onset.descriptor)),
+        ])
+    }
+
+    /**
+     * Convert beats to record batch with `position`, `time` and `confidence` columns
+     */
+    pub fn beats_to_batch(beats: &[BeatEvent]) -> Result<RecordBatch, ArrowError> {
+        batch(vec![
+            position_column(beats.iter().map(|beat| beat.position)),
+            smpl_column("time", beats.iter().map(|beat| beat.time)),
+            smpl_column("confidence", beats.iter().map(|beat| beat.confidence)),
+        ])
+    }
+
+    /**
+     * Convert notes to record batch with `pitch`, `velocity`, `time` and `duration` columns
+     */
+    pub fn notes_to_batch(notes: &[NoteEvent]) -> Result<RecordBatch, ArrowError> {
+        batch(vec![
+            smpl_column("pitch", notes.iter().map(|note| note.pitch)),
+            smpl_column("velocity", notes.iter().map(|note| note.velocity)),
+            smpl_column("time", notes.iter().map(|note| note.time)),
+            smpl_column("duration", notes.iter().map(|note| note.duration)),
+        ])
+    }
+
+    /**
+     * Write record batch to _Parquet_ file
+     */
+    #[cfg(feature = "parquet")]
+    pub fn write_parquet(
+        path: impl AsRef<std::path::Path>,
+        batch: &RecordBatch,
+    ) -> Result<(), parquet::errors::ParquetError> {
+        let file = std::fs::File::create(path)?;
+        let mut writer = parquet::arrow::ArrowWriter::try_new(file, batch.schema(), None)?;
+        writer.write(batch)?;
+        writer.close()?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "npy")]
+pub use npy_impl::*;
+
+#[cfg(feature = "npy")]
+mod npy_impl {
+    use crate::{vec::FMatBuf, Smpl};
+    use alloc::format;
+    use std::{
+        fs::File,
+        io::{BufWriter, Result, Write},
+        path::Path,
+    };
+
+    #[cfg(not(feature = "double"))]
+    const DESCR: &str = "<f4";
+    #[cfg(feature = "double")]
+    const DESCR: &str = "<f8";
+
+    /// Magic string and version 1.0
+    const MAGIC: &[u8] = b"\x93NUMPY\x01\x00";
+
+    /// Alignment of data
+    const ALIGN: usize = 64;
+
+    fn write_array<W: Write>(mut out: W, shape: &[usize], data: &[Smpl]) -> Result<()> {
+        let mut header = format!("{{'descr': '{}', 'fortran_order': False, 'shape': (", DESCR);
+        for dim in shape {
+            header += &format!("{},", dim);
+        }
+        if shape.len() > 1 {
+            // single element tuple only needs trailing comma
+            header.pop();
+        }
+        header += "), }";
+
+        // header is padded by spaces and terminated by newline
+        let length = MAGIC.len() + 2 + header.len() + 1;
+        header += &" ".repeat((ALIGN - length % ALIGN) % ALIGN);
+        header.push('\n');
+
+        out.write_all(MAGIC)?;
+        out.write_all(&(header.len() as u16).to_le_bytes())?;
+        out.write_all(header.as_bytes())?;
+
+        for value in data {
+            out.write_all(&value.to_le_bytes())?;
+        }
+
+        out.flush()
+    }
+
+    /**
+     * Write matrix in _NumPy_ `.npy` format
+     *
+     * The array has `(height, length)` shape, so the spectrograms and feature
+     * matrices have frame per row. The data type is `float32` or `float64`
+     * with __double__ feature.
+     */
+    pub fn write_npy<W: Write>(out: W, matrix: &FMatBuf) -> Result<()> {
+        write_array(out, &[matrix.height(), matrix.length()], matrix.as_slice())
+    }
+
+    /**
+     * Write vector in _NumPy_ `.npy` format as one-dimensional array
+     */
+    pub fn write_npy_vec<W: Write>(out: W, data: &[Smpl]) -> Result<()> {
+        write_array(out, &[data.len()], data)
+    }
+
+    /**
+     * Save matrix to _NumPy_ `.npy` file
+     */
+    pub fn save_npy(path: impl AsRef<Path>, matrix: &FMatBuf) -> Result<()> {
+        write_npy(BufWriter::new(File::create(path)?), matrix)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn onsets() -> Vec<OnsetEvent> {
+        vec![
+            OnsetEvent {
+                position: 0,
+                time: 0.0,
+                descriptor: 1.0,
+            },
+            OnsetEvent {
+                position: 22050,
+                time: 0.5,
+                descriptor: 1.0,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_onsets() {
+        let mut out = String::new();
+        write_onsets(&mut out, &onsets(), OutputFormat::Text).unwrap();
+        assert_eq!(out, "0.000000\n0.500000\n");
+
+        let mut out = String::new();
+        write_onsets(&mut out, &onsets(), OutputFormat::Json).unwrap();
+        assert_eq!(out, "[0.000000, 0.500000]\n");
+
+        let mut out = String::new();
+        write_beats(&mut out, &[], OutputFormat::Json).unwrap();
+        assert_eq!(out, "[]\n");
+    }
+
+    #[test]
+    fn test_notes() {
+        let notes = [NoteEvent {
+            pitch: 60.0,
+            velocity: 100.0,
+            time: 0.25,
+            duration: 0.5,
+        }];
+
+        let mut out = String::new();
+        write_notes(&mut out, &notes, OutputFormat::Text).unwrap();
+        assert_eq!(out, "60.000000\t0.250000\t0.750000\n");
+
+        let mut out = String::new();
+        write_notes(&mut out, &notes, OutputFormat::Json).unwrap();
+        assert_eq!(out, "[[60.000000, 0.250000, 0.750000]]\n");
+    }
+
+    #[test]
+    fn test_pitches() {
+        let mut out = String::new();
+        write_pitches(&mut out, &[0.0, 440.0], 256, 512, OutputFormat::Text).unwrap();
+        assert_eq!(out, "0.000000 0.000000\n0.500000 440.000000\n");
+
+        let mut out = String::new();
+        write_pitches(&mut out, &[440.0], 256, 512, OutputFormat::Json).unwrap();
+        assert_eq!(out, "[[0.000000, 440.000000]]\n");
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    #[test]
+    fn test_csv() {
+        let mut csv = CsvWriter::new(Vec::new())
+            .with_column("time")
+            .with_columns("band", 2)
+            .with_separator(';')
+            .with_precision(2);
+
+        assert_eq!(csv.get_header(), vec!["time", "band0", "band1"]);
+        assert!(csv.write_row(&[&[0.0], &[1.0]]).is_err());
+        assert!(csv.write_row(&[&[0.0]]).is_err());
+
+        csv.write_row(&[&[0.0], &[1.0, 0.5]]).unwrap();
+        csv.write_row(&[&[0.5], &[0.25, 0.0]]).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv.into_inner()).unwrap(),
+            "time;band0;band1\n0.00;1.00;0.50\n0.50;0.25;0.00\n"
+        );
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    #[test]
+    fn test_json_lines() {
+        let mut json = JsonLinesWriter::new(Vec::new()).with_precision(2);
+
+        json.write_beat(&BeatEvent {
+            position: 44100,
+            time: 1.0,
+            confidence: 0.5,
+        })
+        .unwrap();
+        json.write_event(0.25, "tag \"a\"\n", &[("nan", f64::NAN), ("count", 3.0)])
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(json.into_inner()).unwrap(),
+            "{\"time\":1,\"kind\":\"beat\",\"payload\":{\"position\":44100,\"confidence\":0.50}}\n\
+             {\"time\":0.25,\"kind\":\"tag \\\"a\\\"\\n\",\"payload\":{\"nan\":null,\"count\":3}}\n"
+        );
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_arrow() {
+        use crate::vec::FMatBuf;
+
+        let matrix = FMatBuf::from_vec(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let batch = matrix_to_batch(&matrix, "bin").unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(batch.num_columns(), 2);
+        assert_eq!(batch.schema().field(1).name(), "bin1");
+
+        let batch = onsets_to_batch(&onsets()).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().field(0).name(), "position");
+    }
+
+    #[cfg(feature = "npy")]
+    #[test]
+    fn test_npy() {
+        use crate::vec::FMatBuf;
+
+        let matrix = FMatBuf::from_vec(2, 3, vec![0.0; 6]).unwrap();
+        let mut out = Vec::new();
+        write_npy(&mut out, &matrix).unwrap();
+
+        let header_len = u16::from_le_bytes([out[8], out[9]]) as usize;
+        let header = core::str::from_utf8(&out[10..10 + header_len]).unwrap();
+        assert_eq!(&out[..8], b"\x93NUMPY\x01\x00");
+        assert_eq!((10 + header_len) % 64, 0);
+        assert!(header.starts_with("{'descr': '<f"));
+        assert!(header.contains("'shape': (2,3), }"));
+        assert!(header.ends_with('\n'));
+        assert_eq!(
+            out.len(),
+            10 + header_len + 6 * core::mem::size_of::<Smpl>()
+        );
+
+        let mut out = Vec::new();
+        write_npy_vec(&mut out, &[1.0]).unwrap();
+        let header_len = u16::from_le_bytes([out[8], out[9]]) as usize;
+        let header = core::str::from_utf8(&out[10..10 + header_len]).unwrap();
+        assert!(header.contains("'shape': (1,), }"));
+    }
+}
@@ -0,0 +1,368 @@
+use crate::{MediaSource, Smpl};
+
+use ffmpeg_next::{
+    codec,
+    format::{self, context::Input, sample::Type as SampleType, Sample},
+    frame::Audio,
+    media::Type as MediaType,
+    software::resampling,
+    ChannelLayout, Packet,
+};
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    path::Path,
+};
+
+use alloc::{vec, vec::Vec};
+
+#[cfg(not(feature = "double"))]
+const SAMPLE_FORMAT: Sample = Sample::F32(SampleType::Packed);
+
+#[cfg(feature = "double")]
+const SAMPLE_FORMAT: Sample = Sample::F64(SampleType::Packed);
+
+/**
+ * FFmpeg decoding error
+ */
+#[derive(Debug)]
+pub enum FfmpegError {
+    /**
+     * Error reported by FFmpeg
+     */
+    Ffmpeg(ffmpeg_next::Error),
+
+    /**
+     * No audio streams in media
+     */
+    NoTrack,
+
+    /**
+     * Frames size should be greater than zero
+     */
+    InvalidHop,
+}
+
+impl StdError for FfmpegError {}
+
+impl Display for FfmpegError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        use self::FfmpegError::*;
+        match self {
+            Ffmpeg(error) => write!(f, "decoding error: {}", error),
+            NoTrack => "no audio track".fmt(f),
+            InvalidHop => "invalid hop size".fmt(f),
+        }
+    }
+}
+
+impl From<ffmpeg_next::Error> for FfmpegError {
+    fn from(error: ffmpeg_next::Error) -> Self {
+        FfmpegError::Ffmpeg(error)
+    }
+}
+
+/**
+ * Media decoder based on _FFmpeg_
+ *
+ * Works like `SymphoniaSource` but supports every container, codec and protocol
+ * known to _FFmpeg_. Decodes the best audio stream, converts it to mono `Smpl`
+ * samples at the target sampling rate using _libswresample_ and slices the signal
+ * into frames of `hop_size` samples ready to be passed to detectors.
+
+```no_run
+use aubio::{FfmpegSource, Onset, OnsetMode};
+
+let mut source = FfmpegSource::open("https://example.com/stream.mp3", Some(44100), 512).unwrap();
+let mut onset = Onset::new(OnsetMode::Hfc, 1024, 512, source.get_samplerate()).unwrap();
+
+while let Some(frame) = source.next_frame() {
+    if onset.do_result(frame).unwrap() > 0.0 {
+        println!("onset at {}", onset.get_last_s());
+    }
+}
+```
+ */
+pub struct FfmpegSource {
+    input: Input,
+    decoder: codec::decoder::Audio,
+    resampler: resampling::Context,
+    stream_index: usize,
+    sample_rate: u32,
+    channels: usize,
+    decoded: Audio,
+    resampled: Audio,
+    pending: Vec<Smpl>,
+    offset: usize,
+    frame: Vec<Smpl>,
+    frames: usize,
+    eof_sent: bool,
+    finished: bool,
+    error: Option<FfmpegError>,
+}
+
+impl FfmpegSource {
+    /**
+     * Open media file or URL
+     *
+     * - `path` Path to media file or URL of stream
+     * - `sample_rate` Target sampling rate or `None` to keep original
+     * - `hop_size` Number of samples in each frame
+     */
+    pub fn open(
+        path: impl AsRef<Path>,
+        sample_rate: Option<u32>,
+        hop_size: usize,
+    ) -> Result<Self, FfmpegError> {
+        if hop_size == 0 {
+            return Err(FfmpegError::InvalidHop);
+        }
+
+        ffmpeg_next::init()?;
+
+        Self::new(format::input(&path)?, sample_rate, hop_size)
+    }
+
+    /**
+     * Create decoder for the best audio stream of opened input
+     *
+     * - `input` Input context
+     * - `sample_rate` Target sampling rate or `None` to keep original
+     * - `hop_size` Number of samples in each frame
+     */
+    pub fn new(
+        input: Input,
+        sample_rate: Option<u32>,
+        hop_size: usize,
+    ) -> Result<Self, FfmpegError> {
+        if hop_size == 0 {
+            return Err(FfmpegError::InvalidHop);
+        }
+
+        let stream = input
+            .streams()
+            .best(MediaType::Audio)
+            .ok_or(FfmpegError::NoTrack)?;
+        let stream_index = stream.index();
+
+        let decoder = codec::context::Context::from_parameters(stream.parameters())?
+            .decoder()
+            .audio()?;
+
+        let channels = (decoder.channels() as usize).max(1);
+        let layout = if decoder.channel_layout().is_empty() {
+            ChannelLayout::default(channels as i32)
+        } else {
+            decoder.channel_layout()
+        };
+        let source_rate = decoder.rate();
+        let sample_rate = sample_rate.unwrap_or(source_rate);
+
+        let resampler = resampling::Context::get(
+            decoder.format(),
+            layout,
+            source_rate,
+            SAMPLE_FORMAT,
+            ChannelLayout::MONO,
+            sample_rate,
+        )?;
+
+        Ok(Self {
+            input,
+            decoder,
+            resampler,
+            stream_index,
+            sample_rate,
+            channels,
+            decoded: Audio::empty(),
+            resampled: Audio::empty(),
+            pending: Vec::new(),
+            offset: 0,
+            frame: vec![0.; hop_size],
+            frames: 0,
+            eof_sent: false,
+            finished: false,
+            error: None,
+        })
+    }
+
+    /**
+     * Get sampling rate of produced samples
+     */
+    pub fn get_samplerate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /**
+     * Get number of channels of decoded stream
+     */
+    pub fn get_channels(&self) -> usize {
+        self.channels
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.frame.len()
+    }
+
+    /**
+     * Get position of the next frame in samples
+     */
+    pub fn get_position(&self) -> usize {
+        self.frames * self.frame.len()
+    }
+
+    /**
+     * Get the next frame of mono samples
+     *
+     * The last frame is padded by zeros. Returns `None` at the end of stream
+     * or when decoding failed (see `FfmpegSource::take_error()`).
+     */
+    pub fn next_frame(&mut self) -> Option<&[Smpl]> {
+        let hop_size = self.frame.len();
+        let mut filled = 0;
+
+        while filled < hop_size {
+            if self.offset >= self.pending.len() && !self.decode_frame() {
+                break;
+            }
+
+            let count = (hop_size - filled).min(self.pending.len() - self.offset);
+            self.frame[filled..filled + count]
+                .copy_from_slice(&self.pending[self.offset..self.offset + count]);
+            filled += count;
+            self.offset += count;
+        }
+
+        if filled == 0 {
+            return None;
+        }
+
+        for sample in &mut self.frame[filled..] {
+            *sample = 0.0;
+        }
+        self.frames += 1;
+
+        Some(&self.frame)
+    }
+
+    /**
+     * Take the error which stopped decoding
+     */
+    pub fn take_error(&mut self) -> Option<FfmpegError> {
+        self.error.take()
+    }
+
+    /**
+     * Decode and resample the next frame of stream into pending samples
+     *
+     * Returns `false` when no more samples can be decoded.
+     */
+    fn decode_frame(&mut self) -> bool {
+        while !self.finished {
+            if self.decoder.receive_frame(&mut self.decoded).is_ok() {
+                if let Err(error) = self.resampler.run(&self.decoded, &mut self.resampled) {
+                    self.fail(error);
+                    break;
+                }
+            } else if !self.eof_sent {
+                self.send_packet();
+                continue;
+            } else {
+                // drain the samples delayed by resampler
+                self.finished = true;
+                match self.resampler.flush(&mut self.resampled) {
+                    Ok(_) => (),
+                    Err(error) => {
+                        self.fail(error);
+                        break;
+                    }
+                }
+            }
+
+            self.pending.clear();
+            self.pending
+                .extend_from_slice(&self.resampled.plane::<Smpl>(0)[..self.resampled.samples()]);
+            self.offset = 0;
+
+            if !self.pending.is_empty() {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /**
+     * Read the next packet of stream and send it to decoder
+     */
+    fn send_packet(&mut self) {
+        let mut packet = Packet::empty();
+
+        loop {
+            match packet.read(&mut self.input) {
+                Ok(()) if packet.stream() == self.stream_index => {
+                    // the malformed packets are skipped
+                    let _ = self.decoder.send_packet(&packet);
+                    return;
+                }
+                Ok(()) => continue,
+                Err(ffmpeg_next::Error::Eof) => break,
+                Err(error) => {
+                    self.fail(error);
+                    return;
+                }
+            }
+        }
+
+        self.eof_sent = true;
+        if let Err(error) = self.decoder.send_eof() {
+            self.fail(error);
+        }
+    }
+
+    fn fail(&mut self, error: ffmpeg_next::Error) {
+        self.error = Some(error.into());
+        self.finished = true;
+    }
+}
+
+impl MediaSource for FfmpegSource {
+    type Error = FfmpegError;
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /**
+     * Read the next frame of `hop_size` samples
+     *
+     * The last frame is padded by zeros.
+     */
+    fn read_frame(&mut self) -> Result<Option<&[Smpl]>, Self::Error> {
+        if self.next_frame().is_none() {
+            return self.take_error().map_or(Ok(None), Err);
+        }
+        Ok(Some(&self.frame))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_invalid_hop() {
+        assert!(matches!(
+            FfmpegSource::open("missing.ogg", None, 0),
+            Err(FfmpegError::InvalidHop)
+        ));
+    }
+}
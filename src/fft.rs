@@ -1,6 +1,6 @@
 use crate::{
-    check_init, ffi,
-    vec::{CVec, CVecMut, CVecNormMut, CVecPhasMut, FVec, FVecMut},
+    check_buf_size, ffi, try_init,
+    vec::{CVec, CVecMut, CVecNormMut, CVecPhasMut, FVec, FVecMut, Memory},
     Result, Status,
 };
 
@@ -28,18 +28,28 @@ impl Drop for FFT {
     }
 }
 
+unsafe impl Send for FFT {}
+unsafe impl Sync for FFT {}
+
 impl FFT {
     /**
      * Create new FFT computation object
      */
     pub fn new(win_size: usize) -> Result<Self> {
-        let fft = unsafe { ffi::new_aubio_fft(win_size as ffi::uint_t) };
+        check_buf_size("win_size", win_size)?;
 
-        check_init(fft)?;
+        let fft = try_init(|| unsafe { ffi::new_aubio_fft(win_size as ffi::uint_t) })?;
 
         Ok(Self { fft, win_size })
     }
 
+    /**
+     * Create new FFT computation object with the same window size
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        Self::new(self.win_size)
+    }
+
     /**
      * Get window size
      */
@@ -66,6 +76,7 @@ impl FFT {
         let mut spectrum = spectrum.into();
 
         input.check_size(self.get_win())?;
+        input.check_overlap(&spectrum)?;
 
         unsafe {
             ffi::aubio_fft_do(self.fft, input.as_ptr(), spectrum.as_mut_ptr());
@@ -85,6 +96,7 @@ impl FFT {
         let mut output = output.into();
 
         output.check_size(self.get_win())?;
+        spectrum.check_overlap(&output)?;
 
         unsafe {
             ffi::aubio_fft_rdo(self.fft, spectrum.as_ptr(), output.as_mut_ptr());
@@ -105,6 +117,7 @@ impl FFT {
 
         input.check_size(self.get_win())?;
         compspec.check_size(self.get_win())?;
+        input.check_overlap(&compspec)?;
 
         unsafe {
             ffi::aubio_fft_do_complex(self.fft, input.as_ptr(), compspec.as_mut_ptr());
@@ -125,6 +138,7 @@ impl FFT {
 
         compspec.check_size(self.get_win())?;
         output.check_size(self.get_win())?;
+        compspec.check_overlap(&output)?;
 
         unsafe {
             ffi::aubio_fft_rdo_complex(self.fft, compspec.as_ptr(), output.as_mut_ptr());
@@ -144,6 +158,7 @@ impl FFT {
         let mut spectrum = spectrum.into();
 
         spectrum.check_size(compspec.size())?;
+        compspec.check_overlap(&spectrum)?;
 
         unsafe {
             ffi::aubio_fft_get_spectrum(compspec.as_ptr(), spectrum.as_mut_ptr());
@@ -163,6 +178,7 @@ impl FFT {
         let mut compspec = compspec.into();
 
         compspec.check_size(spectrum.size())?;
+        spectrum.check_overlap(&compspec)?;
 
         unsafe {
             ffi::aubio_fft_get_realimag(spectrum.as_ptr(), compspec.as_mut_ptr());
@@ -182,6 +198,7 @@ impl FFT {
         let mut spectrum_phas = spectrum_phas.into();
 
         spectrum_phas.check_size(compspec.size())?;
+        compspec.check_overlap(&*spectrum_phas)?;
 
         unsafe {
             ffi::aubio_fft_get_phas(compspec.as_ptr(), spectrum_phas.as_mut_ptr());
@@ -201,6 +218,7 @@ impl FFT {
         let mut spectrum_norm = spectrum_norm.into();
 
         spectrum_norm.check_size(compspec.size())?;
+        compspec.check_overlap(&*spectrum_norm)?;
 
         unsafe {
             ffi::aubio_fft_get_norm(compspec.as_ptr(), spectrum_norm.as_mut_ptr());
@@ -220,6 +238,7 @@ impl FFT {
         let mut compspec = compspec.into();
 
         compspec.check_size(spectrum.size())?;
+        spectrum.check_overlap(&compspec)?;
 
         unsafe {
             ffi::aubio_fft_get_imag(spectrum.as_ptr(), compspec.as_mut_ptr());
@@ -239,6 +258,7 @@ impl FFT {
         let mut compspec = compspec.into();
 
         compspec.check_size(spectrum.size())?;
+        spectrum.check_overlap(&compspec)?;
 
         unsafe {
             ffi::aubio_fft_get_real(spectrum.as_ptr(), compspec.as_mut_ptr());
@@ -0,0 +1,383 @@
+use crate::{
+    check_non_zero, ffi, try_init,
+    vec::{FVec, FVecMut, LVec, LVecMut, Memory},
+    Error, LSmpl, Result, Smpl, Status,
+};
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/**
+ * Digital filter
+ *
+ * This object stores a digital filter of order `n`.
+ * It contains the following data:
+ *
+ * - `n` coefficients `b_i` of the numerator (feedforward)
+ * - `n` coefficients `a_i` of the denominator (feedback)
+ * - the memory of the last `n` input and output samples
+ *
+ * The filter is computed in-place or out-of-place on the input vector and
+ * can also be applied forward and backward to get a zero-phase response.
+ */
+pub struct Filter {
+    filter: *mut ffi::aubio_filter_t,
+    tmp: Vec<Smpl>,
+}
+
+impl Drop for Filter {
+    fn drop(&mut self) {
+        unsafe { ffi::del_aubio_filter(self.filter) }
+    }
+}
+
+unsafe impl Send for Filter {}
+unsafe impl Sync for Filter {}
+
+impl Filter {
+    fn from_raw(create: impl FnOnce() -> *mut ffi::aubio_filter_t) -> Result<Self> {
+        let filter = try_init(create)?;
+
+        Ok(Self {
+            filter,
+            tmp: Vec::new(),
+        })
+    }
+
+    /**
+     * Create new filter object
+     *
+     * - `order` Order of the filter (number of coefficients)
+     */
+    pub fn new(order: usize) -> Result<Self> {
+        check_non_zero("order", order)?;
+
+        Self::from_raw(|| unsafe { ffi::new_aubio_filter(order as ffi::uint_t) })
+    }
+
+    /**
+     * Create new filter object with the same coefficients and sampling rate
+     *
+     * The memory of filter is not copied.
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        let mut filter = Self::new(self.get_order())?;
+
+        filter
+            .get_feedback_mut()
+            .as_mut_slice()
+            .copy_from_slice(self.get_feedback().as_slice());
+        filter
+            .get_feedforward_mut()
+            .as_mut_slice()
+            .copy_from_slice(self.get_feedforward().as_slice());
+        filter.set_samplerate(self.get_samplerate());
+
+        Ok(filter)
+    }
+
+    /**
+     * Create biquad filter with `b0`, `b1`, `b2`, `a1`, `a2` coeffs
+     *
+     * - `b0`, `b1`, `b2` Forward filter coefficients
+     * - `a1`, `a2` Feedback filter coefficients
+     */
+    pub fn new_biquad(b0: LSmpl, b1: LSmpl, b2: LSmpl, a1: LSmpl, a2: LSmpl) -> Result<Self> {
+        Self::from_raw(|| unsafe { ffi::new_aubio_filter_biquad(b0, b1, b2, a1, a2) })
+    }
+
+    /**
+     * Create new A-design filter
+     *
+     * - `sample_rate` Sampling frequency of the signal to filter.
+     *   Should be one of 8000, 11025, 16000, 22050, 24000, 32000, 44100, 48000, 88200, 96000, and 192000 Hz
+     */
+    pub fn new_a_weighting(sample_rate: u32) -> Result<Self> {
+        let mut filter = Self::new(7)?;
+        filter.set_a_weighting(sample_rate)?;
+        Ok(filter)
+    }
+
+    /**
+     * Create new C-design filter
+     *
+     * - `sample_rate` Sampling frequency of the signal to filter.
+     *   Should be one of 8000, 11025, 16000, 22050, 24000, 32000, 44100, 48000, 88200, 96000, and 192000 Hz
+     */
+    pub fn new_c_weighting(sample_rate: u32) -> Result<Self> {
+        let mut filter = Self::new(5)?;
+        filter.set_c_weighting(sample_rate)?;
+        Ok(filter)
+    }
+
+    /**
+     * Create new DC blocking filter
+     *
+     * The first order high-pass filter `y[n] = x[n] - x[n-1] + R * y[n-1]`
+     * which removes DC offset and low-frequency rumble.
+     *
+     * - `cutoff` Cutoff frequency in Hz, usually from `10` to `40`
+     * - `sample_rate` Sampling frequency of the signal to filter
+     */
+    pub fn new_dc_blocker(cutoff: Smpl, sample_rate: u32) -> Result<Self> {
+        if cutoff <= 0.0 || cutoff >= sample_rate as Smpl / 2.0 {
+            return Err(Error::InvalidArg);
+        }
+
+        let pole = (-2.0 * core::f64::consts::PI as Smpl * cutoff / sample_rate as Smpl).exp();
+        let mut filter = Self::new_biquad(
+            to_lsmpl(1.0),
+            to_lsmpl(-1.0),
+            to_lsmpl(0.0),
+            to_lsmpl(-pole),
+            to_lsmpl(0.0),
+        )?;
+        filter.set_samplerate(sample_rate);
+        Ok(filter)
+    }
+
+    /**
+     * Create new pre-emphasis filter
+     *
+     * The first order filter `y[n] = x[n] - coeff * x[n-1]` which boosts
+     * the high frequencies of signal.
+     *
+     * - `coeff` Emphasis coefficient in range `[0, 1)`, usually `0.97`
+     */
+    pub fn new_pre_emphasis(coeff: Smpl) -> Result<Self> {
+        if !(0.0..1.0).contains(&coeff) {
+            return Err(Error::InvalidArg);
+        }
+
+        Self::new_biquad(
+            to_lsmpl(1.0),
+            to_lsmpl(-coeff),
+            to_lsmpl(0.0),
+            to_lsmpl(0.0),
+            to_lsmpl(0.0),
+        )
+    }
+
+    /**
+     * Filter input vector (in-place)
+     *
+     * - `data` Input vector to filter
+     */
+    pub fn do_<'io, IO>(&mut self, data: IO)
+    where
+        IO: Into<FVecMut<'io>>,
+    {
+        let mut data = data.into();
+        unsafe { ffi::aubio_filter_do(self.filter, data.as_mut_ptr()) }
+    }
+
+    /**
+     * Filter input vector (out-of-place)
+     *
+     * - `input` Input vector to filter
+     * - `output` Output vector to store filtered input (same size as `input`)
+     */
+    pub fn do_outplace<'i, 'o, I, O>(&mut self, input: I, output: O) -> Status
+    where
+        I: Into<FVec<'i>>,
+        O: Into<FVecMut<'o>>,
+    {
+        let input = input.into();
+        let mut output = output.into();
+
+        if input.size() != output.size() {
+            return Err(Error::MismatchSize);
+        }
+
+        input.check_overlap(&output)?;
+
+        unsafe { ffi::aubio_filter_do_outplace(self.filter, input.as_ptr(), output.as_mut_ptr()) }
+        Ok(())
+    }
+
+    /**
+     * Filter input vector forward and backward (in-place)
+     *
+     * The resulting signal has no phase distortion and the magnitude
+     * response of the filter is squared.
+     *
+     * - `data` Input vector to filter
+     */
+    pub fn do_filtfilt<'io, IO>(&mut self, data: IO)
+    where
+        IO: Into<FVecMut<'io>>,
+    {
+        let mut data = data.into();
+
+        self.tmp.resize(data.size(), 0.0);
+        let mut tmp = FVecMut::from(self.tmp.as_mut_slice());
+
+        unsafe { ffi::aubio_filter_do_filtfilt(self.filter, data.as_mut_ptr(), tmp.as_mut_ptr()) }
+    }
+
+    /**
+     * Reset filter memory
+     */
+    pub fn reset(&mut self) {
+        unsafe {
+            ffi::aubio_filter_do_reset(self.filter);
+        }
+    }
+
+    /**
+     * Set coefficients of a biquad filter
+     *
+     * - `b0`, `b1`, `b2` Forward filter coefficients
+     * - `a1`, `a2` Feedback filter coefficients
+     */
+    pub fn set_biquad(&mut self, b0: LSmpl, b1: LSmpl, b2: LSmpl, a1: LSmpl, a2: LSmpl) -> Status {
+        if 0 == unsafe { ffi::aubio_filter_set_biquad(self.filter, b0, b1, b2, a1, a2) } {
+            Ok(())
+        } else {
+            Err(Error::InvalidArg)
+        }
+    }
+
+    /**
+     * Set feedback and feedforward coefficients of a A-weighting filter
+     */
+    pub fn set_a_weighting(&mut self, sample_rate: u32) -> Status {
+        if 0 == unsafe { ffi::aubio_filter_set_a_weighting(self.filter, sample_rate) } {
+            Ok(())
+        } else {
+            Err(Error::InvalidArg)
+        }
+    }
+
+    /**
+     * Set feedback and feedforward coefficients of a C-weighting filter
+     */
+    pub fn set_c_weighting(&mut self, sample_rate: u32) -> Status {
+        if 0 == unsafe { ffi::aubio_filter_set_c_weighting(self.filter, sample_rate) } {
+            Ok(())
+        } else {
+            Err(Error::InvalidArg)
+        }
+    }
+
+    /**
+     * Get feedback coefficients `a_0 ... a_i ... a_P`
+     */
+    pub fn get_feedback(&self) -> LVec<'_> {
+        unsafe { LVec::from_raw_ptr(ffi::aubio_filter_get_feedback(self.filter)) }
+    }
+
+    /**
+     * Get feedback coefficients `a_0 ... a_i ... a_P` for modification
+     */
+    pub fn get_feedback_mut(&mut self) -> LVecMut<'_> {
+        unsafe { LVecMut::from_raw_ptr(ffi::aubio_filter_get_feedback(self.filter)) }
+    }
+
+    /**
+     * Get feedforward coefficients `b_0 ... b_i ... b_P`
+     */
+    pub fn get_feedforward(&self) -> LVec<'_> {
+        unsafe { LVec::from_raw_ptr(ffi::aubio_filter_get_feedforward(self.filter)) }
+    }
+
+    /**
+     * Get feedforward coefficients `b_0 ... b_i ... b_P` for modification
+     */
+    pub fn get_feedforward_mut(&mut self) -> LVecMut<'_> {
+        unsafe { LVecMut::from_raw_ptr(ffi::aubio_filter_get_feedforward(self.filter)) }
+    }
+
+    /**
+     * Get order of the filter
+     */
+    pub fn get_order(&self) -> usize {
+        (unsafe { ffi::aubio_filter_get_order(self.filter) }) as usize
+    }
+
+    /**
+     * Get sampling rate of the filter
+     */
+    pub fn get_samplerate(&self) -> u32 {
+        unsafe { ffi::aubio_filter_get_samplerate(self.filter) }
+    }
+
+    /**
+     * Set sampling rate of the filter
+     */
+    pub fn set_samplerate(&mut self, sample_rate: u32) {
+        unsafe {
+            ffi::aubio_filter_set_samplerate(self.filter, sample_rate);
+        }
+    }
+}
+
+/**
+ * Convert sample to double precision sample
+ *
+ * The conversion is done by library, because `LSmpl` can be an opaque `long double`.
+ */
+fn to_lsmpl(value: Smpl) -> LSmpl {
+    let mut data = [LSmpl::default()];
+    LVec::set_all(&mut data, value);
+    data[0]
+}
+
+#[cfg(test)]
+mod test {
+    use super::to_lsmpl;
+    use crate::*;
+
+    #[test]
+    fn test_biquad() {
+        let [half, zero] = [to_lsmpl(0.5), to_lsmpl(0.0)];
+        let mut filter = Filter::new_biquad(half, zero, zero, zero, zero).unwrap();
+        assert_eq!(filter.get_order(), 3);
+        assert_eq!(filter.get_feedforward().as_slice(), &[half, zero, zero]);
+        assert_eq!(
+            filter.get_feedback().as_slice(),
+            &[to_lsmpl(1.0), zero, zero]
+        );
+
+        let input = [1.0, 2.0, 3.0, 4.0];
+        let mut output = [0.0; 4];
+        filter.do_outplace(input.as_ref(), output.as_mut()).unwrap();
+        assert_eq!(output, [0.5, 1.0, 1.5, 2.0]);
+
+        let mut data = input;
+        filter.reset();
+        filter.do_filtfilt(data.as_mut());
+        assert_eq!(data, [0.25, 0.5, 0.75, 1.0]);
+
+        assert_eq!(
+            filter.do_outplace(input.as_ref(), [0.0; 2].as_mut()),
+            Err(Error::MismatchSize)
+        );
+    }
+
+    #[test]
+    fn test_weighting() {
+        assert!(Filter::new_a_weighting(44100).is_ok());
+        assert!(Filter::new_c_weighting(48000).is_ok());
+        assert!(Filter::new_a_weighting(12345).is_err());
+    }
+
+    #[test]
+    fn test_presets() {
+        let mut filter = Filter::new_pre_emphasis(0.5).unwrap();
+        let mut data = [1.0, 1.0, 1.0, 1.0];
+        filter.do_(data.as_mut());
+        assert_eq!(data, [1.0, 0.5, 0.5, 0.5]);
+        assert!(Filter::new_pre_emphasis(1.0).is_err());
+
+        let mut filter = Filter::new_dc_blocker(20.0, 44100).unwrap();
+        assert_eq!(filter.get_samplerate(), 44100);
+        let mut data = [0.5; 44100];
+        filter.do_(data.as_mut());
+        assert!(data[44099].abs() < 1e-3);
+        assert!(Filter::new_dc_blocker(0.0, 44100).is_err());
+    }
+}
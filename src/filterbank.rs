@@ -1,9 +1,15 @@
 use crate::{
-    check_init, ffi,
-    vec::{CVec, FMat, FMatVecs, FVec, FVecMut},
-    Result, Smpl, Status,
+    check_buf_size, check_non_zero, ffi, try_init,
+    vec::{CVec, FMat, FVec, FVecMut, Memory},
+    Error, Result, Smpl, Status,
 };
 
+use alloc::vec::Vec;
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
 /**
  * Filterbank object.
  *
@@ -11,9 +17,9 @@ use crate::{
  */
 pub struct FilterBank {
     filterbank: *mut ffi::aubio_filterbank_t,
-    #[cfg(feature = "check-size")]
+    #[cfg(not(feature = "unchecked-size"))]
     n_filters: usize,
-    #[cfg(feature = "check-size")]
+    #[cfg(not(feature = "unchecked-size"))]
     win_s: usize,
 }
 
@@ -23,6 +29,9 @@ impl Drop for FilterBank {
     }
 }
 
+unsafe impl Send for FilterBank {}
+unsafe impl Sync for FilterBank {}
+
 impl FilterBank {
     /**
      * Create filterbank object.
@@ -33,12 +42,14 @@ impl FilterBank {
      * Allocates an empty matrix of length win_s / 2 + 1 and height n_filters
      */
     pub fn new(n_filters: usize, win_s: usize) -> Result<Self> {
-        let filterbank =
-            unsafe { ffi::new_aubio_filterbank(n_filters as ffi::uint_t, win_s as ffi::uint_t) };
+        check_non_zero("n_filters", n_filters)?;
+        check_buf_size("win_s", win_s)?;
 
-        check_init(filterbank)?;
+        let filterbank = try_init(|| unsafe {
+            ffi::new_aubio_filterbank(n_filters as ffi::uint_t, win_s as ffi::uint_t)
+        })?;
 
-        #[cfg(feature = "check-size")]
+        #[cfg(not(feature = "unchecked-size"))]
         {
             Ok(Self {
                 filterbank,
@@ -46,54 +57,254 @@ impl FilterBank {
                 win_s,
             })
         }
-        #[cfg(not(feature = "check-size"))]
+        #[cfg(feature = "unchecked-size")]
         {
             Ok(Self { filterbank })
         }
     }
 
-    pub fn set_triangle_bands<'f, F>(&mut self, freqs: F, samplerate: Smpl)
+    /**
+     * Create filterbank object with logarithmically spaced bands
+     *
+     * - `fmin` Center frequency of the lowest band, in Hz
+     * - `fmax` Upper frequency limit, in Hz
+     * - `bins_per_octave` Number of bands per octave
+     * - `win_s` Size of analysis buffer (and length of the FFT transform)
+     * - `samplerate` Audio sampling rate
+     *
+     * The center frequencies of bands are spaced geometrically by `2^(1/bins_per_octave)`
+     * starting from `fmin`, like in a constant-Q transform. Each band is a triangular
+     * filter which spans from the center of the previous band to the center of the
+     * next one. Narrow bands which do not cover any FFT bin are mapped to the nearest bin.
+     *
+     * With `bins_per_octave = 12` the bands are aligned to semitones which is useful
+     * to compute chroma-like features.
+     */
+    pub fn new_log_bands(
+        fmin: Smpl,
+        fmax: Smpl,
+        bins_per_octave: usize,
+        win_s: usize,
+        samplerate: Smpl,
+    ) -> Result<Self> {
+        if fmin <= 0.0 || fmax <= fmin || fmax > samplerate / 2.0 || bins_per_octave == 0 {
+            return Err(Error::InvalidArg);
+        }
+
+        let step = (2.0 as Smpl).powf(1.0 / bins_per_octave as Smpl);
+        // small epsilon to avoid losing the last band due to rounding errors
+        let n_filters = ((fmax / fmin).log2() * bins_per_octave as Smpl + 1e-3).floor() as usize;
+
+        if n_filters == 0 {
+            return Err(Error::InvalidArg);
+        }
+
+        // center frequencies with one extra band on each side
+        let freqs = (0..n_filters + 2)
+            .map(|i| fmin * step.powi(i as i32 - 1))
+            .collect::<Vec<_>>();
+
+        let n_bins = win_s / 2 + 1;
+        let bin_freq = samplerate / win_s as Smpl;
+
+        let coeffs = freqs
+            .windows(3)
+            .map(|band| {
+                let (lower, center, upper) = (band[0], band[1], band[2]);
+                let mut filter = (0..n_bins)
+                    .map(|bin| {
+                        let freq = bin as Smpl * bin_freq;
+                        if freq > lower && freq <= center {
+                            (freq - lower) / (center - lower)
+                        } else if freq > center && freq < upper {
+                            (upper - freq) / (upper - center)
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect::<Vec<Smpl>>();
+                let sum: Smpl = filter.iter().sum();
+                if sum > 0.0 {
+                    filter.iter_mut().for_each(|coeff| *coeff /= sum);
+                } else {
+                    let bin = ((center / bin_freq).round() as usize).min(n_bins - 1);
+                    filter[bin] = 1.0;
+                }
+                filter
+            })
+            .collect::<Vec<_>>();
+
+        let mut filterbank = Self::new(n_filters, win_s)?;
+        filterbank.set_coeffs(
+            coeffs
+                .iter()
+                .map(|filter| filter.as_slice())
+                .collect::<Vec<_>>(),
+        )?;
+
+        Ok(filterbank)
+    }
+
+    /**
+     * Create new filterbank object with the same coefficients and parameters
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        let coeffs = self.get_coeffs();
+        // the window size with same number of bins
+        let mut filterbank = Self::new(coeffs.height(), (coeffs.length() - 1) * 2)?
+            .with_norm(self.get_norm())
+            .with_power(self.get_power());
+
+        filterbank.set_coeffs(coeffs)?;
+
+        Ok(filterbank)
+    }
+
+    /**
+     * Enable or disable normalization of the filters
+     */
+    pub fn with_norm(mut self, norm: bool) -> Self {
+        self.set_norm(norm);
+        self
+    }
+
+    /**
+     * Set power parameter
+     */
+    pub fn with_power(mut self, power: Smpl) -> Self {
+        self.set_power(power);
+        self
+    }
+
+    /**
+     * Enable or disable normalization of the filters
+     *
+     * When enabled (default) each filter is normalized so that its coefficients sum to one.
+     */
+    pub fn set_norm(&mut self, norm: bool) {
+        unsafe {
+            ffi::aubio_filterbank_set_norm(self.filterbank, if norm { 1. } else { 0. });
+        }
+    }
+
+    /**
+     * Get normalization of the filters
+     */
+    pub fn get_norm(&self) -> bool {
+        0.0 < (unsafe { ffi::aubio_filterbank_get_norm(self.filterbank) })
+    }
+
+    /**
+     * Set power parameter
+     *
+     * The norm of the input spectrum is raised to this power before computing filterbank (default is `1`).
+     */
+    pub fn set_power(&mut self, power: Smpl) {
+        unsafe {
+            ffi::aubio_filterbank_set_power(self.filterbank, power);
+        }
+    }
+
+    /**
+     * Get power parameter
+     */
+    pub fn get_power(&self) -> Smpl {
+        unsafe { ffi::aubio_filterbank_get_power(self.filterbank) }
+    }
+
+    /**
+     * Set triangular bands of filters
+     *
+     * - `freqs` Boundary frequencies in Hz, should have `n_filters + 2` values
+     * - `samplerate` Sampling rate in Hz
+     */
+    pub fn set_triangle_bands<'f, F>(&mut self, freqs: F, samplerate: Smpl) -> Status
     where
         F: Into<FVec<'f>>,
     {
         let freqs = freqs.into();
-        #[cfg(feature = "check-size")]
+        #[cfg(not(feature = "unchecked-size"))]
         {
-            if freqs.size() - 2 != self.n_filters {
-                panic!("Invalid number of frequencies for FilterBank");
+            if freqs.size() != self.n_filters + 2 {
+                return Err(Error::InvalidSize {
+                    name: "freqs",
+                    size: freqs.size(),
+                    min: self.n_filters + 2,
+                    max: self.n_filters + 2,
+                });
             }
         }
-        unsafe {
-            ffi::aubio_filterbank_set_triangle_bands(self.filterbank, freqs.as_ptr(), samplerate);
+        if 0 == unsafe {
+            ffi::aubio_filterbank_set_triangle_bands(self.filterbank, freqs.as_ptr(), samplerate)
+        } {
+            Ok(())
+        } else {
+            Err(Error::InvalidArg)
         }
     }
 
-    pub fn set_mel_coeffs_slaney(&mut self, samplerate: Smpl) {
-        #[cfg(feature = "check-size")]
+    /**
+     * Set Mel filters using Slaney's coefficients
+     *
+     * - `samplerate` Sampling rate in Hz
+     *
+     * The filterbank should have 40 filters.
+     */
+    pub fn set_mel_coeffs_slaney(&mut self, samplerate: Smpl) -> Status {
+        #[cfg(not(feature = "unchecked-size"))]
         {
             if self.n_filters != 40 {
-                panic!("Invalid number of filters for FilterBank");
+                return Err(Error::InvalidSize {
+                    name: "n_filters",
+                    size: self.n_filters,
+                    min: 40,
+                    max: 40,
+                });
             }
         }
-        unsafe {
-            ffi::aubio_filterbank_set_mel_coeffs_slaney(self.filterbank, samplerate);
+        if 0 == unsafe { ffi::aubio_filterbank_set_mel_coeffs_slaney(self.filterbank, samplerate) }
+        {
+            Ok(())
+        } else {
+            Err(Error::InvalidArg)
         }
     }
 
-    pub fn set_coeffs(&mut self, filters: FMat<FMatVecs>) {
-        #[cfg(feature = "check-size")]
+    /**
+     * Set filter coefficients
+     *
+     * The coefficients is copied into own matrix of filterbank,
+     * so the `filters` should have `n_filters` rows of `win_s / 2 + 1` values.
+     */
+    pub fn set_coeffs<'f, X, F>(&mut self, filters: F) -> Status
+    where
+        F: Into<FMat<'f, X>>,
+    {
+        let filters = filters.into();
+        let coeffs = unsafe { &*ffi::aubio_filterbank_get_coeffs(self.filterbank) };
+
+        if filters.height() != coeffs.height as usize || filters.length() != coeffs.length as usize
         {
-            if filters.height() != self.n_filters || filters.length() != self.win_s / 2 + 1 {
-                panic!("Invalid FilterBank coeff size");
-            }
+            return Err(Error::MismatchSize);
         }
-        unsafe {
-            ffi::aubio_filterbank_set_coeffs(self.filterbank, filters.as_ptr());
+
+        for (index, filter) in filters.rows().enumerate() {
+            let coeff =
+                unsafe { core::slice::from_raw_parts_mut(*coeffs.data.add(index), filter.len()) };
+            coeff.copy_from_slice(filter);
         }
+
+        Ok(())
     }
 
-    pub fn get_coeffs(&mut self) -> FMat<()> {
-        unsafe { FMat::from_raw_ptr(ffi::aubio_filterbank_get_coeffs(self.filterbank)) }
+    /**
+     * Get filter coefficients
+     *
+     * Returns read-only view of own matrix of filterbank which borrows the filterbank,
+     * use `FilterBank::set_coeffs()` to change it.
+     */
+    pub fn get_coeffs(&self) -> FMat<'_, ()> {
+        unsafe { FMat::from_raw_ptr_of(ffi::aubio_filterbank_get_coeffs(self.filterbank), self) }
     }
 
     pub fn do_<'i, 'o, I, O>(&mut self, input: I, output: O) -> Status
@@ -103,12 +314,15 @@ impl FilterBank {
     {
         let input = input.into();
         let mut output = output.into();
-        #[cfg(feature = "check-size")]
+        #[cfg(not(feature = "unchecked-size"))]
         {
             if output.size() < self.n_filters || input.size() != self.win_s / 2 + 1 {
-                panic!("Invalid output or input size for FilterBank");
+                return Err(Error::MismatchSize);
             }
         }
+
+        input.check_overlap(&output)?;
+
         unsafe { ffi::aubio_filterbank_do(self.filterbank, input.as_ptr(), output.as_mut_ptr()) };
         Ok(())
     }
@@ -117,47 +331,108 @@ impl FilterBank {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::Smpl;
+    use crate::{vec::FMatBuf, Smpl};
 
     #[test]
     fn test_new_filterbank() {
         let _filter_bank = FilterBank::new(10, 512).unwrap();
-        #[cfg(feature = "check-size")]
+        #[cfg(not(feature = "unchecked-size"))]
         {
             assert_eq!(_filter_bank.n_filters, 10);
             assert_eq!(_filter_bank.win_s, 512);
         }
     }
 
+    #[test]
+    #[cfg(not(feature = "unchecked-size"))]
+    fn test_invalid_bands() {
+        let mut filter_bank = FilterBank::new(10, 512).unwrap();
+
+        assert_eq!(
+            filter_bank.set_triangle_bands([].as_ref(), 44100.0),
+            Err(Error::InvalidSize {
+                name: "freqs",
+                size: 0,
+                min: 12,
+                max: 12,
+            })
+        );
+        assert!(filter_bank
+            .set_triangle_bands([100.0; 11].as_ref(), 44100.0)
+            .unwrap_err()
+            .is_size());
+        assert!(filter_bank.set_mel_coeffs_slaney(44100.0).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_norm_power() {
+        let filter_bank = FilterBank::new(10, 512)
+            .unwrap()
+            .with_norm(false)
+            .with_power(2.0);
+
+        assert!(!filter_bank.get_norm());
+        assert_eq!(filter_bank.get_power(), 2.0);
+    }
+
+    #[test]
+    fn test_new_log_bands() {
+        let filter_bank = FilterBank::new_log_bands(110.0, 3520.0, 12, 2048, 44100.0).unwrap();
+        let coeffs = filter_bank.get_coeffs();
+
+        assert_eq!(coeffs.height(), 60);
+        assert_eq!(coeffs.length(), 1025);
+
+        for filter in coeffs.rows() {
+            let sum: Smpl = filter.iter().sum();
+            assert!((sum - 1.0).abs() < 1e-4);
+        }
+
+        assert!(FilterBank::new_log_bands(0.0, 3520.0, 12, 2048, 44100.0).is_err());
+        assert!(FilterBank::new_log_bands(110.0, 30000.0, 12, 2048, 44100.0).is_err());
+    }
+
     #[test]
     fn test_set_coeffs() {
         let mut filter_bank = FilterBank::new(2, 4).unwrap();
         let filters: Vec<&[Smpl]> = vec![&[1.0, 1.0, 1.0], &[2.0, 2.0, 2.0]];
 
-        filter_bank.set_coeffs(filters.into());
+        filter_bank.set_coeffs(filters).unwrap();
         let coeffs = filter_bank.get_coeffs();
 
-        assert_eq!(coeffs.get_vec(), vec![&[1.0, 1.0, 1.0], &[2.0, 2.0, 2.0]]);
+        assert_eq!(
+            coeffs.rows().collect::<Vec<_>>(),
+            vec![&[1.0, 1.0, 1.0], &[2.0, 2.0, 2.0]]
+        );
     }
 
-    #[cfg(feature = "check-size")]
-    #[should_panic]
     #[test]
     fn test_wrong_height_set_coeffs() {
         let mut filter_bank = FilterBank::new(2, 4).unwrap();
         let filters: Vec<&[Smpl]> = vec![&[1.0, 1.0, 1.0], &[2.0, 2.0, 2.0], &[0.0, 0.0, 0.0]];
 
-        filter_bank.set_coeffs(filters.into());
+        assert_eq!(filter_bank.set_coeffs(filters), Err(Error::MismatchSize));
     }
 
-    #[cfg(feature = "check-size")]
-    #[should_panic]
     #[test]
     fn test_wrong_length_set_coeffs() {
         let mut filter_bank = FilterBank::new(2, 4).unwrap();
         let filters: Vec<&[Smpl]> = vec![&[1.0], &[2.0], &[0.0], &[0.0]];
 
-        filter_bank.set_coeffs(filters.into());
+        assert_eq!(filter_bank.set_coeffs(filters), Err(Error::MismatchSize));
+    }
+
+    #[test]
+    fn test_set_coeffs_buf() {
+        let mut filter_bank = FilterBank::new(2, 4).unwrap();
+        let filters = FMatBuf::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+
+        filter_bank.set_coeffs(&filters).unwrap();
+
+        assert!(filter_bank
+            .get_coeffs()
+            .approx_eq(&FMat::from(&filters), 0.0));
     }
 
     #[test]
@@ -168,7 +443,7 @@ mod test {
         let input: Vec<Smpl> = vec![2., 2., 2., 100., 100., 100.];
         let mut output: Vec<Smpl> = vec![0.; 2];
 
-        filter_bank.set_coeffs(filters.into());
+        filter_bank.set_coeffs(filters).unwrap();
         filter_bank
             .do_(input.as_slice().as_ref(), output.as_mut_slice().as_mut())
             .unwrap();
@@ -176,7 +451,7 @@ mod test {
         assert_eq!(vec![6.0, 12.0], output);
     }
 
-    #[cfg(feature = "check-size")]
+    #[cfg(not(feature = "unchecked-size"))]
     #[should_panic]
     #[test]
     fn test_filterbank_do_wrong_dimensions_input() {
@@ -189,7 +464,7 @@ mod test {
             .unwrap();
     }
 
-    #[cfg(feature = "check-size")]
+    #[cfg(not(feature = "unchecked-size"))]
     #[should_panic]
     #[test]
     fn test_filterbank_do_wrong_dimensions_output() {
@@ -0,0 +1,141 @@
+use crate::{vec::downmix_into, MediaSource, Smpl};
+
+use claxon::FlacReader;
+
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use alloc::vec::Vec;
+
+/**
+ * FLAC decoder based on _claxon_
+ *
+ * Decodes FLAC files in pure Rust, down-mixes channels and slices
+ * the signal into frames of `hop_size` samples.
+ *
+ * The FLAC blocks are decoded entirely, so the samples which does not
+ * fit into the current frame are kept until the next one.
+ */
+pub struct FlacSource<R: Read> {
+    reader: FlacReader<R>,
+    sample_rate: u32,
+    channels: usize,
+    scale: f64,
+    hop_size: usize,
+    block: Vec<i32>,
+    samples: Vec<Smpl>,
+    frame: Vec<Smpl>,
+    eof: bool,
+}
+
+impl FlacSource<BufReader<File>> {
+    /**
+     * Open FLAC file
+     *
+     * - `path` Path to FLAC file
+     * - `hop_size` Number of samples in each frame
+     */
+    pub fn open(path: impl AsRef<Path>, hop_size: usize) -> Result<Self, claxon::Error> {
+        let file = BufReader::new(File::open(path)?);
+        Ok(Self::new(FlacReader::new(file)?, hop_size))
+    }
+}
+
+impl<R: Read> FlacSource<R> {
+    /**
+     * Create decoder using opened reader
+     *
+     * - `reader` FLAC reader
+     * - `hop_size` Number of samples in each frame
+     */
+    pub fn new(reader: FlacReader<R>, hop_size: usize) -> Self {
+        let info = reader.streaminfo();
+        let channels = (info.channels as usize).max(1);
+
+        Self {
+            sample_rate: info.sample_rate,
+            channels,
+            scale: (1u64 << (info.bits_per_sample.clamp(1, 32) - 1)) as f64,
+            reader,
+            hop_size,
+            block: Vec::new(),
+            samples: Vec::with_capacity(hop_size * channels),
+            frame: Vec::with_capacity(hop_size),
+            eof: false,
+        }
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.hop_size
+    }
+}
+
+impl<R: Read> MediaSource for FlacSource<R> {
+    type Error = claxon::Error;
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /**
+     * Read the next frame of `hop_size` samples
+     *
+     * The last frame can be shorter.
+     */
+    fn read_frame(&mut self) -> Result<Option<&[Smpl]>, Self::Error> {
+        let length = self.hop_size * self.channels;
+
+        while !self.eof && self.samples.len() < length {
+            self.read_block()?;
+        }
+
+        // the incomplete trailing frame is dropped
+        let length = length.min(self.samples.len() - self.samples.len() % self.channels);
+
+        if length == 0 {
+            return Ok(None);
+        }
+
+        self.frame.resize(length / self.channels, 0.0);
+        downmix_into(&self.samples[..length], self.channels, &mut self.frame)
+            .expect("samples are interleaved");
+        self.samples.drain(..length);
+
+        Ok(Some(&self.frame))
+    }
+}
+
+impl<R: Read> FlacSource<R> {
+    /**
+     * Decode the next block and append its interleaved samples
+     */
+    fn read_block(&mut self) -> Result<(), claxon::Error> {
+        let buffer = core::mem::take(&mut self.block);
+
+        match self.reader.blocks().read_next_or_eof(buffer)? {
+            Some(block) => {
+                for index in 0..block.duration() {
+                    for channel in 0..block.channels() {
+                        self.samples
+                            .push((block.sample(channel, index) as f64 / self.scale) as Smpl);
+                    }
+                }
+
+                self.block = block.into_buffer();
+            }
+            None => self.eof = true,
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,474 @@
+/*!
+ * JavaScript bindings
+ *
+ * Exposes `Onset`, `Pitch`, `Tempo` and `Notes` detectors as _wasm-bindgen_
+ * classes. The `process()` method of each class accepts the typed array of any
+ * length (`Float32Array`, or `Float64Array` with __double__ feature), rebuffers
+ * it into hops and returns the array of plain objects describing detected
+ * events. The positions of events is counted in samples from the first
+ * processed sample and the times in seconds.
+ *
+ * The instances own memory of WebAssembly heap, so call `free()` when
+ * the detector is no longer needed.
+
+```js
+import init, { Onset, Pitch } from "aubio";
+
+await init();
+
+const onset = new Onset("specflux", 1024, 512, 44100);
+onset.setThreshold(0.3);
+
+for (const { time, descriptor } of onset.process(samples)) {
+    console.log(`onset at ${time}s (${descriptor})`);
+}
+
+onset.free();
+```
+ */
+
+use crate::{Error, Notes, Onset, Pitch, Smpl, Tempo};
+
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::{prelude::*, JsValue};
+
+use core::str::FromStr;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+fn js_error(error: Error) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+fn parse<T: FromStr + Default>(name: Option<String>, kind: &str) -> Result<T, JsError> {
+    match name {
+        Some(name) => name
+            .parse()
+            .map_err(|_| JsError::new(&format!("unknown {}: {}", kind, name))),
+        None => Ok(T::default()),
+    }
+}
+
+/**
+ * Create plain object from numeric fields
+ */
+fn event(fields: &[(&str, f64)]) -> JsValue {
+    let object = Object::new();
+    for &(name, value) in fields {
+        // setting property of plain object never fails
+        let _ = Reflect::set(&object, &name.into(), &value.into());
+    }
+    object.into()
+}
+
+/**
+ * Rebuffering of input arrays into hops
+ */
+struct Hops {
+    pending: Vec<Smpl>,
+    frames: usize,
+    sample_rate: u32,
+}
+
+impl Hops {
+    fn new(hop_size: usize, sample_rate: u32) -> Self {
+        Self {
+            pending: Vec::with_capacity(hop_size),
+            frames: 0,
+            sample_rate,
+        }
+    }
+
+    /**
+     * Call function with each complete hop and position of its start
+     */
+    fn feed<F>(&mut self, mut input: &[Smpl], hop_size: usize, mut func: F) -> Result<(), JsError>
+    where
+        F: FnMut(&[Smpl], usize) -> crate::Result<()>,
+    {
+        while !input.is_empty() {
+            let count = (hop_size - self.pending.len()).min(input.len());
+            self.pending.extend_from_slice(&input[..count]);
+            input = &input[count..];
+
+            if self.pending.len() == hop_size {
+                func(&self.pending, self.frames * hop_size).map_err(js_error)?;
+                self.pending.clear();
+                self.frames += 1;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/**
+ * Onset detector
+ *
+ * The events has `position`, `time` and `descriptor` fields.
+ */
+#[wasm_bindgen(js_name = Onset)]
+pub struct JsOnset {
+    onset: Onset,
+    hops: Hops,
+}
+
+#[wasm_bindgen(js_class = Onset)]
+impl JsOnset {
+    /**
+     * Create onset detector
+     *
+     * - `method` Name of onset detection function like `"hfc"` or `"specflux"`
+     *   (`undefined` for default)
+     * - `buf_size` Size of analysis buffer
+     * - `hop_size` Number of samples between analysis
+     * - `sample_rate` Sampling rate of signal
+     */
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        method: Option<String>,
+        buf_size: usize,
+        hop_size: usize,
+        sample_rate: u32,
+    ) -> Result<JsOnset, JsError> {
+        let onset = Onset::new(
+            parse(method, "onset method")?,
+            buf_size,
+            hop_size,
+            sample_rate,
+        )
+        .map_err(js_error)?;
+
+        Ok(Self {
+            onset,
+            hops: Hops::new(hop_size, sample_rate),
+        })
+    }
+
+    /**
+     * Process samples and return detected onsets
+     */
+    #[allow(clippy::unnecessary_cast)]
+    pub fn process(&mut self, input: &[Smpl]) -> Result<Array, JsError> {
+        let events = Array::new();
+        let onset = &mut self.onset;
+        let sample_rate = self.hops.sample_rate as f64;
+
+        self.hops.feed(input, onset.get_hop(), |hop, _| {
+            if onset.do_result(hop)? > 0.0 {
+                let position = onset.get_last();
+                events.push(&event(&[
+                    ("position", position as f64),
+                    ("time", position as f64 / sample_rate),
+                    ("descriptor", onset.get_descriptor() as f64),
+                ]));
+            }
+            Ok(())
+        })?;
+
+        Ok(events)
+    }
+
+    /**
+     * Set peak picking threshold
+     */
+    #[wasm_bindgen(js_name = setThreshold)]
+    pub fn set_threshold(&mut self, threshold: Smpl) {
+        self.onset.set_threshold(threshold);
+    }
+
+    /**
+     * Set silence threshold in dB
+     */
+    #[wasm_bindgen(js_name = setSilence)]
+    pub fn set_silence(&mut self, silence: Smpl) {
+        self.onset.set_silence(silence);
+    }
+
+    /**
+     * Set minimum inter onset interval in milliseconds
+     */
+    #[wasm_bindgen(js_name = setMinioiMs)]
+    pub fn set_minioi_ms(&mut self, minioi: Smpl) {
+        self.onset.set_minioi_ms(minioi);
+    }
+}
+
+/**
+ * Pitch detector
+ *
+ * The event is produced for each hop and has `position`, `time`, `pitch`
+ * and `confidence` fields.
+ */
+#[wasm_bindgen(js_name = Pitch)]
+pub struct JsPitch {
+    pitch: Pitch,
+    hops: Hops,
+}
+
+#[wasm_bindgen(js_class = Pitch)]
+impl JsPitch {
+    /**
+     * Create pitch detector
+     *
+     * - `method` Name of pitch detection method like `"yin"` or `"yinfft"`
+     *   (`undefined` for default)
+     * - `buf_size` Size of analysis buffer
+     * - `hop_size` Number of samples between analysis
+     * - `sample_rate` Sampling rate of signal
+     * - `unit` Output unit like `"Hz"` or `"midi"` (`undefined` for default)
+     */
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        method: Option<String>,
+        buf_size: usize,
+        hop_size: usize,
+        sample_rate: u32,
+        unit: Option<String>,
+    ) -> Result<JsPitch, JsError> {
+        let pitch = Pitch::new(
+            parse(method, "pitch method")?,
+            buf_size,
+            hop_size,
+            sample_rate,
+        )
+        .map_err(js_error)?
+        .with_unit(parse(unit, "pitch unit")?);
+
+        Ok(Self {
+            pitch,
+            hops: Hops::new(hop_size, sample_rate),
+        })
+    }
+
+    /**
+     * Process samples and return pitch of each hop
+     */
+    #[allow(clippy::unnecessary_cast)]
+    pub fn process(&mut self, input: &[Smpl]) -> Result<Array, JsError> {
+        let events = Array::new();
+        let pitch = &mut self.pitch;
+        let sample_rate = self.hops.sample_rate as f64;
+
+        self.hops.feed(input, pitch.get_hop(), |hop, position| {
+            let value = pitch.do_result(hop)?;
+            events.push(&event(&[
+                ("position", position as f64),
+                ("time", position as f64 / sample_rate),
+                ("pitch", value as f64),
+                ("confidence", pitch.get_confidence() as f64),
+            ]));
+            Ok(())
+        })?;
+
+        Ok(events)
+    }
+
+    /**
+     * Set silence threshold in dB
+     */
+    #[wasm_bindgen(js_name = setSilence)]
+    pub fn set_silence(&mut self, silence: Smpl) {
+        self.pitch.set_silence(silence);
+    }
+
+    /**
+     * Set yin or yinfft tolerance threshold
+     */
+    #[wasm_bindgen(js_name = setTolerance)]
+    pub fn set_tolerance(&mut self, tolerance: Smpl) {
+        self.pitch.set_tolerance(tolerance);
+    }
+}
+
+/**
+ * Beat tracker
+ *
+ * The events has `position`, `time`, `bpm` and `confidence` fields.
+ */
+#[wasm_bindgen(js_name = Tempo)]
+pub struct JsTempo {
+    tempo: Tempo,
+    hops: Hops,
+}
+
+#[wasm_bindgen(js_class = Tempo)]
+impl JsTempo {
+    /**
+     * Create beat tracker
+     *
+     * - `method` Name of onset detection function (`undefined` for default)
+     * - `buf_size` Size of analysis buffer
+     * - `hop_size` Number of samples between analysis
+     * - `sample_rate` Sampling rate of signal
+     */
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        method: Option<String>,
+        buf_size: usize,
+        hop_size: usize,
+        sample_rate: u32,
+    ) -> Result<JsTempo, JsError> {
+        let tempo = Tempo::new(
+            parse(method, "onset method")?,
+            buf_size,
+            hop_size,
+            sample_rate,
+        )
+        .map_err(js_error)?;
+
+        Ok(Self {
+            tempo,
+            hops: Hops::new(hop_size, sample_rate),
+        })
+    }
+
+    /**
+     * Process samples and return detected beats
+     */
+    #[allow(clippy::unnecessary_cast)]
+    pub fn process(&mut self, input: &[Smpl]) -> Result<Array, JsError> {
+        let events = Array::new();
+        let tempo = &mut self.tempo;
+        let sample_rate = self.hops.sample_rate as f64;
+
+        self.hops.feed(input, tempo.get_hop(), |hop, _| {
+            if tempo.do_result(hop)? > 0.0 {
+                let position = tempo.get_last();
+                events.push(&event(&[
+                    ("position", position as f64),
+                    ("time", position as f64 / sample_rate),
+                    ("bpm", tempo.get_bpm() as f64),
+                    ("confidence", tempo.get_confidence() as f64),
+                ]));
+            }
+            Ok(())
+        })?;
+
+        Ok(events)
+    }
+
+    /**
+     * Current tempo estimate in beats per minute
+     */
+    #[wasm_bindgen(getter)]
+    pub fn bpm(&self) -> Smpl {
+        self.tempo.get_bpm()
+    }
+
+    /**
+     * Set peak picking threshold
+     */
+    #[wasm_bindgen(js_name = setThreshold)]
+    pub fn set_threshold(&mut self, threshold: Smpl) {
+        self.tempo.set_threshold(threshold);
+    }
+
+    /**
+     * Set silence threshold in dB
+     */
+    #[wasm_bindgen(js_name = setSilence)]
+    pub fn set_silence(&mut self, silence: Smpl) {
+        self.tempo.set_silence(silence);
+    }
+}
+
+/**
+ * Notes detector
+ *
+ * The events has `position`, `time`, `pitch` (MIDI) and `velocity` fields,
+ * the note-off events has zero velocity.
+ */
+#[wasm_bindgen(js_name = Notes)]
+pub struct JsNotes {
+    notes: Notes,
+    hops: Hops,
+}
+
+#[wasm_bindgen(js_class = Notes)]
+impl JsNotes {
+    /**
+     * Create notes detector
+     *
+     * - `buf_size` Size of analysis buffer
+     * - `hop_size` Number of samples between analysis
+     * - `sample_rate` Sampling rate of signal
+     */
+    #[wasm_bindgen(constructor)]
+    pub fn new(buf_size: usize, hop_size: usize, sample_rate: u32) -> Result<JsNotes, JsError> {
+        let notes = Notes::new(buf_size, hop_size, sample_rate).map_err(js_error)?;
+
+        Ok(Self {
+            notes,
+            hops: Hops::new(hop_size, sample_rate),
+        })
+    }
+
+    /**
+     * Process samples and return note events
+     */
+    #[allow(clippy::unnecessary_cast)]
+    pub fn process(&mut self, input: &[Smpl]) -> Result<Array, JsError> {
+        let events = Array::new();
+        let notes = &mut self.notes;
+        let sample_rate = self.hops.sample_rate as f64;
+
+        self.hops.feed(input, notes.get_hop(), |hop, position| {
+            for note in notes.do_result(hop)? {
+                events.push(&event(&[
+                    ("position", position as f64),
+                    ("time", position as f64 / sample_rate),
+                    ("pitch", note.pitch as f64),
+                    ("velocity", note.velocity as f64),
+                ]));
+            }
+            Ok(())
+        })?;
+
+        Ok(events)
+    }
+
+    /**
+     * Set silence threshold in dB
+     */
+    #[wasm_bindgen(js_name = setSilence)]
+    pub fn set_silence(&mut self, silence: Smpl) {
+        self.notes.set_silence(silence);
+    }
+
+    /**
+     * Set minimum inter onset interval in milliseconds
+     */
+    #[wasm_bindgen(js_name = setMinioiMs)]
+    pub fn set_minioi_ms(&mut self, minioi: Smpl) {
+        self.notes.set_minioi_ms(minioi);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Hops;
+    use crate::Smpl;
+
+    #[test]
+    fn test_hops() {
+        let mut hops = Hops::new(3, 100);
+        let mut frames = Vec::new();
+
+        let mut collect = |hop: &[Smpl], position| {
+            frames.push((hop.iter().sum::<Smpl>(), position));
+            Ok(())
+        };
+        assert!(hops.feed(&[1.0, 2.0], 3, &mut collect).is_ok());
+        assert!(hops
+            .feed(&[3.0, 4.0, 5.0, 6.0, 7.0], 3, &mut collect)
+            .is_ok());
+
+        assert_eq!(frames, vec![(6.0, 0), (15.0, 3)]);
+        assert_eq!(hops.pending, vec![7.0]);
+    }
+}
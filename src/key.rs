@@ -0,0 +1,156 @@
+use crate::{tuner::NOTE_NAMES, Smpl, CHROMA_SIZE};
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/// Krumhansl-Kessler major key profile
+const MAJOR_PROFILE: [Smpl; CHROMA_SIZE] = [
+    6.35, 2.23, 3.48, 2.33, 4.38, 4.09, 2.52, 5.19, 2.39, 3.66, 2.29, 2.88,
+];
+
+/// Krumhansl-Kessler minor key profile
+const MINOR_PROFILE: [Smpl; CHROMA_SIZE] = [
+    6.33, 2.68, 3.52, 5.38, 2.60, 3.53, 2.54, 4.75, 3.98, 2.69, 3.34, 3.17,
+];
+
+/**
+ * Key mode
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum KeyMode {
+    /**
+     * Major
+     */
+    Major,
+
+    /**
+     * Minor
+     */
+    Minor,
+}
+
+/**
+ * Musical key
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Key {
+    /// Pitch class of tonic, `0` for C
+    pub tonic: usize,
+    /// Key mode
+    pub mode: KeyMode,
+    /// Correlation of chroma with the key profile
+    pub correlation: Smpl,
+}
+
+impl Key {
+    /**
+     * Estimate key from chroma using Krumhansl-Schmuckler algorithm
+     *
+     * - `chroma` Energy of pitch classes accumulated over the analyzed signal (see `Chroma`)
+     *
+     * Returns `None` when chroma is flat.
+     */
+    pub fn estimate(chroma: &[Smpl; CHROMA_SIZE]) -> Option<Self> {
+        [
+            (KeyMode::Major, &MAJOR_PROFILE),
+            (KeyMode::Minor, &MINOR_PROFILE),
+        ]
+        .iter()
+        .flat_map(|&(mode, profile)| {
+            (0..CHROMA_SIZE).filter_map(move |tonic| {
+                correlation(chroma, profile, tonic).map(|correlation| Key {
+                    tonic,
+                    mode,
+                    correlation,
+                })
+            })
+        })
+        .fold(None, |best: Option<Key>, key| match best {
+            Some(best) if best.correlation >= key.correlation => Some(best),
+            _ => Some(key),
+        })
+    }
+
+    /**
+     * Get name of tonic like `"C#"`
+     */
+    pub fn tonic_name(&self) -> &'static str {
+        NOTE_NAMES[self.tonic % CHROMA_SIZE]
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let mode = match self.mode {
+            KeyMode::Major => "major",
+            KeyMode::Minor => "minor",
+        };
+        write!(f, "{} {}", self.tonic_name(), mode)
+    }
+}
+
+/**
+ * Pearson correlation of chroma with key profile rotated to tonic
+ */
+fn correlation(
+    chroma: &[Smpl; CHROMA_SIZE],
+    profile: &[Smpl; CHROMA_SIZE],
+    tonic: usize,
+) -> Option<Smpl> {
+    let mean = |values: &mut dyn Iterator<Item = Smpl>| values.sum::<Smpl>() / CHROMA_SIZE as Smpl;
+
+    let chroma_mean = mean(&mut chroma.iter().copied());
+    let profile_mean = mean(&mut profile.iter().copied());
+
+    let (mut covariance, mut chroma_var, mut profile_var) = (0.0, 0.0, 0.0);
+
+    for (class, value) in chroma.iter().enumerate() {
+        let x = value - chroma_mean;
+        let y = profile[(class + CHROMA_SIZE - tonic) % CHROMA_SIZE] - profile_mean;
+        covariance += x * y;
+        chroma_var += x * x;
+        profile_var += y * y;
+    }
+
+    if chroma_var > 0.0 {
+        Some(covariance / (chroma_var * profile_var).sqrt())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_key() {
+        // C major triad
+        let mut chroma = [0.; CHROMA_SIZE];
+        chroma[0] = 1.0;
+        chroma[4] = 0.8;
+        chroma[7] = 0.9;
+
+        let key = Key::estimate(&chroma).unwrap();
+        assert_eq!(key.tonic, 0);
+        assert_eq!(key.mode, KeyMode::Major);
+        assert_eq!(key.to_string(), "C major");
+
+        // A minor triad
+        let mut chroma = [0.; CHROMA_SIZE];
+        chroma[9] = 1.0;
+        chroma[0] = 0.8;
+        chroma[4] = 0.9;
+
+        let key = Key::estimate(&chroma).unwrap();
+        assert_eq!(key.to_string(), "A minor");
+
+        assert!(Key::estimate(&[1.0; CHROMA_SIZE]).is_none());
+    }
+}
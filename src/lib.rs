@@ -1,44 +1,215 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(all(feature = "no_std", not(test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(all(
+    feature = "no_std",
+    any(
+        feature = "alsa",
+        feature = "cpal",
+        feature = "decode-flac",
+        feature = "decode-mp3",
+        feature = "ffmpeg",
+        feature = "gstreamer",
+        feature = "hound",
+        feature = "midi-out",
+        feature = "npy",
+        feature = "osc",
+        feature = "parquet",
+        feature = "plot",
+        feature = "portaudio",
+        feature = "python",
+        feature = "rodio",
+        feature = "symphonia",
+        feature = "tokio"
+    )
+))]
+extern crate std;
 
 pub(crate) use aubio_sys as ffi;
 
+mod aggregate;
+#[cfg(all(feature = "alsa", target_os = "linux"))]
+mod alsa_capture;
+#[cfg(feature = "gstreamer")]
+mod appsink;
+#[cfg(feature = "async")]
+mod async_stream;
+#[cfg(feature = "cpal")]
+mod capture;
+mod chroma;
+#[cfg(feature = "tokio")]
+mod codec;
+#[cfg(feature = "symphonia")]
+mod decoder;
+mod dispatch;
+mod downbeat;
+mod envelope;
+#[cfg(feature = "ffmpeg")]
+mod ffmpeg;
 mod fft;
+mod filter;
 mod filterbank;
+#[cfg(feature = "decode-flac")]
+mod flac;
+mod key;
 mod log;
+#[cfg(feature = "no_std")]
+mod math;
+mod media;
 mod mfcc;
+#[cfg(feature = "midi-out")]
+mod midi;
+#[cfg(feature = "decode-mp3")]
+mod mp3;
+mod multipitch;
+#[cfg(feature = "fundsp")]
+mod node;
+mod normalize;
 mod notes;
 mod onset;
+#[cfg(feature = "osc")]
+mod osc;
+#[cfg(feature = "portaudio")]
+mod pa_capture;
+mod periodicity;
+mod pipeline;
 mod pitch;
+mod pitchtrack;
 mod pvoc;
 mod resampler;
+#[cfg(any(feature = "cpal", feature = "portaudio"))]
+mod ring;
+mod segmenter;
+#[cfg(feature = "dasp")]
+mod signal;
+#[cfg(not(feature = "no_std"))]
+mod source;
 mod specdesc;
+mod spectrogram;
+mod stream;
+#[cfg(feature = "rodio")]
+mod tap;
 mod tempo;
+mod temporal;
+#[cfg(test)]
+mod testing;
+mod transient;
+mod tss;
+mod tuner;
 mod types;
 mod utils;
+#[cfg(feature = "hound")]
+mod wav;
+mod whitening;
 mod winfunc;
+#[cfg(any(feature = "cpal", feature = "portaudio", feature = "rodio"))]
+mod worker;
+#[cfg(feature = "wasm")]
+mod worklet;
 
+pub mod export;
+#[cfg(feature = "wasm-bindgen")]
+pub mod js;
+pub mod offline;
+#[cfg(feature = "plot")]
+pub mod plot;
+#[cfg(feature = "lv2")]
+pub mod plugin;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod structure;
+pub mod synth;
 pub mod vec;
 
+pub use self::aggregate::*;
+#[cfg(all(feature = "alsa", target_os = "linux"))]
+pub use self::alsa_capture::*;
+#[cfg(feature = "gstreamer")]
+pub use self::appsink::*;
+#[cfg(feature = "async")]
+pub use self::async_stream::*;
+#[cfg(feature = "cpal")]
+pub use self::capture::*;
+pub use self::chroma::*;
+#[cfg(feature = "tokio")]
+pub use self::codec::*;
+#[cfg(feature = "symphonia")]
+pub use self::decoder::*;
+pub use self::dispatch::*;
+pub use self::downbeat::*;
+pub use self::envelope::*;
+#[cfg(feature = "ffmpeg")]
+pub use self::ffmpeg::*;
 pub use self::fft::*;
+pub use self::filter::*;
 pub use self::filterbank::*;
+#[cfg(feature = "decode-flac")]
+pub use self::flac::*;
+pub use self::key::*;
 pub use self::log::*;
+pub use self::media::*;
 pub use self::mfcc::*;
+#[cfg(feature = "midi-out")]
+pub use self::midi::*;
+#[cfg(feature = "decode-mp3")]
+pub use self::mp3::*;
+pub use self::multipitch::*;
+#[cfg(feature = "fundsp")]
+pub use self::node::*;
+pub use self::normalize::*;
 pub use self::notes::*;
 pub use self::onset::*;
+#[cfg(feature = "osc")]
+pub use self::osc::*;
+#[cfg(feature = "portaudio")]
+pub use self::pa_capture::*;
+pub use self::periodicity::*;
+pub use self::pipeline::*;
 pub use self::pitch::*;
+pub use self::pitchtrack::*;
 pub use self::pvoc::*;
 pub use self::resampler::*;
+pub use self::segmenter::*;
+#[cfg(feature = "dasp")]
+pub use self::signal::*;
+#[cfg(not(feature = "no_std"))]
+pub use self::source::*;
 pub use self::specdesc::*;
+pub use self::spectrogram::*;
+pub use self::stream::*;
+#[cfg(feature = "rodio")]
+pub use self::tap::*;
 pub use self::tempo::*;
+pub use self::temporal::*;
+pub use self::transient::*;
+pub use self::tss::*;
+pub use self::tuner::*;
 pub use self::types::*;
 pub use self::utils::*;
+#[cfg(feature = "hound")]
+pub use self::wav::*;
+pub use self::whitening::*;
 pub use self::winfunc::*;
+#[cfg(feature = "wasm")]
+pub use self::worklet::*;
 
 /**
  * Sample data type
  */
 pub type Smpl = ffi::smpl_t;
 
+/**
+ * Double precision sample data type
+ */
+pub type LSmpl = ffi::lsmp_t;
+
+/**
+ * Create zero-filled array of samples with given length
+ *
+ * See also `vec::FArr` which carries its length in the type.
+ */
 #[macro_export]
 macro_rules! farr {
     ($len: expr) => {
@@ -46,6 +217,11 @@ macro_rules! farr {
     };
 }
 
+/**
+ * Create zero-filled array for spectrum of given FFT window size
+ *
+ * See also `vec::CArr` which carries its size in the type.
+ */
 #[macro_export]
 macro_rules! carr {
     ($len: expr) => {
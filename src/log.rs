@@ -1,7 +1,18 @@
 use crate::ffi;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+#[cfg(not(feature = "no_std"))]
 use std::{
+    any::Any,
+    boxed::Box,
+    cell::{Cell, RefCell},
     ffi::{c_void, CStr},
-    fmt::{Display, Formatter, Result as FmtResult},
+    panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
+    string::String,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, PoisonError, RwLock,
+    },
 };
 
 /**
@@ -36,6 +47,7 @@ pub enum LogLevel {
     Warning = ffi::aubio_log_level_AUBIO_LOG_WRN as _,
 }
 
+#[cfg(not(feature = "no_std"))]
 impl LogLevel {
     fn from_ffi(level: ffi::aubio_log_level) -> Option<Self> {
         if level < ffi::aubio_log_level_AUBIO_LOG_LAST_LEVEL {
@@ -116,73 +128,405 @@ where
  * Logging
  *
  * You can use own logger to handle library messages.
- * Only one logger supported at a time.
- * You should keep logger from dropping while it used.
+ * Only one global logger supported at a time, but it can be overridden
+ * in the current thread using `Log::with()`.
+ *
+ * The error messages reported while creating objects is also attached
+ * to the returned errors (see `Error::FailedInitReason`).
+ *
+ * The logger is called from threads which use the library, so it should be `Send`.
+ * The calls of logger is serialized by mutex, so it is never called concurrently.
+ * Setting and resetting of logger is safe to do from any thread (even from logger
+ * itself), the replaced logger is dropped when the calls in progress is completed.
+ * The messages which is reported while logger handles another message in the same
+ * thread (for example when logger creates objects of this crate) is dropped.
+ *
+ * Note that _aubio_ has single global handler of messages per process.
+ * This crate keeps track of its own loggers only, so when some other code
+ * (another version of this crate or another bindings linked with the same
+ * _aubio_ library) changes the handler, the messages go there until the
+ * logger is set again here. Conversely creating of any object here restores
+ * the handler of this crate replacing the foreign one. The handler itself
+ * does not carry any data, so it never refers to the replaced logger.
+ *
+ * The handler is reset to default only when the handler of this crate
+ * is installed, so the foreign handler is kept when logger is reset.
  */
-pub struct Log(Box<dyn Logger>);
-
-impl Drop for Log {
-    fn drop(&mut self) {
-        unsafe {
-            ffi::aubio_log_reset();
-        }
-    }
+#[cfg(not(feature = "no_std"))]
+pub struct Log {
+    logger: SharedLogger,
 }
 
+/**
+ * Logger which can be called from any thread
+ */
+#[cfg(not(feature = "no_std"))]
+type SharedLogger = Arc<Mutex<dyn Logger + Send>>;
+
+#[cfg(not(feature = "no_std"))]
 impl Log {
     fn new<T>(logger: T) -> Self
     where
-        T: Logger + 'static,
+        T: Logger + Send + 'static,
     {
-        let logger = Box::new(logger);
+        Log {
+            logger: Arc::new(Mutex::new(logger)),
+        }
+    }
+}
+
+/**
+ * The logger set by `Log::set()`
+ */
+#[cfg(not(feature = "no_std"))]
+static LOGGER: Mutex<Option<Log>> = Mutex::new(None);
 
+/**
+ * Pass message to the logger set by `Log::set()`
+ *
+ * The logger is cloned from global state, so it stays alive while
+ * it handles the message even if it is replaced concurrently.
+ */
+#[cfg(not(feature = "no_std"))]
+fn log_global(level: LogLevel, message: &str) {
+    let logger = LOGGER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .map(|log| log.logger.clone());
+
+    if let Some(logger) = logger {
+        // the state is consistent even if logger panicked
+        logger
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .log(level, message);
+    }
+}
+
+/**
+ * Whether the handler of this crate is installed
+ */
+#[cfg(not(feature = "no_std"))]
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/**
+ * Set handler of library messages to the handler of this crate
+ */
+#[cfg(not(feature = "no_std"))]
+fn set_handler() {
+    unsafe {
+        ffi::aubio_log_set_function(Some(handler), core::ptr::null_mut());
+    }
+    INSTALLED.store(true, Ordering::Release);
+}
+
+/**
+ * Reset handler of library messages to default
+ *
+ * The foreign handler is kept.
+ */
+#[cfg(not(feature = "no_std"))]
+fn reset_handler() {
+    if INSTALLED.swap(false, Ordering::AcqRel) {
         unsafe {
-            ffi::aubio_log_set_function(Some(handler::<T>), logger.as_ref() as *const _ as *mut _);
+            ffi::aubio_log_reset();
         }
+    }
+}
 
-        Log(logger)
+/**
+ * Logger installed in the current thread for the duration of `Log::with()` call
+ *
+ * The logger is type-erased to not extend the lifetime of borrowed data.
+ */
+#[cfg(not(feature = "no_std"))]
+struct ScopedLogger {
+    logger: *mut (),
+    log: unsafe fn(*mut (), LogLevel, &str),
+}
+
+#[cfg(not(feature = "no_std"))]
+std::thread_local! {
+    /**
+     * The last scoped logger of the current thread
+     *
+     * It takes precedence over the logger set by `Log::set()`.
+     */
+    static SCOPED: Cell<*const ScopedLogger> = const { Cell::new(core::ptr::null()) };
+}
+
+#[cfg(not(feature = "no_std"))]
+unsafe fn log_scoped<T: Logger>(logger: *mut (), level: LogLevel, message: &str) {
+    (*(logger as *mut T)).log(level, message);
+}
+
+/**
+ * Pass message to the scoped logger or to the logger set by `Log::set()`
+ * when the scoped logger is null
+ */
+#[cfg(not(feature = "no_std"))]
+fn log_scoped_or_global(scoped: *const ScopedLogger, level: LogLevel, message: &str) {
+    if scoped.is_null() {
+        log_global(level, message);
+    } else {
+        // the scoped logger is alive until it is removed from the current thread
+        unsafe { ((*scoped).log)((*scoped).logger, level, message) }
     }
 }
 
-fn with_global_logger(func: impl FnOnce(&mut Option<Log>)) {
-    use std::{
-        ptr::null_mut,
-        sync::{Arc, Mutex, Once},
-    };
+/**
+ * Use scoped logger in the current thread while calling function
+ */
+#[cfg(not(feature = "no_std"))]
+fn with_scoped<T: Logger, R>(
+    logger: &mut T,
+    parent: *const ScopedLogger,
+    func: impl FnOnce() -> R,
+) -> R {
+    struct Guard(*const ScopedLogger);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            SCOPED.with(|scoped| scoped.set(self.0));
+
+            with_global_logger(|global_logger| {
+                global_logger.scoped -= 1;
+                global_logger.install();
+            });
+        }
+    }
 
-    static ONCE: Once = Once::new();
-    static mut LOG: *mut Arc<Mutex<Option<Log>>> = null_mut();
+    let scoped = ScopedLogger {
+        logger: logger as *mut T as *mut (),
+        log: log_scoped::<T>,
+    };
 
-    ONCE.call_once(|| unsafe {
-        LOG = Box::into_raw(Box::new(Arc::new(Mutex::new(None))));
+    with_global_logger(|global_logger| {
+        global_logger.scoped += 1;
+        global_logger.install();
     });
 
-    let log = (unsafe { &*LOG }).clone();
-    let mut log = log.lock().unwrap();
+    // the parent logger is restored by guard before the logger goes out of scope
+    SCOPED.with(|current| current.set(&scoped));
+    let _guard = Guard(parent);
 
-    func(&mut log);
+    func()
 }
 
+/**
+ * The state of library handler
+ */
+#[cfg(not(feature = "no_std"))]
+struct GlobalLogger {
+    scoped: usize,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl GlobalLogger {
+    const fn new() -> Self {
+        Self { scoped: 0 }
+    }
+
+    /**
+     * Set handler of library messages to current logger
+     *
+     * The handler is kept while scoped loggers is used by any thread.
+     */
+    fn install(&self) {
+        if self.scoped > 0
+            || LOGGER
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .is_some()
+        {
+            set_handler();
+        } else {
+            reset_handler();
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+fn with_global_logger<R>(func: impl FnOnce(&mut GlobalLogger) -> R) -> R {
+    static LOG: Mutex<GlobalLogger> = Mutex::new(GlobalLogger::new());
+
+    // the state is consistent even if logger panicked
+    let mut log = LOG.lock().unwrap_or_else(PoisonError::into_inner);
+
+    func(&mut log)
+}
+
+#[cfg(not(feature = "no_std"))]
 impl Log {
     /// Set logger
     pub fn set<T>(logger: T)
     where
-        T: Logger + 'static,
+        T: Logger + Send + 'static,
     {
-        with_global_logger(|global_logger| {
-            if global_logger.is_some() {
-                *global_logger = None;
-            }
-            *global_logger = Some(Log::new(logger));
+        let logger = Log::new(logger);
+
+        // the previous logger is dropped after unlocking, because it may log
+        let _previous = with_global_logger(|global_logger| {
+            let previous = Log::replace(Some(logger));
+            global_logger.install();
+            previous
         });
     }
 
     /// Reset logger
     pub fn reset() {
-        with_global_logger(|global_logger| {
-            *global_logger = None;
+        let _previous = with_global_logger(|global_logger| {
+            let previous = Log::replace(None);
+            global_logger.install();
+            previous
         });
     }
+
+    fn replace(logger: Option<Log>) -> Option<Log> {
+        core::mem::replace(
+            &mut *LOGGER.lock().unwrap_or_else(PoisonError::into_inner),
+            logger,
+        )
+    }
+
+    /**
+    Use logger while calling function
+
+    The logger handles library messages reported by the current thread until
+    the function returns, then the previous logger is restored. Unlike `Log::set()`
+    the logger may borrow local data and is not required to be `Send`.
+
+    ```
+    use aubio::{FnLogger, Log, Onset};
+
+    let mut messages = Vec::new();
+
+    let onset = Log::with(
+        FnLogger::from(|_level, message: &str| messages.push(message.to_string())),
+        || Onset::new(Default::default(), 1024, 512, 44100),
+    );
+
+    assert!(onset.is_ok());
+    ```
+
+    The messages reported by other threads while the function runs go to their
+    own scoped loggers or to the logger set by `Log::set()`.
+     */
+    pub fn with<T, R>(logger: T, func: impl FnOnce() -> R) -> R
+    where
+        T: Logger,
+    {
+        let mut logger = logger;
+        let result = with_scoped(&mut logger, SCOPED.with(Cell::get), func);
+
+        if let Some(panic) = Log::take_panic() {
+            resume_unwind(panic);
+        }
+
+        result
+    }
+
+    /**
+     * Take the panic of logger which occurred in the current thread
+     *
+     * The panics of loggers can't be propagated through the library code,
+     * so it is caught and kept by the thread which reported the message
+     * until taken. While the panic is kept the library messages reported
+     * by this thread is dropped.
+     *
+     * The `Log::with()` and constructors of objects resume the panic which
+     * occurred during the call, the panics in other calls should be checked
+     * using this function.
+     */
+    pub fn take_panic() -> Option<Box<dyn Any + Send>> {
+        PANIC.with(|panic| panic.borrow_mut().take())
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+std::thread_local! {
+    /**
+     * The panic of logger in the current thread which is not taken yet
+     */
+    static PANIC: RefCell<Option<Box<dyn Any + Send>>> = const { RefCell::new(None) };
+}
+
+/**
+ * Logger which collects error messages
+ *
+ * All messages is forwarded to the scoped logger or to the logger
+ * set by `Log::set()` as usual.
+ */
+#[cfg(not(feature = "no_std"))]
+struct ErrorCapture {
+    errors: String,
+    parent: *const ScopedLogger,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl Logger for ErrorCapture {
+    fn log(&mut self, level: LogLevel, message: &str) {
+        if level == LogLevel::Error {
+            let error = message.trim();
+            let error = error
+                .strip_prefix("AUBIO ERROR:")
+                .unwrap_or(error)
+                .trim_start();
+
+            if !self.errors.is_empty() {
+                self.errors.push_str("; ");
+            }
+            self.errors.push_str(error);
+        }
+
+        log_scoped_or_global(self.parent, level, message);
+    }
+}
+
+/**
+ * Lock which is shared by creating of objects and exclusive for cleanup
+ */
+#[cfg(not(feature = "no_std"))]
+static CREATION: RwLock<()> = RwLock::new(());
+
+/**
+ * Call function collecting the error messages reported by library
+ *
+ * Only the messages reported by the current thread is collected, so the
+ * objects can be created by many threads concurrently.
+ */
+#[cfg(not(feature = "no_std"))]
+pub(crate) fn capture_errors<T>(func: impl FnOnce() -> T) -> (T, Option<String>) {
+    let mut capture = ErrorCapture {
+        errors: String::new(),
+        parent: SCOPED.with(Cell::get),
+    };
+    let parent = capture.parent;
+
+    let result = {
+        let _creation = CREATION.read().unwrap_or_else(PoisonError::into_inner);
+
+        with_scoped(&mut capture, parent, func)
+    };
+
+    if let Some(panic) = Log::take_panic() {
+        resume_unwind(panic);
+    }
+
+    let errors = capture.errors;
+
+    (result, Some(errors).filter(|errors| !errors.is_empty()))
+}
+
+/**
+ * Call function which should not run concurrently with creating of objects
+ */
+#[cfg(not(feature = "no_std"))]
+pub(crate) fn without_creation<T>(func: impl FnOnce() -> T) -> T {
+    let _creation = CREATION.write().unwrap_or_else(PoisonError::into_inner);
+
+    func()
 }
 
 #[cfg(feature = "log")]
@@ -237,15 +581,322 @@ mod log_impl {
     }
 }
 
-extern "C" fn handler<T>(level: ffi::sint_t, message: *const ffi::char_t, data: *mut c_void)
-where
-    T: Logger,
-{
-    assert!(!data.is_null());
+#[cfg(feature = "tracing")]
+pub use tracing_impl::TracingLogger;
 
-    let logger = unsafe { &mut *(data as *mut T) };
-    let level = LogLevel::from_ffi(level as _).unwrap();
-    let message = unsafe { CStr::from_ptr(message).to_str().unwrap() };
+#[cfg(feature = "tracing")]
+mod tracing_impl {
+    use super::{LogLevel, Logger};
+    use tracing::{event, Level, Span};
 
-    logger.log(level, message);
+    /**
+    Logger implementation backed by [tracing](https://crates.io/crates/tracing) crate.
+
+    ```
+    use aubio::{Log, TracingLogger};
+
+    Log::set(TracingLogger::new("my_app::aubio").with_span(tracing::info_span!("analysis")));
+    ```
+
+    The callsites of _tracing_ requires static metadata, so the events always has `aubio`
+    target and the configured target is recorded as `logger` field of event.
+    When span is set the events are emitted inside it, otherwise the current span
+    of calling thread is used.
+     */
+    pub struct TracingLogger<S> {
+        target: S,
+        span: Option<Span>,
+    }
+
+    impl Default for TracingLogger<&'static str> {
+        fn default() -> Self {
+            Self::new("aubio")
+        }
+    }
+
+    impl<S> TracingLogger<S> {
+        pub fn new(target: S) -> Self {
+            Self { target, span: None }
+        }
+
+        /**
+         * Emit events inside span
+         */
+        pub fn with_span(mut self, span: Span) -> Self {
+            self.set_span(span);
+            self
+        }
+
+        /**
+         * Set span to emit events inside
+         */
+        pub fn set_span(&mut self, span: Span) {
+            self.span = Some(span);
+        }
+
+        /**
+         * Get span which events emitted inside
+         */
+        pub fn get_span(&self) -> Option<&Span> {
+            self.span.as_ref()
+        }
+    }
+
+    macro_rules! emit {
+        ($level: expr, $target: expr, $message: expr) => {
+            event!(target: "aubio", $level, logger = $target, "{}", $message)
+        };
+    }
+
+    impl<S: AsRef<str>> Logger for TracingLogger<S> {
+        fn log(&mut self, level: LogLevel, message: &str) {
+            let _entered = self.span.as_ref().map(|span| span.enter());
+            let target = self.target.as_ref();
+
+            match level {
+                LogLevel::Error => emit!(Level::ERROR, target, message),
+                LogLevel::Warning => emit!(Level::WARN, target, message),
+                LogLevel::Message | LogLevel::Info => emit!(Level::INFO, target, message),
+                LogLevel::Debug => emit!(Level::DEBUG, target, message),
+            }
+        }
+    }
+
+    impl From<LogLevel> for Level {
+        fn from(level: LogLevel) -> Self {
+            match level {
+                LogLevel::Error => Level::ERROR,
+                LogLevel::Warning => Level::WARN,
+                LogLevel::Message => Level::INFO,
+                LogLevel::Info => Level::INFO,
+                LogLevel::Debug => Level::DEBUG,
+            }
+        }
+    }
+}
+
+/**
+ * Handler of library messages
+ *
+ * The messages is passed to the scoped logger of the current thread
+ * or to the logger set by `Log::set()`. The panics of logger is caught
+ * and the nested messages is dropped.
+ */
+#[cfg(not(feature = "no_std"))]
+extern "C" fn handler(level: ffi::sint_t, message: *const ffi::char_t, _data: *mut c_void) {
+    std::thread_local! {
+        static HANDLING: Cell<bool> = const { Cell::new(false) };
+    }
+
+    // the thread may be exiting when the thread locals is destroyed
+    if PANIC
+        .try_with(|panic| panic.borrow().is_some())
+        .unwrap_or(true)
+        || HANDLING.with(|handling| handling.replace(true))
+    {
+        return;
+    }
+
+    // unwinding through the library code is undefined behavior
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let level = LogLevel::from_ffi(level as _).unwrap();
+        let message = unsafe { CStr::from_ptr(message).to_string_lossy() };
+
+        log_scoped_or_global(SCOPED.with(Cell::get), level, &message);
+    }));
+
+    HANDLING.with(|handling| handling.set(false));
+
+    if let Err(panic) = result {
+        PANIC.with(|kept| {
+            kept.borrow_mut().get_or_insert(panic);
+        });
+    }
+}
+
+#[cfg(all(test, not(feature = "no_std")))]
+mod test {
+    use super::{
+        capture_errors, handler, with_global_logger, ErrorCapture, FnLogger, Log, LogLevel, Logger,
+        INSTALLED,
+    };
+    use std::{
+        panic::catch_unwind,
+        sync::{atomic::Ordering, Arc, Mutex},
+    };
+
+    // the tests use global state
+    static SERIAL: Mutex<()> = Mutex::new(());
+
+    // report message like library does
+    fn log(level: LogLevel, message: &str) {
+        let message = std::ffi::CString::new(message).unwrap();
+
+        handler(level as _, message.as_ptr(), core::ptr::null_mut());
+    }
+
+    #[test]
+    fn test_error_capture() {
+        let _serial = SERIAL.lock();
+        let mut capture = ErrorCapture {
+            errors: String::new(),
+            parent: core::ptr::null(),
+        };
+
+        capture.log(LogLevel::Warning, "AUBIO WARNING: ignored\n");
+        capture.log(
+            LogLevel::Error,
+            "AUBIO ERROR: onset: got buffer_size 5, but can not be < 2\n",
+        );
+        capture.log(LogLevel::Error, "failed creating pvoc\n");
+
+        assert_eq!(
+            capture.errors,
+            "onset: got buffer_size 5, but can not be < 2; failed creating pvoc"
+        );
+    }
+
+    #[test]
+    fn test_capture_errors() {
+        let _serial = SERIAL.lock();
+        let mut messages = Vec::new();
+
+        let (result, errors) = Log::with(
+            FnLogger::from(|_, message: &str| messages.push(message.to_string())),
+            || {
+                capture_errors(|| {
+                    log(LogLevel::Error, "AUBIO ERROR: invalid size\n");
+                    // the errors of other threads is not captured
+                    std::thread::scope(|scope| {
+                        scope.spawn(|| log(LogLevel::Error, "AUBIO ERROR: other thread\n"));
+                    });
+                    log(LogLevel::Info, "AUBIO INFO: ignored\n");
+                    true
+                })
+            },
+        );
+
+        assert!(result);
+        assert_eq!(errors.as_deref(), Some("invalid size"));
+        // the messages is forwarded to scoped logger
+        assert_eq!(
+            messages,
+            ["AUBIO ERROR: invalid size\n", "AUBIO INFO: ignored\n"]
+        );
+    }
+
+    #[test]
+    fn test_scoped_logger() {
+        let _serial = SERIAL.lock();
+        let mut messages = Vec::new();
+
+        Log::with(
+            FnLogger::from(|_, message: &str| messages.push(message.to_string())),
+            || {
+                Log::with(FnLogger::from(|_, _: &str| {}), || {
+                    log(LogLevel::Info, "nested")
+                });
+                log(LogLevel::Info, "scoped");
+                // the messages of other threads does not go to scoped logger
+                std::thread::scope(|scope| {
+                    scope.spawn(|| log(LogLevel::Info, "other thread"));
+                });
+            },
+        );
+
+        with_global_logger(|global_logger| assert_eq!(global_logger.scoped, 0));
+        assert_eq!(messages, ["scoped"]);
+    }
+
+    #[test]
+    fn test_replace_in_use() {
+        let _serial = SERIAL.lock();
+        let messages = Arc::new(Mutex::new(Vec::new()));
+
+        struct ResetLogger(Arc<Mutex<Vec<String>>>);
+
+        impl Logger for ResetLogger {
+            fn log(&mut self, _level: LogLevel, message: &str) {
+                // creating of objects by logger does not deadlock
+                capture_errors(|| log(LogLevel::Error, "dropped"));
+                // the logger stays alive until the call is completed
+                Log::reset();
+                self.0.lock().unwrap().push(message.to_string());
+            }
+        }
+
+        impl Drop for ResetLogger {
+            fn drop(&mut self) {
+                self.0.lock().unwrap().push("dropped".into());
+            }
+        }
+
+        Log::set(ResetLogger(messages.clone()));
+
+        log(LogLevel::Info, "message");
+        log(LogLevel::Info, "ignored");
+
+        assert_eq!(*messages.lock().unwrap(), ["message", "dropped"]);
+        assert!(!INSTALLED.load(Ordering::Acquire));
+    }
+
+    #[test]
+    fn test_logger_panic() {
+        let _serial = SERIAL.lock();
+        struct PanicLogger;
+
+        impl Logger for PanicLogger {
+            fn log(&mut self, _level: LogLevel, _message: &str) {
+                panic!("logger panic");
+            }
+        }
+
+        let panic = catch_unwind(|| {
+            Log::with(PanicLogger, || {
+                log(LogLevel::Error, "message");
+                // the messages is dropped while the panic is kept
+                log(LogLevel::Error, "ignored");
+            })
+        })
+        .unwrap_err();
+
+        assert_eq!(panic.downcast_ref::<&str>(), Some(&"logger panic"));
+        assert!(Log::take_panic().is_none());
+    }
+
+    #[test]
+    fn test_logger_panic_thread() {
+        let _serial = SERIAL.lock();
+        struct PanicLogger;
+
+        impl Logger for PanicLogger {
+            fn log(&mut self, _level: LogLevel, _message: &str) {
+                panic!("logger panic");
+            }
+        }
+
+        Log::set(PanicLogger);
+
+        let panicked = std::thread::spawn(|| {
+            log(LogLevel::Error, "message");
+            // the panic is kept by the thread which reported message
+            Log::take_panic().is_some()
+        })
+        .join()
+        .unwrap();
+
+        std::thread::spawn(|| log(LogLevel::Error, "message"))
+            .join()
+            .unwrap();
+
+        Log::reset();
+
+        assert!(panicked);
+        // the panic of other thread is not resumed here
+        assert!(Log::take_panic().is_none());
+        assert_eq!(
+            capture_errors(|| log(LogLevel::Error, "error")).1,
+            Some("error".into())
+        );
+    }
 }
@@ -0,0 +1,84 @@
+/**
+ * Floating point functions which are missing in `core`
+ *
+ * Implemented using [libm](https://crates.io/crates/libm) when `no_std` feature is enabled.
+ * The inherent methods take precedence when `std` is linked anyway (by dependencies or tests),
+ * so the trait and its imports may be unused.
+ */
+#[allow(dead_code)]
+pub(crate) trait Float: Sized {
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn exp2(self) -> Self;
+    fn ln(self) -> Self;
+    fn log10(self) -> Self;
+    fn log2(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+}
+
+macro_rules! float_impl {
+    ($type: ty, $floor: ident, $ceil: ident, $round: ident, $sqrt: ident, $exp: ident, $exp2: ident, $ln: ident, $log10: ident, $log2: ident, $pow: ident, $sin: ident, $cos: ident) => {
+        impl Float for $type {
+            fn floor(self) -> Self {
+                libm::$floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                libm::$ceil(self)
+            }
+
+            fn round(self) -> Self {
+                libm::$round(self)
+            }
+
+            fn sqrt(self) -> Self {
+                libm::$sqrt(self)
+            }
+
+            fn exp(self) -> Self {
+                libm::$exp(self)
+            }
+
+            fn exp2(self) -> Self {
+                libm::$exp2(self)
+            }
+
+            fn ln(self) -> Self {
+                libm::$ln(self)
+            }
+
+            fn log10(self) -> Self {
+                libm::$log10(self)
+            }
+
+            fn log2(self) -> Self {
+                libm::$log2(self)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                libm::$pow(self, n)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                libm::$pow(self, n as Self)
+            }
+
+            fn sin(self) -> Self {
+                libm::$sin(self)
+            }
+
+            fn cos(self) -> Self {
+                libm::$cos(self)
+            }
+        }
+    };
+}
+
+float_impl!(f32, floorf, ceilf, roundf, sqrtf, expf, exp2f, logf, log10f, log2f, powf, sinf, cosf);
+float_impl!(f64, floor, ceil, round, sqrt, exp, exp2, log, log10, log2, pow, sin, cos);
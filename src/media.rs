@@ -0,0 +1,88 @@
+use crate::{Error, Smpl};
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/**
+ * Source of mono samples decoded from media
+ *
+ * This trait decouples the analysis from specific decoder: the streaming
+ * and offline functions which read the media accept any implementation.
+ * The implementations are provided for the builtin `Source` and for
+ * the decoders enabled by features (like `WavSource` with __hound__ or
+ * `SymphoniaSource` with __symphonia__).
+ */
+pub trait MediaSource {
+    /**
+     * The error of decoding
+     */
+    type Error;
+
+    /**
+     * Get sampling rate of produced samples
+     */
+    fn sample_rate(&self) -> u32;
+
+    /**
+     * Get number of channels of media
+     */
+    fn channels(&self) -> usize;
+
+    /**
+     * Read the next frame of mono samples
+     *
+     * The length of frames is determined by source and may vary.
+     * Returns `None` at the end of media.
+     */
+    fn read_frame(&mut self) -> Result<Option<&[Smpl]>, Self::Error>;
+}
+
+impl<S: MediaSource + ?Sized> MediaSource for &mut S {
+    type Error = S::Error;
+
+    fn sample_rate(&self) -> u32 {
+        (**self).sample_rate()
+    }
+
+    fn channels(&self) -> usize {
+        (**self).channels()
+    }
+
+    fn read_frame(&mut self) -> Result<Option<&[Smpl]>, Self::Error> {
+        (**self).read_frame()
+    }
+}
+
+/**
+ * Error of analysis of media
+ */
+#[derive(Debug)]
+pub enum MediaError<E> {
+    /**
+     * Failed to read media
+     */
+    Media(E),
+
+    /**
+     * Failed to analyze samples
+     */
+    Analyzer(Error),
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<E: std::error::Error> std::error::Error for MediaError<E> {}
+
+impl<E: Display> Display for MediaError<E> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        use self::MediaError::*;
+        match self {
+            Media(error) => write!(f, "media error: {}", error),
+            Analyzer(error) => write!(f, "analyzer error: {}", error),
+        }
+    }
+}
+
+impl<E> From<Error> for MediaError<E> {
+    fn from(error: Error) -> Self {
+        MediaError::Analyzer(error)
+    }
+}
@@ -1,9 +1,19 @@
 use crate::{
-    check_init, ffi,
-    vec::{CVec, FVecMut},
+    check_buf_size, check_non_zero, ffi, try_init,
+    vec::{CVec, FVecMut, Memory},
     Result, Smpl, Status,
 };
 
+/**
+ * Initialization of mel filterbank
+ */
+#[derive(Clone, Copy)]
+enum MelCoeffs {
+    Mel(Smpl, Smpl),
+    Htk(Smpl, Smpl),
+    Slaney,
+}
+
 /**
  * MFCC object
  *
@@ -18,7 +28,10 @@ use crate::{
 pub struct MFCC {
     mfcc: *mut ffi::aubio_mfcc_t,
     buf_size: usize,
+    n_filters: usize,
     n_coeffs: usize,
+    sample_rate: u32,
+    mel_coeffs: Option<MelCoeffs>,
 }
 
 impl Drop for MFCC {
@@ -27,6 +40,9 @@ impl Drop for MFCC {
     }
 }
 
+unsafe impl Send for MFCC {}
+unsafe impl Sync for MFCC {}
+
 impl MFCC {
     /**
      * Create MFCC object
@@ -42,24 +58,53 @@ impl MFCC {
         n_coeffs: usize,
         sample_rate: u32,
     ) -> Result<Self> {
-        let mfcc = unsafe {
+        check_buf_size("buf_size", buf_size)?;
+        check_non_zero("n_filters", n_filters)?;
+        check_non_zero("n_coeffs", n_coeffs)?;
+        check_non_zero("sample_rate", sample_rate as usize)?;
+
+        let mfcc = try_init(|| unsafe {
             ffi::new_aubio_mfcc(
                 buf_size as ffi::uint_t,
                 n_filters as ffi::uint_t,
                 n_coeffs as ffi::uint_t,
                 sample_rate as ffi::uint_t,
             )
-        };
-
-        check_init(mfcc)?;
+        })?;
 
         Ok(Self {
             mfcc,
             buf_size,
+            n_filters,
             n_coeffs,
+            sample_rate,
+            mel_coeffs: None,
         })
     }
 
+    /**
+     * Create new MFCC object with the same parameters
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        let mut mfcc = Self::new(
+            self.buf_size,
+            self.n_filters,
+            self.n_coeffs,
+            self.sample_rate,
+        )?
+        .with_power(self.get_power())
+        .with_scale(self.get_scale());
+
+        match self.mel_coeffs {
+            Some(MelCoeffs::Mel(fmin, fmax)) => mfcc.set_mel_coeffs(fmin, fmax),
+            Some(MelCoeffs::Htk(fmin, fmax)) => mfcc.set_mel_coeffs_htk(fmin, fmax),
+            Some(MelCoeffs::Slaney) => mfcc.set_mel_coeffs_slaney(),
+            None => (),
+        }
+
+        Ok(mfcc)
+    }
+
     /**
      * Set power parameter
      */
@@ -130,6 +175,7 @@ impl MFCC {
 
         input.check_size(self.buf_size)?;
         output.check_size(self.n_coeffs)?;
+        input.check_overlap(&output)?;
 
         unsafe { ffi::aubio_mfcc_do(self.mfcc, input.as_ptr(), output.as_mut_ptr()) }
         Ok(())
@@ -179,6 +225,7 @@ impl MFCC {
         unsafe {
             ffi::aubio_mfcc_set_mel_coeffs(self.mfcc, fmin, fmax);
         }
+        self.mel_coeffs = Some(MelCoeffs::Mel(fmin, fmax));
     }
 
     /**
@@ -193,6 +240,7 @@ impl MFCC {
         unsafe {
             ffi::aubio_mfcc_set_mel_coeffs_htk(self.mfcc, fmin, fmax);
         }
+        self.mel_coeffs = Some(MelCoeffs::Htk(fmin, fmax));
     }
 
     /**
@@ -206,5 +254,6 @@ impl MFCC {
         unsafe {
             ffi::aubio_mfcc_set_mel_coeffs_slaney(self.mfcc);
         }
+        self.mel_coeffs = Some(MelCoeffs::Slaney);
     }
 }
@@ -0,0 +1,266 @@
+use crate::{Error, MidiEvent, Note, Smpl, Status};
+
+use midir::{ConnectError, InitError, MidiOutput, MidiOutputConnection, MidiOutputPort, SendError};
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/// Note off status byte
+const NOTE_OFF: u8 = 0x80;
+/// Note on status byte
+const NOTE_ON: u8 = 0x90;
+
+/**
+ * MIDI output error
+ */
+#[derive(Debug)]
+pub enum MidiError {
+    /**
+     * Failed to initialize MIDI client
+     */
+    Init(InitError),
+
+    /**
+     * No output port found
+     */
+    NoPort,
+
+    /**
+     * Failed to connect to output port
+     */
+    Connect(ConnectError<MidiOutput>),
+
+    /**
+     * Failed to send message
+     */
+    Send(SendError),
+}
+
+impl StdError for MidiError {}
+
+impl Display for MidiError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        use self::MidiError::*;
+        match self {
+            Init(error) => write!(f, "MIDI initialization error: {}", error),
+            NoPort => "no MIDI output port".fmt(f),
+            Connect(error) => write!(f, "MIDI connection error: {}", error),
+            Send(error) => write!(f, "MIDI send error: {}", error),
+        }
+    }
+}
+
+impl From<InitError> for MidiError {
+    fn from(error: InitError) -> Self {
+        MidiError::Init(error)
+    }
+}
+
+impl From<ConnectError<MidiOutput>> for MidiError {
+    fn from(error: ConnectError<MidiOutput>) -> Self {
+        MidiError::Connect(error)
+    }
+}
+
+impl From<SendError> for MidiError {
+    fn from(error: SendError) -> Self {
+        MidiError::Send(error)
+    }
+}
+
+/**
+ * Real-time MIDI output of detected notes
+ *
+ * Sends the note events produced by `Notes` detector to _midir_ output port.
+ * The sounding notes are tracked, so all of it is released when the sink is dropped.
+
+```no_run
+use aubio::{MidiSink, Notes, Smpl};
+
+let mut notes = Notes::new(512, 256, 44100).unwrap();
+let mut sink = MidiSink::connect("aubio", None).unwrap();
+
+# let frames: Vec<[Smpl; 256]> = Vec::new();
+for frame in &frames {
+    notes.do_events(frame.as_ref()).unwrap();
+    for event in notes.poll_events() {
+        sink.send(&event).unwrap();
+    }
+}
+```
+ */
+pub struct MidiSink {
+    connection: MidiOutputConnection,
+    channel: u8,
+    sounding: [bool; 128],
+}
+
+impl Drop for MidiSink {
+    fn drop(&mut self) {
+        let _ = self.all_notes_off();
+    }
+}
+
+impl MidiSink {
+    /**
+     * Create sink using established connection
+     */
+    pub fn new(connection: MidiOutputConnection) -> Self {
+        Self {
+            connection,
+            channel: 0,
+            sounding: [false; 128],
+        }
+    }
+
+    /**
+     * Connect to output port
+     *
+     * - `client_name` Name of MIDI client
+     * - `port_name` The part of name of port to connect to, or `None` to use the first port
+     */
+    pub fn connect(client_name: &str, port_name: Option<&str>) -> Result<Self, MidiError> {
+        let output = MidiOutput::new(client_name)?;
+
+        let port = output
+            .ports()
+            .into_iter()
+            .find(|port| match port_name {
+                Some(name) => output
+                    .port_name(port)
+                    .map(|port_name| port_name.contains(name))
+                    .unwrap_or(false),
+                None => true,
+            })
+            .ok_or(MidiError::NoPort)?;
+
+        Self::connect_port(output, &port, client_name)
+    }
+
+    /**
+     * Connect to given output port
+     */
+    pub fn connect_port(
+        output: MidiOutput,
+        port: &MidiOutputPort,
+        name: &str,
+    ) -> Result<Self, MidiError> {
+        Ok(Self::new(output.connect(port, name)?))
+    }
+
+    /**
+     * Set MIDI channel to send notes to (`0..16`)
+     */
+    pub fn with_channel(mut self, channel: u8) -> crate::Result<Self> {
+        self.set_channel(channel)?;
+        Ok(self)
+    }
+
+    /**
+     * Set MIDI channel to send notes to (`0..16`)
+     */
+    pub fn set_channel(&mut self, channel: u8) -> Status {
+        if channel > 15 {
+            return Err(Error::InvalidArg);
+        }
+        self.channel = channel;
+        Ok(())
+    }
+
+    /**
+     * Get MIDI channel
+     */
+    pub fn get_channel(&self) -> u8 {
+        self.channel
+    }
+
+    /**
+     * Send note event
+     */
+    pub fn send(&mut self, event: &MidiEvent) -> Result<(), MidiError> {
+        self.connection.send(&message(event, self.channel))?;
+
+        match *event {
+            MidiEvent::NoteOn { key, .. } => self.sounding[key as usize & 0x7f] = true,
+            MidiEvent::NoteOff { key, .. } => self.sounding[key as usize & 0x7f] = false,
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Send note produced by `Notes::do_result()`
+     *
+     * The notes with zero velocity are sent as note off messages.
+     */
+    pub fn send_note(&mut self, note: &Note) -> Result<(), MidiError> {
+        let key = to_midi(note.pitch);
+
+        self.send(&if note.velocity > 0.0 {
+            MidiEvent::NoteOn {
+                key,
+                velocity: to_midi(note.velocity),
+                time: 0,
+            }
+        } else {
+            MidiEvent::NoteOff { key, time: 0 }
+        })
+    }
+
+    /**
+     * Release all sounding notes
+     */
+    pub fn all_notes_off(&mut self) -> Result<(), MidiError> {
+        for key in 0..128u8 {
+            if self.sounding[key as usize] {
+                self.send(&MidiEvent::NoteOff { key, time: 0 })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn to_midi(value: Smpl) -> u8 {
+    value.round().clamp(0.0, 127.0) as u8
+}
+
+/**
+ * Encode note event as MIDI message
+ */
+fn message(event: &MidiEvent, channel: u8) -> [u8; 3] {
+    match *event {
+        MidiEvent::NoteOn { key, velocity, .. } => [NOTE_ON | channel, key & 0x7f, velocity & 0x7f],
+        MidiEvent::NoteOff { key, .. } => [NOTE_OFF | channel, key & 0x7f, 0],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::message;
+    use crate::MidiEvent;
+
+    #[test]
+    fn test_message() {
+        assert_eq!(
+            message(
+                &MidiEvent::NoteOn {
+                    key: 60,
+                    velocity: 100,
+                    time: 0,
+                },
+                0,
+            ),
+            [0x90, 60, 100]
+        );
+        assert_eq!(
+            message(&MidiEvent::NoteOff { key: 69, time: 0 }, 9),
+            [0x89, 69, 0]
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{vec::downmix_into, MediaSource, Smpl};
+
+use minimp3::{Decoder, Frame};
+
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use alloc::vec::Vec;
+
+/**
+ * MP3 decoder based on _minimp3_
+ *
+ * Decodes MP3 streams frame by frame and down-mixes channels. The frames
+ * of MP3 stream are produced as is (usually 1152 samples), so wrap the
+ * analyzer by `StreamingAnalyzer` to rebuffer it.
+ *
+ * The first frame is decoded on creation to determine sampling rate
+ * and number of channels.
+ */
+pub struct Mp3Source<R> {
+    decoder: Decoder<R>,
+    sample_rate: u32,
+    channels: usize,
+    first: bool,
+    samples: Vec<Smpl>,
+    frame: Vec<Smpl>,
+}
+
+impl Mp3Source<BufReader<File>> {
+    /**
+     * Open MP3 file
+     *
+     * - `path` Path to MP3 file
+     */
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, minimp3::Error> {
+        Self::new(BufReader::new(
+            File::open(path).map_err(minimp3::Error::Io)?,
+        ))
+    }
+}
+
+impl<R: Read> Mp3Source<R> {
+    /**
+     * Create decoder of MP3 stream
+     *
+     * - `reader` Reader of MP3 stream
+     */
+    pub fn new(reader: R) -> Result<Self, minimp3::Error> {
+        let mut decoder = Decoder::new(reader);
+
+        let (data, sample_rate, channels) = match next_frame(&mut decoder)? {
+            Some(Frame {
+                data,
+                sample_rate,
+                channels,
+                ..
+            }) => (data, sample_rate as u32, channels.max(1)),
+            None => return Err(minimp3::Error::Eof),
+        };
+
+        let mut source = Self {
+            decoder,
+            sample_rate,
+            channels,
+            first: true,
+            samples: Vec::new(),
+            frame: Vec::new(),
+        };
+        source.set_frame(&data, channels);
+
+        Ok(source)
+    }
+}
+
+impl<R> Mp3Source<R> {
+    /**
+     * Convert samples of decoded frame and down-mix it
+     */
+    fn set_frame(&mut self, data: &[i16], channels: usize) {
+        let channels = channels.max(1);
+        let length = data.len() - data.len() % channels;
+
+        self.samples.clear();
+        self.samples.extend(
+            data[..length]
+                .iter()
+                .map(|&sample| sample as Smpl / 32768.0),
+        );
+        self.frame.resize(length / channels, 0.0);
+        downmix_into(&self.samples, channels, &mut self.frame).expect("samples are interleaved");
+    }
+}
+
+impl<R: Read> MediaSource for Mp3Source<R> {
+    type Error = minimp3::Error;
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn channels(&self) -> usize {
+        self.channels
+    }
+
+    fn read_frame(&mut self) -> Result<Option<&[Smpl]>, Self::Error> {
+        if self.first {
+            self.first = false;
+        } else {
+            match next_frame(&mut self.decoder)? {
+                Some(Frame { data, channels, .. }) => self.set_frame(&data, channels),
+                None => return Ok(None),
+            }
+        }
+
+        Ok(Some(&self.frame))
+    }
+}
+
+/**
+ * Decode the next frame with audio samples
+ *
+ * Returns `None` at the end of stream.
+ */
+fn next_frame<R: Read>(decoder: &mut Decoder<R>) -> Result<Option<Frame>, minimp3::Error> {
+    loop {
+        match decoder.next_frame() {
+            Ok(frame) if frame.data.is_empty() => continue,
+            Ok(frame) => return Ok(Some(frame)),
+            // the non-audio data like tags is skipped
+            Err(minimp3::Error::SkippedData) => continue,
+            Err(minimp3::Error::Eof) | Err(minimp3::Error::InsufficientData) => return Ok(None),
+            Err(error) => return Err(error),
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use crate::{vec::FVec, Error, PVoc, Result, Smpl, Status, WindowType};
+
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/// Relative tolerance of harmonic frequencies
+const HARMONIC_TOLERANCE: Smpl = 0.03;
+
+/**
+ * Pitch candidate of polyphonic signal
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PitchCandidate {
+    /// Fundamental frequency in Hz
+    pub freq: Smpl,
+    /// Weighted sum of magnitudes of harmonics
+    pub salience: Smpl,
+}
+
+/// Spectral peak
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Peak {
+    freq: Smpl,
+    mag: Smpl,
+}
+
+/**
+ * Polyphonic pitch detection object
+ *
+ * Estimates up to `max_voices` simultaneous fundamental frequencies in each hop:
+ *
+ * 1. the peaks of magnitude spectrum above threshold are picked,
+ * 2. each peak in frequency range is tried as fundamental and its salience is
+ *    computed as the weighted sum of peaks found at its harmonics,
+ * 3. the most salient candidate is selected, its harmonic peaks are removed,
+ *    and the estimation is repeated for the rest of peaks.
+ */
+pub struct MultiPitch {
+    pvoc: PVoc,
+    grain: Vec<Smpl>,
+    sample_rate: u32,
+    max_voices: usize,
+    min_freq: Smpl,
+    max_freq: Smpl,
+    threshold: Smpl,
+    harmonics: usize,
+}
+
+impl MultiPitch {
+    /**
+     * Create polyphonic pitch detection object
+     *
+     * - `buf_size` Size of analysis buffer (and length the FFT transform)
+     * - `hop_size` Step size between two consecutive analysis instant
+     * - `sample_rate` Sampling rate of the signal
+     */
+    pub fn new(buf_size: usize, hop_size: usize, sample_rate: u32) -> Result<Self> {
+        let pvoc = PVoc::new(buf_size, hop_size)?.with_window(WindowType::Hanningz)?;
+
+        Ok(Self {
+            pvoc,
+            grain: vec![0.; buf_size + 2],
+            sample_rate,
+            max_voices: 4,
+            min_freq: 50.0,
+            max_freq: 2000.0,
+            threshold: -40.0,
+            harmonics: 8,
+        })
+    }
+
+    /**
+     * Set maximum number of simultaneous pitches
+     */
+    pub fn with_max_voices(mut self, max_voices: usize) -> Self {
+        self.set_max_voices(max_voices);
+        self
+    }
+
+    /**
+     * Set range of fundamental frequencies, in Hz
+     */
+    pub fn with_freq_range(mut self, min_freq: Smpl, max_freq: Smpl) -> Result<Self> {
+        self.set_freq_range(min_freq, max_freq).map(|_| self)
+    }
+
+    /**
+     * Set peak picking threshold, in dB relative to the highest peak
+     */
+    pub fn with_threshold(mut self, threshold: Smpl) -> Self {
+        self.set_threshold(threshold);
+        self
+    }
+
+    /**
+     * Set number of harmonics used to compute salience
+     */
+    pub fn with_harmonics(mut self, harmonics: usize) -> Self {
+        self.set_harmonics(harmonics);
+        self
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.pvoc.get_hop()
+    }
+
+    /**
+     * Set maximum number of simultaneous pitches
+     *
+     * Default is `4`.
+     */
+    pub fn set_max_voices(&mut self, max_voices: usize) {
+        self.max_voices = max_voices;
+    }
+
+    /**
+     * Get maximum number of simultaneous pitches
+     */
+    pub fn get_max_voices(&self) -> usize {
+        self.max_voices
+    }
+
+    /**
+     * Set range of fundamental frequencies, in Hz
+     *
+     * Default range is from `50` to `2000` Hz.
+     */
+    pub fn set_freq_range(&mut self, min_freq: Smpl, max_freq: Smpl) -> Status {
+        if min_freq <= 0.0 || max_freq <= min_freq {
+            return Err(Error::InvalidArg);
+        }
+        self.min_freq = min_freq;
+        self.max_freq = max_freq;
+        Ok(())
+    }
+
+    /**
+     * Get range of fundamental frequencies, in Hz
+     */
+    pub fn get_freq_range(&self) -> (Smpl, Smpl) {
+        (self.min_freq, self.max_freq)
+    }
+
+    /**
+     * Set peak picking threshold, in dB relative to the highest peak
+     *
+     * Default is `-40`.
+     */
+    pub fn set_threshold(&mut self, threshold: Smpl) {
+        self.threshold = threshold;
+    }
+
+    /**
+     * Get peak picking threshold, in dB relative to the highest peak
+     */
+    pub fn get_threshold(&self) -> Smpl {
+        self.threshold
+    }
+
+    /**
+     * Set number of harmonics used to compute salience
+     *
+     * Default is `8`.
+     */
+    pub fn set_harmonics(&mut self, harmonics: usize) {
+        self.harmonics = harmonics.max(1);
+    }
+
+    /**
+     * Get number of harmonics used to compute salience
+     */
+    pub fn get_harmonics(&self) -> usize {
+        self.harmonics
+    }
+
+    /**
+     * Execute pitch detection on an input signal frame
+     *
+     * - `input` Input signal of size `hop_size`
+     *
+     * Returns pitch candidates sorted by salience in descending order.
+     */
+    pub fn do_result<'i, I>(&mut self, input: I) -> Result<Vec<PitchCandidate>>
+    where
+        I: Into<FVec<'i>>,
+    {
+        self.pvoc.do_(input, self.grain.as_mut_slice())?;
+
+        let bins = self.grain.len() / 2;
+        let bin_freq = self.sample_rate as Smpl / (self.grain.len() - 2) as Smpl;
+        let peaks = pick_peaks(&self.grain[..bins], bin_freq, self.threshold);
+
+        Ok(self.estimate(peaks))
+    }
+
+    fn estimate(&self, mut peaks: Vec<Peak>) -> Vec<PitchCandidate> {
+        let mut candidates = Vec::new();
+
+        while candidates.len() < self.max_voices {
+            let best = peaks
+                .iter()
+                .filter(|peak| peak.freq >= self.min_freq && peak.freq <= self.max_freq)
+                .map(|peak| PitchCandidate {
+                    freq: peak.freq,
+                    salience: self.salience(&peaks, peak.freq),
+                })
+                .fold(None, |best: Option<PitchCandidate>, candidate| match best {
+                    Some(best) if best.salience >= candidate.salience => Some(best),
+                    _ => Some(candidate),
+                });
+
+            let best = match best {
+                Some(best) if best.salience > 0.0 => best,
+                _ => break,
+            };
+
+            peaks.retain(|peak| harmonic_number(peak.freq, best.freq, self.harmonics).is_none());
+            candidates.push(best);
+        }
+
+        candidates
+    }
+
+    fn salience(&self, peaks: &[Peak], freq: Smpl) -> Smpl {
+        peaks
+            .iter()
+            .filter_map(|peak| {
+                harmonic_number(peak.freq, freq, self.harmonics)
+                    .map(|harmonic| peak.mag / harmonic as Smpl)
+            })
+            .sum()
+    }
+}
+
+/**
+ * Get number of harmonic of fundamental frequency which matches given frequency
+ */
+fn harmonic_number(freq: Smpl, fundamental: Smpl, harmonics: usize) -> Option<usize> {
+    let harmonic = (freq / fundamental).round();
+
+    if harmonic >= 1.0
+        && harmonic <= harmonics as Smpl
+        && (freq - harmonic * fundamental).abs() <= HARMONIC_TOLERANCE * harmonic * fundamental
+    {
+        Some(harmonic as usize)
+    } else {
+        None
+    }
+}
+
+/**
+ * Find local maxima of magnitude spectrum above threshold relative to the highest one
+ */
+fn pick_peaks(norm: &[Smpl], bin_freq: Smpl, threshold: Smpl) -> Vec<Peak> {
+    let max = norm.iter().copied().fold(0.0, Smpl::max);
+
+    if max <= 0.0 {
+        return Vec::new();
+    }
+
+    let min = max * (10.0 as Smpl).powf(threshold / 20.0);
+
+    norm.windows(3)
+        .enumerate()
+        .filter(|(_, values)| values[1] >= min && values[1] > values[0] && values[1] >= values[2])
+        .map(|(index, values)| {
+            // parabolic interpolation of peak position
+            let curvature = values[0] - 2.0 * values[1] + values[2];
+            let offset = if curvature < 0.0 {
+                0.5 * (values[0] - values[2]) / curvature
+            } else {
+                0.0
+            };
+
+            Peak {
+                freq: (index as Smpl + 1.0 + offset) * bin_freq,
+                mag: values[1],
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pick_peaks() {
+        let norm = [0.0, 1.0, 0.0, 0.0, 0.5, 0.0, 0.001, 0.0];
+        let peaks = pick_peaks(&norm, 10.0, -20.0);
+
+        assert_eq!(
+            peaks,
+            vec![
+                Peak {
+                    freq: 10.0,
+                    mag: 1.0
+                },
+                Peak {
+                    freq: 40.0,
+                    mag: 0.5
+                }
+            ]
+        );
+        assert!(pick_peaks(&[0.0; 8], 10.0, -20.0).is_empty());
+    }
+
+    #[test]
+    fn test_multipitch() {
+        let sample_rate = 44100;
+        let buf_size = 4096;
+        let hop_size = 1024;
+
+        let mut multipitch = MultiPitch::new(buf_size, hop_size, sample_rate)
+            .unwrap()
+            .with_max_voices(2);
+
+        // two harmonic tones: A3 and E4 with 4 harmonics
+        let samples = (0..buf_size * 2)
+            .map(|index| {
+                let time = index as Smpl / sample_rate as Smpl;
+                [220.0, 329.63]
+                    .iter()
+                    .flat_map(|freq| {
+                        (1..=4).map(move |harmonic| {
+                            let phase =
+                                2.0 * std::f64::consts::PI as Smpl * freq * harmonic as Smpl;
+                            (phase * time).sin() / harmonic as Smpl
+                        })
+                    })
+                    .sum::<Smpl>()
+                    * 0.1
+            })
+            .collect::<Vec<_>>();
+
+        let mut candidates = Vec::new();
+        for frame in samples.chunks_exact(hop_size) {
+            candidates = multipitch.do_result(frame).unwrap();
+        }
+
+        assert_eq!(candidates.len(), 2);
+        let mut freqs = candidates.iter().map(|c| c.freq).collect::<Vec<_>>();
+        freqs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!((freqs[0] - 220.0).abs() < 3.0);
+        assert!((freqs[1] - 329.63).abs() < 3.0);
+    }
+}
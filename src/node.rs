@@ -0,0 +1,202 @@
+use crate::{Analyzer, Result, Smpl, StreamingAnalyzer};
+
+use fundsp::prelude::{An, AudioNode, Frame, U1, U2};
+
+/**
+ * Analyzer as _fundsp_ audio node
+ *
+ * Has one input and two outputs: the first output passes the input through
+ * unchanged and the second one holds the latest value derived from analysis
+ * results (like onset descriptor, tempo or pitch), so it can be used as control
+ * signal of other nodes.
+ *
+ * The nodes of _fundsp_ should be cloneable, so the analyzer is created by factory
+ * on sample rate changes and for each clone of node. The analyzer is created
+ * lazily on first tick when the sample rate was not set, call
+ * `AudioNode::set_sample_rate()` before processing to avoid allocation
+ * on audio thread.
+ *
+ * The analyzer should be `Send` and `Sync` to be used in audio graphs.
+ */
+pub struct AnalyzerNode<A, G, M> {
+    factory: G,
+    map: M,
+    sample_rate: u32,
+    analyzer: Option<StreamingAnalyzer<A>>,
+    failed: bool,
+    value: Smpl,
+}
+
+impl<A, G, M> AnalyzerNode<A, G, M>
+where
+    A: Analyzer,
+    G: Fn(u32) -> Result<A>,
+    M: Fn(&A, A::Output) -> Smpl,
+{
+    /**
+     * Create node
+     *
+     * - `sample_rate` Initial sampling rate
+     * - `factory` Function which creates the analyzer for given sampling rate
+     * - `map` Function which derives control value from analyzer and result of analysis
+     */
+    pub fn new(sample_rate: u32, factory: G, map: M) -> Self {
+        Self {
+            factory,
+            map,
+            sample_rate,
+            analyzer: None,
+            failed: false,
+            value: 0.0,
+        }
+    }
+
+    /**
+     * Get the latest control value
+     */
+    pub fn get_value(&self) -> Smpl {
+        self.value
+    }
+
+    /**
+     * Check whether analyzer creation or analysis failed
+     *
+     * The control value is not updated after failure until reset.
+     */
+    pub fn is_failed(&self) -> bool {
+        self.failed
+    }
+
+    fn init(&mut self) {
+        match (self.factory)(self.sample_rate) {
+            Ok(analyzer) => self.analyzer = Some(StreamingAnalyzer::new(analyzer)),
+            Err(_) => self.failed = true,
+        }
+    }
+
+    fn feed(&mut self, sample: Smpl) {
+        if self.failed {
+            return;
+        }
+
+        if self.analyzer.is_none() {
+            self.init();
+        }
+
+        if let Some(analyzer) = &mut self.analyzer {
+            let mut result = None;
+
+            match analyzer.push_with(&[sample], |output| result = Some(output)) {
+                Ok(()) => {
+                    if let Some(output) = result {
+                        self.value = (self.map)(analyzer.get_ref(), output);
+                    }
+                }
+                Err(_) => self.failed = true,
+            }
+        }
+    }
+}
+
+impl<A, G: Clone, M: Clone> Clone for AnalyzerNode<A, G, M> {
+    fn clone(&self) -> Self {
+        Self {
+            factory: self.factory.clone(),
+            map: self.map.clone(),
+            sample_rate: self.sample_rate,
+            analyzer: None,
+            failed: false,
+            value: self.value,
+        }
+    }
+}
+
+impl<A, G, M> AudioNode for AnalyzerNode<A, G, M>
+where
+    A: Analyzer + Send + Sync,
+    G: Fn(u32) -> Result<A> + Clone + Send + Sync,
+    M: Fn(&A, A::Output) -> Smpl + Clone + Send + Sync,
+{
+    const ID: u64 = 0x6175_6269_6f00;
+    type Inputs = U1;
+    type Outputs = U2;
+
+    fn reset(&mut self) {
+        self.analyzer = None;
+        self.failed = false;
+        self.value = 0.0;
+        self.init();
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f64) {
+        let sample_rate = sample_rate as u32;
+
+        if sample_rate != self.sample_rate || self.analyzer.is_none() {
+            self.sample_rate = sample_rate;
+            self.failed = false;
+            self.init();
+        }
+    }
+
+    #[allow(clippy::unnecessary_cast)]
+    fn tick(&mut self, input: &Frame<f32, Self::Inputs>) -> Frame<f32, Self::Outputs> {
+        self.feed(input[0] as Smpl);
+        [input[0], self.value as f32].into()
+    }
+}
+
+/**
+ * Create analyzer node wrapped for use in _fundsp_ graph expressions
+
+```ignore
+use aubio::{analyzer_node, Tempo, OnsetMode};
+use fundsp::hacker32::*;
+
+let tempo = analyzer_node(
+    44100,
+    |sample_rate| Tempo::new(OnsetMode::SpecFlux, 1024, 512, sample_rate),
+    |tempo, _| tempo.get_bpm(),
+);
+
+// modulate the cutoff frequency by tempo
+let patch = (pass() | tempo) >> (pass() | mul(10.0)) >> lowpole();
+```
+ */
+pub fn analyzer_node<A, G, M>(sample_rate: u32, factory: G, map: M) -> An<AnalyzerNode<A, G, M>>
+where
+    A: Analyzer + Send + Sync,
+    G: Fn(u32) -> Result<A> + Clone + Send + Sync,
+    M: Fn(&A, A::Output) -> Smpl + Clone + Send + Sync,
+{
+    An(AnalyzerNode::new(sample_rate, factory, map))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::testing::Sum;
+    use crate::*;
+    use fundsp::prelude::AudioNode;
+
+    #[test]
+    fn test_node() {
+        let mut node = AnalyzerNode::new(44100, |_| Ok(Sum(2)), |_: &Sum, output| output * 2.0);
+        node.set_sample_rate(48000.0);
+
+        let outputs = [1.0f32, 2.0, 0.5, 0.5, 0.25]
+            .iter()
+            .map(|&input| {
+                let output = node.tick(&[input].into());
+                (output[0], output[1])
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            outputs,
+            vec![(1.0, 0.0), (2.0, 6.0), (0.5, 6.0), (0.5, 2.0), (0.25, 2.0)]
+        );
+
+        let clone = node.clone();
+        assert_eq!(clone.get_value(), 2.0);
+        assert!(!clone.is_failed());
+    }
+}
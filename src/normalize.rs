@@ -0,0 +1,260 @@
+use crate::{
+    db_spl,
+    vec::{FVec, FVecMut},
+    Error, Smpl, Status,
+};
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/**
+ * Level measure used for normalization
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum NormalizeMode {
+    /**
+     * Absolute peak value of signal
+     */
+    #[default]
+    Peak,
+
+    /**
+     * Root mean square of signal (see `db_spl()`)
+     */
+    Rms,
+}
+
+/**
+ * Gain normalization stage
+ *
+ * Measures the level of signal and applies the gain which brings it to the target
+ * level. Put it in front of detectors so quiet recordings don't fall under the
+ * default silence thresholds.
+ *
+ * The level can be measured over the whole signal (see `Normalize::process()`) or
+ * over the leading part of stream with `Normalize::analyze()` before applying the
+ * gain to each frame with `Normalize::do_()`.
+ */
+#[derive(Debug, Clone)]
+pub struct Normalize {
+    mode: NormalizeMode,
+    target: Smpl,
+    max_gain: Smpl,
+    gain: Smpl,
+}
+
+impl Normalize {
+    /**
+     * Create normalization stage
+     *
+     * - `mode` Level measure
+     * - `target` Target level, in dB (relative to full scale)
+     */
+    pub fn new(mode: NormalizeMode, target: Smpl) -> Self {
+        Self {
+            mode,
+            target,
+            max_gain: 40.0,
+            gain: 1.0,
+        }
+    }
+
+    /**
+     * Set maximum gain, in dB
+     */
+    pub fn with_max_gain(mut self, max_gain: Smpl) -> Self {
+        self.set_max_gain(max_gain);
+        self
+    }
+
+    /**
+     * Get level measure
+     */
+    pub fn get_mode(&self) -> NormalizeMode {
+        self.mode
+    }
+
+    /**
+     * Set target level, in dB
+     */
+    pub fn set_target(&mut self, target: Smpl) {
+        self.target = target;
+    }
+
+    /**
+     * Get target level, in dB
+     */
+    pub fn get_target(&self) -> Smpl {
+        self.target
+    }
+
+    /**
+     * Set maximum gain, in dB
+     *
+     * Limits the amplification of silent or nearly silent signals. Default is `40`.
+     */
+    pub fn set_max_gain(&mut self, max_gain: Smpl) {
+        self.max_gain = max_gain;
+    }
+
+    /**
+     * Get maximum gain, in dB
+     */
+    pub fn get_max_gain(&self) -> Smpl {
+        self.max_gain
+    }
+
+    /**
+     * Set applied gain, on a linear scale
+     */
+    pub fn set_gain(&mut self, gain: Smpl) {
+        self.gain = gain;
+    }
+
+    /**
+     * Get applied gain, on a linear scale
+     */
+    pub fn get_gain(&self) -> Smpl {
+        self.gain
+    }
+
+    /**
+     * Measure level of signal, in dB
+     *
+     * Returns negative infinity for silent or empty signal.
+     */
+    pub fn measure<'i, I>(&self, input: I) -> Smpl
+    where
+        I: Into<FVec<'i>>,
+    {
+        let input = input.into();
+
+        if input.size() == 0 {
+            return Smpl::NEG_INFINITY;
+        }
+
+        match self.mode {
+            NormalizeMode::Peak => {
+                let peak = input
+                    .iter()
+                    .fold(0.0 as Smpl, |peak, value| peak.max(value.abs()));
+                20.0 * peak.log10()
+            }
+            NormalizeMode::Rms => db_spl(input),
+        }
+    }
+
+    /**
+     * Measure level of signal and update the gain
+     *
+     * Returns the new gain on a linear scale.
+     */
+    pub fn analyze<'i, I>(&mut self, input: I) -> Smpl
+    where
+        I: Into<FVec<'i>>,
+    {
+        let level = self.measure(input);
+
+        let gain = if level.is_finite() {
+            (self.target - level).min(self.max_gain)
+        } else {
+            self.max_gain
+        };
+
+        self.gain = (10.0 as Smpl).powf(gain / 20.0);
+        self.gain
+    }
+
+    /**
+     * Apply gain to input signal (out-of-place)
+     *
+     * - `input` Input signal
+     * - `output` Output signal (same size as `input`)
+     */
+    pub fn do_<'i, 'o, I, O>(&self, input: I, output: O) -> Status
+    where
+        I: Into<FVec<'i>>,
+        O: Into<FVecMut<'o>>,
+    {
+        let input = input.into();
+        let mut output = output.into();
+
+        if input.size() != output.size() {
+            return Err(Error::MismatchSize);
+        }
+
+        for (output, input) in output.iter_mut().zip(input.iter()) {
+            *output = input * self.gain;
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Apply gain to signal (in-place)
+     */
+    pub fn do_inplace<'io, IO>(&self, data: IO)
+    where
+        IO: Into<FVecMut<'io>>,
+    {
+        let mut data = data.into();
+
+        for value in data.iter_mut() {
+            *value *= self.gain;
+        }
+    }
+
+    /**
+     * Normalize the whole signal
+     *
+     * Measures the level over all samples and returns the amplified copy.
+     */
+    pub fn process(&mut self, samples: &[Smpl]) -> Vec<Smpl> {
+        self.analyze(samples);
+
+        let mut output = samples.to_vec();
+        self.do_inplace(output.as_mut_slice());
+        output
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_normalize_peak() {
+        let mut normalize = Normalize::new(NormalizeMode::Peak, -6.0);
+
+        let samples = [0.0, 0.01, -0.05, 0.02];
+        let output = normalize.process(&samples);
+
+        let peak = output
+            .iter()
+            .fold(0.0 as Smpl, |peak, value| peak.max(value.abs()));
+        assert!((20.0 * peak.log10() + 6.0).abs() < 1e-3);
+
+        // silence is amplified by maximum gain only
+        assert!((normalize.analyze([0.0; 4].as_ref()) - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_normalize_rms() {
+        let mut normalize = Normalize::new(NormalizeMode::Rms, -20.0).with_max_gain(60.0);
+
+        let samples = [0.001, -0.001, 0.001, -0.001];
+        normalize.analyze(samples.as_ref());
+        assert!((normalize.get_gain() - 100.0).abs() < 1e-2);
+
+        let mut output = [0.0; 4];
+        normalize.do_(samples.as_ref(), output.as_mut()).unwrap();
+        assert!((normalize.measure(output.as_ref()) + 20.0).abs() < 1e-3);
+
+        assert!(normalize.do_(samples.as_ref(), [0.0; 3].as_mut()).is_err());
+    }
+}
@@ -1,13 +1,23 @@
 use crate::{
-    check_init, ffi,
-    vec::{FVec, FVecMut},
-    Result, Smpl, Status,
+    check_params, ffi, try_init,
+    vec::{FVec, FVecMut, Memory},
+    Pitch, PitchMode, Result, Smpl, Status,
 };
 
+use alloc::{
+    collections::{vec_deque::Drain, VecDeque},
+    vec::Vec,
+};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
 /**
  * Recognized note data
  */
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Note {
     pub pitch: Smpl,
     pub velocity: Smpl,
@@ -35,12 +45,65 @@ impl Note {
     }
 }
 
+/**
+ * Note event in MIDI terms
+ *
+ * The `time` is a position of the frame where event was detected, in samples
+ * since the creation of notes detection object.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MidiEvent {
+    NoteOn { key: u8, velocity: u8, time: usize },
+    NoteOff { key: u8, time: usize },
+}
+
+impl MidiEvent {
+    fn parse(values: &[Smpl; 3], time: usize, events: &mut VecDeque<Self>) {
+        let to_midi = |value: Smpl| value.round().clamp(0.0, 127.0) as u8;
+
+        if values[2] != 0.0 {
+            events.push_back(MidiEvent::NoteOff {
+                key: to_midi(values[2]),
+                time,
+            });
+        }
+
+        if values[0] != 0.0 {
+            events.push_back(MidiEvent::NoteOn {
+                key: to_midi(values[0]),
+                velocity: to_midi(values[1]),
+                time,
+            });
+        }
+    }
+
+    /**
+     * Get the time of event in samples
+     */
+    pub fn time(&self) -> usize {
+        match self {
+            MidiEvent::NoteOn { time, .. } | MidiEvent::NoteOff { time, .. } => *time,
+        }
+    }
+}
+
 /**
  * Notes detection object
+ *
+ * The notes can be gated by pitch confidence (see `Notes::set_min_confidence()`).
+ * Because _aubio_ does not expose the confidence of its internal pitch detector
+ * the gating runs the separate `yinfft` pitch detector on the same frames.
  */
 pub struct Notes {
     notes: *mut ffi::aubio_notes_t,
+    buf_size: usize,
     hop_size: usize,
+    sample_rate: u32,
+    position: usize,
+    events: VecDeque<MidiEvent>,
+    gate: Option<(Pitch, Smpl)>,
+    sounding: Smpl,
 }
 
 impl Drop for Notes {
@@ -49,6 +112,9 @@ impl Drop for Notes {
     }
 }
 
+unsafe impl Send for Notes {}
+unsafe impl Sync for Notes {}
+
 impl Notes {
     /**
      * Create notes detection object
@@ -58,18 +124,27 @@ impl Notes {
      * - `samplerate` Sampling rate of the input signal
      */
     pub fn new(buf_size: usize, hop_size: usize, sample_rate: u32) -> Result<Self> {
-        let notes = unsafe {
+        check_params(buf_size, hop_size, sample_rate)?;
+
+        let notes = try_init(|| unsafe {
             ffi::new_aubio_notes(
                 "default\0".as_ptr() as *const _,
                 buf_size as ffi::uint_t,
                 hop_size as ffi::uint_t,
                 sample_rate as ffi::uint_t,
             )
-        };
+        })?;
 
-        check_init(notes)?;
-
-        Ok(Self { notes, hop_size })
+        Ok(Self {
+            notes,
+            buf_size,
+            hop_size,
+            sample_rate,
+            position: 0,
+            events: VecDeque::new(),
+            gate: None,
+            sounding: 0.0,
+        })
     }
 
     /**
@@ -96,6 +171,13 @@ impl Notes {
         self
     }
 
+    /**
+     * Set minimum pitch confidence of new notes
+     */
+    pub fn with_min_confidence(mut self, min_confidence: Smpl) -> Result<Self> {
+        self.set_min_confidence(min_confidence).map(|_| self)
+    }
+
     /**
      * Get hop size
      */
@@ -114,6 +196,9 @@ impl Notes {
      * 0. the midi note value, or 0 if no note was found
      * 1. the note velocity
      * 2. the midi note to turn off
+     *
+     * When gating is enabled the notes with low pitch confidence are
+     * suppressed together with its note off values.
      */
     pub fn do_<'i, 'o, I, O>(&mut self, input: I, output: O) -> Status
     where
@@ -121,12 +206,24 @@ impl Notes {
         O: Into<FVecMut<'o>>,
     {
         let input = input.into();
+        let input = input.as_slice();
         let mut output = output.into();
 
-        input.check_size(self.get_hop())?;
+        FVec::from(input).check_size(self.get_hop())?;
         output.check_size(3)?;
+        FVec::from(input).check_overlap(&output)?;
 
-        unsafe { ffi::aubio_notes_do(self.notes, input.as_ptr(), output.as_mut_ptr()) }
+        let mut notes = [0.; 3];
+        {
+            let input = FVec::from(input);
+            let mut notes = FVecMut::from(notes.as_mut());
+            unsafe { ffi::aubio_notes_do(self.notes, input.as_ptr(), notes.as_mut_ptr()) }
+        }
+        self.position += self.hop_size;
+
+        self.gate(input, &mut notes)?;
+
+        output.as_mut_slice()[..3].copy_from_slice(&notes);
         Ok(())
     }
 
@@ -142,6 +239,36 @@ impl Notes {
         Ok(Note::parse(&output))
     }
 
+    /**
+     * Execute note detection on an input signal frame and queue the events
+     *
+     * The detected events can be taken using `Notes::poll_events()`.
+     */
+    pub fn do_events<'i, I>(&mut self, input: I) -> Status
+    where
+        I: Into<FVec<'i>>,
+    {
+        let time = self.position;
+        let mut output = [0.; 3];
+        self.do_(input, output.as_mut())?;
+        MidiEvent::parse(&output, time, &mut self.events);
+        Ok(())
+    }
+
+    /**
+     * Take the events queued by `Notes::do_events()`
+     */
+    pub fn poll_events(&mut self) -> Drain<'_, MidiEvent> {
+        self.events.drain(..)
+    }
+
+    /**
+     * Get number of processed samples
+     */
+    pub fn get_position(&self) -> usize {
+        self.position
+    }
+
     /**
      * Set notes detection silence threshold
      */
@@ -189,4 +316,139 @@ impl Notes {
     pub fn get_release_drop(&self) -> Smpl {
         unsafe { ffi::aubio_notes_get_release_drop(self.notes) }
     }
+
+    /**
+     * Set minimum pitch confidence of new notes
+     *
+     * The notes with lower confidence are not reported. Default is `0` which means no gating.
+     */
+    pub fn set_min_confidence(&mut self, min_confidence: Smpl) -> Status {
+        if min_confidence <= 0.0 {
+            self.gate = None;
+            return Ok(());
+        }
+
+        match &mut self.gate {
+            Some((_, threshold)) => *threshold = min_confidence,
+            None => {
+                let pitch = Pitch::new(
+                    PitchMode::Yinfft,
+                    self.buf_size,
+                    self.hop_size,
+                    self.sample_rate,
+                )?;
+                self.gate = Some((pitch, min_confidence));
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Get minimum pitch confidence of new notes
+     */
+    pub fn get_min_confidence(&self) -> Smpl {
+        self.gate
+            .as_ref()
+            .map(|(_, min_confidence)| *min_confidence)
+            .unwrap_or(0.0)
+    }
+
+    /**
+     * Create new notes detection object with the same parameters
+     *
+     * The state of analysis is not copied.
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        let mut notes = Self::new(self.buf_size, self.hop_size, self.sample_rate)?
+            .with_silence(self.get_silence())
+            .with_minioi_ms(self.get_minioi_ms())
+            .with_release_drop(self.get_release_drop());
+        notes.set_min_confidence(self.get_min_confidence())?;
+        Ok(notes)
+    }
+
+    /**
+     * Reset notes detection
+     *
+     * The library can't reset the notes detector, so the new one is created
+     * with the same parameters. The pending events is discarded.
+     */
+    pub fn reset(&mut self) -> Status {
+        *self = self.try_clone()?;
+        Ok(())
+    }
+
+    fn gate(&mut self, input: &[Smpl], notes: &mut [Smpl; 3]) -> Status {
+        if let Some((pitch, min_confidence)) = &mut self.gate {
+            pitch.do_result(input)?;
+
+            // note off of suppressed note
+            if notes[2] != 0.0 && notes[2] != self.sounding {
+                notes[2] = 0.0;
+            }
+            if notes[0] != 0.0 && pitch.get_confidence() < *min_confidence {
+                notes[0] = 0.0;
+                notes[1] = 0.0;
+            }
+        }
+
+        if notes[2] != 0.0 && notes[2] == self.sounding {
+            self.sounding = 0.0;
+        }
+        if notes[0] != 0.0 {
+            self.sounding = notes[0];
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_midi_events() {
+        let mut events = VecDeque::new();
+
+        MidiEvent::parse(&[69.2, 100.0, 0.0], 256, &mut events);
+        MidiEvent::parse(&[71.0, 90.0, 69.0], 512, &mut events);
+        MidiEvent::parse(&[0.0, 0.0, 0.0], 768, &mut events);
+
+        assert_eq!(
+            events.into_iter().collect::<Vec<_>>(),
+            vec![
+                MidiEvent::NoteOn {
+                    key: 69,
+                    velocity: 100,
+                    time: 256
+                },
+                MidiEvent::NoteOff { key: 69, time: 512 },
+                MidiEvent::NoteOn {
+                    key: 71,
+                    velocity: 90,
+                    time: 512
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_min_confidence() {
+        let mut notes = Notes::new(512, 256, 44100)
+            .unwrap()
+            .with_min_confidence(0.9)
+            .unwrap();
+
+        assert_eq!(notes.get_min_confidence(), 0.9);
+
+        let frame = [0.0; 256];
+        for _ in 0..8 {
+            assert!(notes.do_result(frame.as_ref()).unwrap().is_empty());
+        }
+
+        notes.set_min_confidence(0.0).unwrap();
+        assert_eq!(notes.get_min_confidence(), 0.0);
+    }
 }
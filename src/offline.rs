@@ -0,0 +1,1455 @@
+/*!
+ * Offline analysis of whole buffers
+ *
+ * The functions of this module create the detection objects, feed them
+ * the whole signal hop by hop and collect the timestamped results.
+ */
+
+use crate::{
+    silence_detection, utils::median, Error, MediaSource, Notes, Onset, OnsetMode, Result, Smpl,
+    Status, Tempo,
+};
+
+use core::{ops::Range, result::Result as StdResult};
+
+#[cfg(not(feature = "no_std"))]
+use crate::{Chroma, Key, MediaError, Source, CHROMA_SIZE};
+
+#[cfg(not(feature = "no_std"))]
+use std::path::{Path, PathBuf};
+
+use alloc::{vec, vec::Vec};
+
+#[cfg(any(
+    feature = "decode-wav",
+    feature = "decode-flac",
+    feature = "decode-mp3"
+))]
+use {alloc::string::ToString, core::fmt::Display};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/**
+ * Call `func` for each frame of `hop_size` samples
+ *
+ * The trailing partial frame is padded by zeros.
+ */
+fn for_each_frame<F>(samples: &[Smpl], hop_size: usize, mut func: F) -> Status
+where
+    F: FnMut(&[Smpl]) -> Status,
+{
+    if hop_size == 0 {
+        return Err(Error::InvalidArg);
+    }
+
+    let mut frames = samples.chunks_exact(hop_size);
+
+    for frame in &mut frames {
+        func(frame)?;
+    }
+
+    let rest = frames.remainder();
+
+    if !rest.is_empty() {
+        let mut frame = vec![0.; hop_size];
+        frame[..rest.len()].copy_from_slice(rest);
+        func(&frame)?;
+    }
+
+    Ok(())
+}
+
+/**
+ * Onset detection options
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct OnsetOptions {
+    /**
+     * Onset detection function
+     */
+    pub method: OnsetMode,
+
+    /**
+     * Buffer size for phase vocoder
+     */
+    pub buf_size: usize,
+
+    /**
+     * Hop size for phase vocoder
+     */
+    pub hop_size: usize,
+
+    /**
+     * Peak picking threshold
+     */
+    pub threshold: Option<Smpl>,
+
+    /**
+     * Silence threshold in dB
+     */
+    pub silence: Option<Smpl>,
+
+    /**
+     * Minimum inter onset interval in milliseconds
+     */
+    pub minioi_ms: Option<Smpl>,
+
+    /**
+     * Adaptive whitening
+     */
+    pub awhitening: Option<bool>,
+
+    /**
+     * Logarithmic compression factor
+     */
+    pub compression: Option<Smpl>,
+}
+
+impl Default for OnsetOptions {
+    fn default() -> Self {
+        Self {
+            method: OnsetMode::default(),
+            buf_size: 512,
+            hop_size: 256,
+            threshold: None,
+            silence: None,
+            minioi_ms: None,
+            awhitening: None,
+            compression: None,
+        }
+    }
+}
+
+impl OnsetOptions {
+    /**
+     * Create onset detection object using options
+     */
+    pub fn create(&self, sample_rate: u32) -> Result<Onset> {
+        let mut onset = Onset::new(self.method, self.buf_size, self.hop_size, sample_rate)?;
+
+        if let Some(threshold) = self.threshold {
+            onset.set_threshold(threshold);
+        }
+        if let Some(silence) = self.silence {
+            onset.set_silence(silence);
+        }
+        if let Some(minioi) = self.minioi_ms {
+            onset.set_minioi_ms(minioi);
+        }
+        if let Some(enable) = self.awhitening {
+            onset.set_awhitening(enable);
+        }
+        if let Some(lambda) = self.compression {
+            onset.set_compression(lambda);
+        }
+
+        Ok(onset)
+    }
+}
+
+/**
+ * Detected onset
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OnsetEvent {
+    /**
+     * Position of onset in samples
+     */
+    pub position: usize,
+
+    /**
+     * Time of onset in seconds
+     */
+    pub time: Smpl,
+
+    /**
+     * Value of onset detection function
+     */
+    pub descriptor: Smpl,
+}
+
+/**
+ * Detect onsets in the whole signal
+ *
+ * - `samples` Mono input signal
+ * - `sample_rate` Sampling rate of the signal
+ * - `options` Onset detection options
+ */
+pub fn detect_onsets(
+    samples: &[Smpl],
+    sample_rate: u32,
+    options: OnsetOptions,
+) -> Result<Vec<OnsetEvent>> {
+    let mut onset = options.create(sample_rate)?;
+    let mut events = Vec::new();
+
+    for_each_frame(samples, options.hop_size, |frame| {
+        if onset.do_result(frame)? > 0.0 {
+            let position = onset.get_last();
+            events.push(OnsetEvent {
+                position,
+                time: position as Smpl / sample_rate as Smpl,
+                descriptor: onset.get_descriptor(),
+            });
+        }
+        Ok(())
+    })?;
+
+    Ok(events)
+}
+
+/**
+ * Beat tracking options
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TempoOptions {
+    /**
+     * Onset detection function used for beat tracking
+     */
+    pub method: OnsetMode,
+
+    /**
+     * Length of FFT
+     */
+    pub buf_size: usize,
+
+    /**
+     * Number of samples between two consecutive runs
+     */
+    pub hop_size: usize,
+
+    /**
+     * Peak picking threshold
+     */
+    pub threshold: Option<Smpl>,
+
+    /**
+     * Silence threshold in dB
+     */
+    pub silence: Option<Smpl>,
+
+    /**
+     * Delay in milliseconds
+     */
+    pub delay_ms: Option<Smpl>,
+}
+
+impl Default for TempoOptions {
+    fn default() -> Self {
+        Self {
+            method: OnsetMode::default(),
+            buf_size: 1024,
+            hop_size: 512,
+            threshold: None,
+            silence: None,
+            delay_ms: None,
+        }
+    }
+}
+
+impl TempoOptions {
+    /**
+     * Create tempo detection object using options
+     */
+    pub fn create(&self, sample_rate: u32) -> Result<Tempo> {
+        let mut tempo = Tempo::new(self.method, self.buf_size, self.hop_size, sample_rate)?;
+
+        if let Some(threshold) = self.threshold {
+            tempo.set_threshold(threshold);
+        }
+        if let Some(silence) = self.silence {
+            tempo.set_silence(silence);
+        }
+        if let Some(delay) = self.delay_ms {
+            tempo.set_delay_ms(delay);
+        }
+
+        Ok(tempo)
+    }
+}
+
+/**
+ * Detected beat
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BeatEvent {
+    /**
+     * Position of beat in samples
+     */
+    pub position: usize,
+
+    /**
+     * Time of beat in seconds
+     */
+    pub time: Smpl,
+
+    /**
+     * Confidence of tempo detection at the beat
+     */
+    pub confidence: Smpl,
+}
+
+/**
+ * Result of beat tracking
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BeatAnalysis {
+    /**
+     * Detected beats
+     */
+    pub beats: Vec<BeatEvent>,
+
+    /**
+     * Global tempo estimate in beats per minute
+     *
+     * It is computed from the median of inter-beat intervals
+     * or equals to `0` when less than two beats was detected.
+     */
+    pub bpm: Smpl,
+}
+
+/**
+ * Track beats in the whole signal
+ *
+ * - `samples` Mono input signal
+ * - `sample_rate` Sampling rate of the signal
+ * - `options` Beat tracking options
+ *
+ * This function works like the `aubiotrack` command-line tool.
+ */
+pub fn track_beats(
+    samples: &[Smpl],
+    sample_rate: u32,
+    options: TempoOptions,
+) -> Result<BeatAnalysis> {
+    let mut tempo = options.create(sample_rate)?;
+    let mut beats = Vec::new();
+
+    for_each_frame(samples, options.hop_size, |frame| {
+        if tempo.do_result(frame)? > 0.0 {
+            let position = tempo.get_last();
+            beats.push(BeatEvent {
+                position,
+                time: position as Smpl / sample_rate as Smpl,
+                confidence: tempo.get_confidence(),
+            });
+        }
+        Ok(())
+    })?;
+
+    let mut intervals = beats
+        .windows(2)
+        .map(|pair| pair[1].time - pair[0].time)
+        .filter(|interval| *interval > 0.0)
+        .collect::<Vec<_>>();
+
+    let bpm = median(&mut intervals)
+        .map(|interval| 60.0 / interval)
+        .unwrap_or(0.0);
+
+    Ok(BeatAnalysis { beats, bpm })
+}
+
+/**
+ * Tempo map options
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TempoMapOptions {
+    /**
+     * Beat tracking options
+     */
+    pub tempo: TempoOptions,
+
+    /**
+     * Length of analysis window in seconds
+     */
+    pub window_s: Smpl,
+
+    /**
+     * Maximum relative difference of tempo of merged windows
+     */
+    pub tolerance: Smpl,
+}
+
+impl Default for TempoMapOptions {
+    fn default() -> Self {
+        Self {
+            tempo: TempoOptions::default(),
+            window_s: 8.0,
+            tolerance: 0.04,
+        }
+    }
+}
+
+/**
+ * Segment of tempo map
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TempoSegment {
+    /**
+     * Time of segment start in seconds
+     */
+    pub start: Smpl,
+
+    /**
+     * Time of segment end in seconds
+     */
+    pub end: Smpl,
+
+    /**
+     * Tempo of segment in beats per minute
+     *
+     * It equals to `0` when the segment contains less than two beats.
+     */
+    pub bpm: Smpl,
+}
+
+/**
+ * Get tempo from median of inter-beat intervals of beats in time range
+ */
+fn beats_bpm(beats: &[BeatEvent], start: Smpl, end: Smpl) -> Smpl {
+    let mut intervals = beats
+        .iter()
+        .filter(|beat| beat.time >= start && beat.time < end)
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|pair| pair[1].time - pair[0].time)
+        .filter(|interval| *interval > 0.0)
+        .collect::<Vec<_>>();
+
+    median(&mut intervals)
+        .map(|interval| 60.0 / interval)
+        .unwrap_or(0.0)
+}
+
+/**
+ * Build tempo map of the whole signal
+ *
+ * - `samples` Mono input signal
+ * - `sample_rate` Sampling rate of the signal
+ * - `options` Tempo map options
+ *
+ * The tempo is estimated in consecutive windows from the tracked beats.
+ * The neighbour windows with similar tempo are merged into segments
+ * and the tempo of each segment is estimated again over the whole segment.
+ */
+pub fn tempo_map(
+    samples: &[Smpl],
+    sample_rate: u32,
+    options: TempoMapOptions,
+) -> Result<Vec<TempoSegment>> {
+    if options.window_s <= 0.0 {
+        return Err(Error::InvalidArg);
+    }
+
+    let beats = track_beats(samples, sample_rate, options.tempo)?.beats;
+    let duration = samples.len() as Smpl / sample_rate as Smpl;
+    let windows = (duration / options.window_s).ceil() as usize;
+
+    let similar =
+        |a: Smpl, b: Smpl| (a == 0.0 && b == 0.0) || (a - b).abs() <= options.tolerance * a.max(b);
+
+    let mut segments: Vec<TempoSegment> = Vec::new();
+
+    for index in 0..windows {
+        let start = index as Smpl * options.window_s;
+        let end = (start + options.window_s).min(duration);
+        let bpm = beats_bpm(&beats, start, end);
+
+        match segments.last_mut() {
+            Some(last) if similar(last.bpm, bpm) => {
+                last.end = end;
+                last.bpm = beats_bpm(&beats, last.start, end);
+            }
+            _ => segments.push(TempoSegment { start, end, bpm }),
+        }
+    }
+
+    Ok(segments)
+}
+
+/**
+ * Histogram of instantaneous tempo estimates
+ *
+ * The estimates are grouped into bins of `1` BPM width.
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BpmHistogram {
+    /**
+     * Tempo at the lower edge of the first bin
+     */
+    pub min_bpm: Smpl,
+
+    /**
+     * Number of estimates in each bin
+     */
+    pub counts: Vec<usize>,
+
+    /**
+     * Final tempo estimate in beats per minute
+     *
+     * It is the mean of estimates in the most populated bin
+     * or equals to `0` when there are no estimates.
+     */
+    pub bpm: Smpl,
+
+    estimates: Vec<Smpl>,
+}
+
+impl BpmHistogram {
+    fn new(mut estimates: Vec<Smpl>) -> Self {
+        estimates.retain(|bpm| *bpm > 0.0);
+        estimates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let (first, last) = match (estimates.first(), estimates.last()) {
+            (Some(first), Some(last)) => (first.floor(), *last),
+            _ => return Self::default(),
+        };
+
+        let mut counts = vec![0; (last - first) as usize + 1];
+        for bpm in &estimates {
+            counts[(bpm - first) as usize] += 1;
+        }
+
+        let mode = counts
+            .iter()
+            .enumerate()
+            .max_by(|(a_index, a), (b_index, b)| a.cmp(b).then(b_index.cmp(a_index)))
+            .map(|(index, _)| first + index as Smpl)
+            .unwrap();
+
+        let in_mode = estimates
+            .iter()
+            .filter(|bpm| **bpm >= mode && **bpm < mode + 1.0)
+            .collect::<Vec<_>>();
+        let bpm = in_mode.iter().copied().sum::<Smpl>() / in_mode.len() as Smpl;
+
+        Self {
+            min_bpm: first,
+            counts,
+            bpm,
+            estimates,
+        }
+    }
+
+    /**
+     * Get all estimates sorted in ascending order
+     */
+    pub fn estimates(&self) -> &[Smpl] {
+        &self.estimates
+    }
+
+    /**
+     * Get percentile of estimates
+     *
+     * - `percent` Percentile from `0` to `100`
+     *
+     * Returns `None` when there are no estimates.
+     */
+    pub fn percentile(&self, percent: Smpl) -> Option<Smpl> {
+        if self.estimates.is_empty() {
+            return None;
+        }
+
+        let rank = percent.clamp(0.0, 100.0) / 100.0 * (self.estimates.len() - 1) as Smpl;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+        let fract = rank - lower as Smpl;
+
+        Some(self.estimates[lower] + (self.estimates[upper] - self.estimates[lower]) * fract)
+    }
+
+    /**
+     * Get median of estimates
+     */
+    pub fn median(&self) -> Option<Smpl> {
+        self.percentile(50.0)
+    }
+}
+
+/**
+ * Build histogram of instantaneous tempo estimates of the whole signal
+ *
+ * - `samples` Mono input signal
+ * - `sample_rate` Sampling rate of the signal
+ *
+ * The tempo estimate of the beat tracker (see `Tempo::get_bpm()`) is collected
+ * at each detected beat using default `TempoOptions`.
+ */
+pub fn bpm_histogram(samples: &[Smpl], sample_rate: u32) -> Result<BpmHistogram> {
+    let options = TempoOptions::default();
+    let mut tempo = options.create(sample_rate)?;
+    let mut estimates = Vec::new();
+
+    for_each_frame(samples, options.hop_size, |frame| {
+        if tempo.do_result(frame)? > 0.0 {
+            estimates.push(tempo.get_bpm());
+        }
+        Ok(())
+    })?;
+
+    Ok(BpmHistogram::new(estimates))
+}
+
+/**
+ * Note transcription options
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct NotesOptions {
+    /**
+     * Buffer size for phase vocoder
+     */
+    pub buf_size: usize,
+
+    /**
+     * Hop size for phase vocoder
+     */
+    pub hop_size: usize,
+
+    /**
+     * Silence threshold in dB
+     */
+    pub silence: Option<Smpl>,
+
+    /**
+     * Minimum inter onset interval in milliseconds
+     */
+    pub minioi_ms: Option<Smpl>,
+
+    /**
+     * Release drop level in dB
+     */
+    pub release_drop: Option<Smpl>,
+}
+
+impl Default for NotesOptions {
+    fn default() -> Self {
+        Self {
+            buf_size: 512,
+            hop_size: 256,
+            silence: None,
+            minioi_ms: None,
+            release_drop: None,
+        }
+    }
+}
+
+impl NotesOptions {
+    /**
+     * Create notes detection object using options
+     */
+    pub fn create(&self, sample_rate: u32) -> Result<Notes> {
+        let mut notes = Notes::new(self.buf_size, self.hop_size, sample_rate)?;
+
+        if let Some(silence) = self.silence {
+            notes.set_silence(silence);
+        }
+        if let Some(minioi) = self.minioi_ms {
+            notes.set_minioi_ms(minioi);
+        }
+        if let Some(release_drop) = self.release_drop {
+            notes.set_release_drop(release_drop);
+        }
+
+        Ok(notes)
+    }
+}
+
+/**
+ * Transcribed note
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NoteEvent {
+    /**
+     * MIDI pitch of note
+     */
+    pub pitch: Smpl,
+
+    /**
+     * Velocity of note
+     */
+    pub velocity: Smpl,
+
+    /**
+     * Time of note start in seconds
+     */
+    pub time: Smpl,
+
+    /**
+     * Duration of note in seconds
+     */
+    pub duration: Smpl,
+}
+
+/**
+ * Transcribe notes of the whole signal
+ *
+ * - `samples` Mono input signal
+ * - `sample_rate` Sampling rate of the signal
+ * - `options` Note transcription options
+ *
+ * The note-on and note-off events produced by `Notes` are paired into notes
+ * with durations. The note which is still sounding at the end of signal
+ * is terminated at the end.
+ */
+pub fn transcribe(
+    samples: &[Smpl],
+    sample_rate: u32,
+    options: NotesOptions,
+) -> Result<Vec<NoteEvent>> {
+    let mut notes = options.create(sample_rate)?;
+    let mut events = Vec::new();
+    let mut current: Option<NoteEvent> = None;
+    let mut position = 0;
+    let period = 1.0 / sample_rate as Smpl;
+
+    for_each_frame(samples, options.hop_size, |frame| {
+        let time = position as Smpl * period;
+
+        for note in notes.do_result(frame)? {
+            if let Some(mut event) = current.take() {
+                if note.velocity > 0.0 || note.pitch == event.pitch {
+                    event.duration = time - event.time;
+                    events.push(event);
+                } else {
+                    current = Some(event);
+                }
+            }
+
+            if note.velocity > 0.0 {
+                current = Some(NoteEvent {
+                    pitch: note.pitch,
+                    velocity: note.velocity,
+                    time,
+                    duration: 0.0,
+                });
+            }
+        }
+
+        position += frame.len();
+        Ok(())
+    })?;
+
+    if let Some(mut event) = current {
+        event.duration = samples.len() as Smpl * period - event.time;
+        events.push(event);
+    }
+
+    Ok(events)
+}
+
+/**
+ * Find active (non-silent) regions of the whole signal
+ *
+ * - `samples` Mono input signal
+ * - `sample_rate` Sampling rate of the signal
+ * - `threshold_db` Silence threshold in dB SPL
+ * - `min_duration` Minimum duration of regions in seconds
+ *
+ * The signal is analyzed in hops of 10 ms using `silence_detection()`.
+ * The silent gaps shorter than `min_duration` do not split active regions
+ * and the active regions shorter than `min_duration` are dropped, which
+ * avoids the flapping around the threshold.
+ *
+ * Returns the ranges of samples of active regions. The silent regions
+ * are the gaps between them.
+ */
+pub fn segment_silence(
+    samples: &[Smpl],
+    sample_rate: u32,
+    threshold_db: Smpl,
+    min_duration: Smpl,
+) -> Vec<Range<usize>> {
+    let hop_size = (sample_rate as usize / 100).max(1);
+    let min_length = (min_duration.max(0.0) * sample_rate as Smpl) as usize;
+    let mut regions: Vec<Range<usize>> = Vec::new();
+
+    for (index, frame) in samples.chunks(hop_size).enumerate() {
+        if silence_detection(frame, threshold_db) {
+            continue;
+        }
+
+        let start = index * hop_size;
+        let end = start + frame.len();
+
+        match regions.last_mut() {
+            Some(last) if start - last.end < min_length.max(1) => last.end = end,
+            _ => regions.push(start..end),
+        }
+    }
+
+    regions.retain(|region| region.len() >= min_length);
+    regions
+}
+
+/// Frame size of overlap-add time stretching
+const STRETCH_FRAME: usize = 1024;
+
+/// Synthesis hop size of overlap-add time stretching
+const STRETCH_HOP: usize = STRETCH_FRAME / 4;
+
+/// Maximum shift of analysis frame to keep waveform continuity
+const STRETCH_TOLERANCE: usize = STRETCH_HOP / 2;
+
+/**
+ * Change tempo of the whole signal keeping the beats aligned
+ *
+ * - `samples` Mono input signal
+ * - `sample_rate` Sampling rate of the signal
+ * - `source_bpm` Tempo of the input signal
+ * - `target_bpm` Desired tempo of the output signal
+ *
+ * The bindings do not provide time stretching object of _aubio_ (it requires
+ * _rubberband_), so the signal is stretched using waveform similarity overlap-add.
+ * The beats are detected using default `TempoOptions` and the signal between
+ * consecutive beats is stretched separately, so each beat starts exactly at the
+ * scaled position and its transient is not smeared over the previous segment.
+ */
+pub fn stretch_to_bpm(
+    samples: &[Smpl],
+    sample_rate: u32,
+    source_bpm: Smpl,
+    target_bpm: Smpl,
+) -> Result<Vec<Smpl>> {
+    if source_bpm <= 0.0 || target_bpm <= 0.0 {
+        return Err(Error::InvalidArg);
+    }
+
+    let ratio = source_bpm / target_bpm;
+    let scale = |position: usize| (position as Smpl * ratio).round() as usize;
+
+    let beats = track_beats(samples, sample_rate, TempoOptions::default())?.beats;
+
+    let mut bounds = vec![0];
+    bounds.extend(
+        beats
+            .iter()
+            .map(|beat| beat.position)
+            .filter(|&position| position > 0 && position < samples.len()),
+    );
+    bounds.push(samples.len());
+    bounds.dedup();
+
+    let mut output = Vec::with_capacity(scale(samples.len()));
+
+    for pair in bounds.windows(2) {
+        let segment = &samples[pair[0]..pair[1]];
+        let length = scale(pair[1]) - scale(pair[0]);
+        output.extend(stretch_segment(segment, length));
+    }
+
+    Ok(output)
+}
+
+/**
+ * Stretch signal to given length using waveform similarity overlap-add
+ */
+fn stretch_segment(input: &[Smpl], length: usize) -> Vec<Smpl> {
+    let mut output = vec![0.; length];
+
+    if input.is_empty() {
+        return output;
+    }
+
+    let mut norm = vec![0.; length];
+    // periodic Hann window shifted by half of sample to avoid zero weights
+    let window = (0..STRETCH_FRAME)
+        .map(|index| {
+            let phase = (index as Smpl + 0.5) / STRETCH_FRAME as Smpl;
+            0.5 - 0.5 * (2.0 * core::f64::consts::PI as Smpl * phase).cos()
+        })
+        .collect::<Vec<_>>();
+
+    let ratio = input.len() as Smpl / length.max(1) as Smpl;
+    let sample = |index: usize| input.get(index).copied().unwrap_or(0.0);
+    let mut previous: Option<usize> = None;
+
+    for start in (0..length).step_by(STRETCH_HOP) {
+        let nominal = (start as Smpl * ratio) as usize;
+
+        let position = match previous {
+            Some(previous) => {
+                // the natural continuation of previous frame
+                let natural = previous + STRETCH_HOP;
+
+                (nominal.saturating_sub(STRETCH_TOLERANCE)..=nominal + STRETCH_TOLERANCE)
+                    .map(|candidate| {
+                        let similarity = (0..STRETCH_HOP)
+                            .map(|index| sample(natural + index) * sample(candidate + index))
+                            .sum::<Smpl>();
+                        (candidate, similarity)
+                    })
+                    .fold((nominal, Smpl::NEG_INFINITY), |best, current| {
+                        if current.1 > best.1 {
+                            current
+                        } else {
+                            best
+                        }
+                    })
+                    .0
+            }
+            None => nominal,
+        };
+
+        for (index, weight) in window.iter().enumerate() {
+            if start + index >= length {
+                break;
+            }
+            output[start + index] += sample(position + index) * weight;
+            norm[start + index] += weight;
+        }
+
+        previous = Some(position);
+    }
+
+    for (value, norm) in output.iter_mut().zip(norm) {
+        if norm > 0.0 {
+            *value /= norm;
+        }
+    }
+
+    output
+}
+
+/**
+ * Batch analysis options
+ */
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct AnalysisConfig {
+    /**
+     * Sampling rate to read files at, `0` to use the rate of each file
+     */
+    pub sample_rate: u32,
+
+    /**
+     * Onset detection options, `None` to skip counting onsets
+     */
+    pub onsets: Option<OnsetOptions>,
+
+    /**
+     * Beat tracking options, `None` to skip tempo estimation
+     */
+    pub tempo: Option<TempoOptions>,
+
+    /**
+     * Estimate musical key
+     */
+    pub key: bool,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl Default for AnalysisConfig {
+    fn default() -> Self {
+        Self {
+            sample_rate: 0,
+            onsets: Some(OnsetOptions::default()),
+            tempo: Some(TempoOptions::default()),
+            key: true,
+        }
+    }
+}
+
+/**
+ * Analysis report of single file
+ */
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FileReport {
+    /**
+     * Path to file
+     */
+    pub path: PathBuf,
+
+    /**
+     * Sampling rate used for analysis
+     */
+    pub sample_rate: u32,
+
+    /**
+     * Number of channels in file
+     */
+    pub channels: usize,
+
+    /**
+     * Duration in seconds
+     */
+    pub duration: Smpl,
+
+    /**
+     * Global tempo in beats per minute when detected
+     */
+    pub bpm: Option<Smpl>,
+
+    /**
+     * Estimated musical key
+     */
+    pub key: Option<Key>,
+
+    /**
+     * Number of detected onsets
+     */
+    pub onsets: Option<usize>,
+}
+
+/// Block size for reading files
+#[cfg(not(feature = "no_std"))]
+const READ_HOP_SIZE: usize = 4096;
+
+/**
+ * Read all samples of media source
+ */
+pub fn read_source<S: MediaSource>(mut source: S) -> StdResult<Vec<Smpl>, S::Error> {
+    let mut samples = Vec::new();
+
+    while let Some(frame) = source.read_frame()? {
+        samples.extend_from_slice(frame);
+    }
+
+    Ok(samples)
+}
+
+/**
+ * Analyze single file
+ *
+ * - `path` Path to audio file
+ * - `config` Analysis options
+ *
+ * The files with `.wav`, `.flac` and `.mp3` extensions are read using pure-Rust
+ * decoders when __decode-wav__, __decode-flac__ and __decode-mp3__ features
+ * is enabled respectively. Other files are read via `Source` (the supported
+ * formats depend on backends of _aubio_). The signal is down-mixed to mono
+ * before analysis.
+ */
+#[cfg(not(feature = "no_std"))]
+pub fn analyze_file(path: impl AsRef<Path>, config: &AnalysisConfig) -> Result<FileReport> {
+    let path = path.as_ref();
+
+    let report = match analyze_decoded_file(path, config) {
+        Some(report) => report?,
+        None => {
+            let mut source = Source::new(path, config.sample_rate, READ_HOP_SIZE)?;
+            let report = analyze_source(&mut source, config).map_err(|error| match error {
+                MediaError::Media(error) | MediaError::Analyzer(error) => error,
+            })?;
+            source.close()?;
+            report
+        }
+    };
+
+    Ok(FileReport {
+        path: path.into(),
+        ..report
+    })
+}
+
+/**
+ * Analyze file using pure-Rust decoder selected by extension
+ *
+ * Returns `None` when no decoder is enabled for file.
+ */
+#[cfg(not(feature = "no_std"))]
+#[allow(unused_variables)]
+fn analyze_decoded_file(path: &Path, config: &AnalysisConfig) -> Option<Result<FileReport>> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+
+    #[cfg(feature = "decode-wav")]
+    if extension == "wav" {
+        return Some(analyze_decoded(
+            crate::WavSource::open(path, READ_HOP_SIZE),
+            config,
+        ));
+    }
+
+    #[cfg(feature = "decode-flac")]
+    if extension == "flac" {
+        return Some(analyze_decoded(
+            crate::FlacSource::open(path, READ_HOP_SIZE),
+            config,
+        ));
+    }
+
+    #[cfg(feature = "decode-mp3")]
+    if extension == "mp3" {
+        return Some(analyze_decoded(crate::Mp3Source::open(path), config));
+    }
+
+    None
+}
+
+/**
+ * Analyze signal of opened decoder resampled to the rate from config
+ *
+ * The decoding errors is reported as errors of backend.
+ */
+#[cfg(any(
+    feature = "decode-wav",
+    feature = "decode-flac",
+    feature = "decode-mp3"
+))]
+fn analyze_decoded<S, E>(source: StdResult<S, E>, config: &AnalysisConfig) -> Result<FileReport>
+where
+    S: MediaSource,
+    S::Error: Display,
+    E: Display,
+{
+    let source = source.map_err(|error| Error::Backend(error.to_string()))?;
+    let source_rate = source.sample_rate();
+    let channels = source.channels();
+    let mut samples = read_source(source).map_err(|error| Error::Backend(error.to_string()))?;
+
+    let sample_rate = if config.sample_rate > 0 && config.sample_rate != source_rate {
+        let mut resampler = crate::StreamingResampler::new(
+            config.sample_rate as Smpl / source_rate as Smpl,
+            crate::ResampleMode::default(),
+        )?;
+        let mut resampled = resampler.push(&samples)?;
+        resampled.extend(resampler.flush()?);
+        samples = resampled;
+        config.sample_rate
+    } else {
+        source_rate
+    };
+
+    analyze_samples(&samples, sample_rate, channels, config)
+}
+
+/**
+ * Analyze media source
+ *
+ * - `source` Media source (like `Source` or `SymphoniaSource`)
+ * - `config` Analysis options (the `sample_rate` is ignored)
+ *
+ * The source is read to the end before analysis. The `path` of report is empty.
+ */
+#[cfg(not(feature = "no_std"))]
+pub fn analyze_source<S: MediaSource>(
+    source: S,
+    config: &AnalysisConfig,
+) -> StdResult<FileReport, MediaError<S::Error>> {
+    let sample_rate = source.sample_rate();
+    let channels = source.channels();
+    let samples = read_source(source).map_err(MediaError::Media)?;
+
+    Ok(analyze_samples(&samples, sample_rate, channels, config)?)
+}
+
+#[cfg(not(feature = "no_std"))]
+fn analyze_samples(
+    samples: &[Smpl],
+    sample_rate: u32,
+    channels: usize,
+    config: &AnalysisConfig,
+) -> Result<FileReport> {
+    let bpm = match config.tempo {
+        Some(options) => {
+            Some(track_beats(samples, sample_rate, options)?.bpm).filter(|&bpm| bpm > 0.0)
+        }
+        None => None,
+    };
+
+    let onsets = match config.onsets {
+        Some(options) => Some(detect_onsets(samples, sample_rate, options)?.len()),
+        None => None,
+    };
+
+    let key = if config.key {
+        estimate_key(samples, sample_rate)?
+    } else {
+        None
+    };
+
+    Ok(FileReport {
+        path: PathBuf::new(),
+        sample_rate,
+        channels,
+        duration: samples.len() as Smpl / sample_rate as Smpl,
+        bpm,
+        key,
+        onsets,
+    })
+}
+
+/**
+ * Analyze several files
+ *
+ * - `paths` Paths to audio files
+ * - `config` Analysis options
+ *
+ * Returns the result for each file in the same order, so the failure
+ * of one file does not interrupt the analysis of others.
+ */
+#[cfg(not(feature = "no_std"))]
+pub fn analyze_files<I, P>(paths: I, config: AnalysisConfig) -> Vec<Result<FileReport>>
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<Path>,
+{
+    paths
+        .into_iter()
+        .map(|path| analyze_file(path, &config))
+        .collect()
+}
+
+/**
+ * Estimate key from chroma accumulated over the whole signal
+ */
+#[cfg(not(feature = "no_std"))]
+fn estimate_key(samples: &[Smpl], sample_rate: u32) -> Result<Option<Key>> {
+    let mut chroma = Chroma::new(4096, 2048, sample_rate)?;
+    let mut total = [0.; CHROMA_SIZE];
+
+    for_each_frame(samples, chroma.get_hop(), |frame| {
+        for (total, value) in total.iter_mut().zip(&chroma.do_result(frame)?) {
+            *total += value;
+        }
+        Ok(())
+    })?;
+
+    Ok(Key::estimate(&total))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn clicks(sample_rate: u32, length: Smpl, period: Smpl) -> Vec<Smpl> {
+        let mut seed = 1u32;
+        let mut samples = vec![0.; (length * sample_rate as Smpl) as usize];
+        let step = (period * sample_rate as Smpl) as usize;
+
+        for start in (step..samples.len()).step_by(step) {
+            for (index, sample) in samples[start..].iter_mut().take(2048).enumerate() {
+                seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+                let noise = (seed >> 16) as Smpl / 32768.0 - 1.0;
+                *sample = noise * (1.0 - index as Smpl / 2048.0);
+            }
+        }
+
+        samples
+    }
+
+    /// Frames to read and the current frame
+    struct Frames(Vec<Vec<Smpl>>, Vec<Smpl>);
+
+    impl MediaSource for Frames {
+        type Error = Error;
+
+        fn sample_rate(&self) -> u32 {
+            1000
+        }
+
+        fn channels(&self) -> usize {
+            1
+        }
+
+        fn read_frame(&mut self) -> Result<Option<&[Smpl]>> {
+            if self.0.is_empty() {
+                return Err(Error::InvalidArg);
+            }
+            self.1 = self.0.remove(0);
+            Ok(if self.1.is_empty() {
+                None
+            } else {
+                Some(&self.1)
+            })
+        }
+    }
+
+    #[test]
+    fn test_read_source() {
+        let source = Frames(vec![vec![1.0, 2.0], vec![3.0], vec![]], vec![]);
+        assert_eq!(read_source(source).unwrap(), vec![1.0, 2.0, 3.0]);
+
+        assert_eq!(read_source(Frames(vec![], vec![])), Err(Error::InvalidArg));
+    }
+
+    #[test]
+    fn test_frames() {
+        let mut frames = Vec::new();
+        for_each_frame(&[1.0, 2.0, 3.0, 4.0, 5.0], 2, |frame| {
+            frames.push(frame.to_vec());
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(frames, vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 0.0]]);
+
+        assert!(for_each_frame(&[1.0], 0, |_| Ok(())).is_err());
+    }
+
+    #[test]
+    fn test_track_beats() {
+        let samples = clicks(44100, 10.0, 0.5);
+        let analysis = track_beats(&samples, 44100, TempoOptions::default()).unwrap();
+
+        assert!(!analysis.beats.is_empty());
+        assert!((analysis.bpm - 120.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_transcribe() {
+        let sample_rate = 44100;
+        let samples = (0..sample_rate)
+            .map(|index| {
+                let time = index as Smpl / sample_rate as Smpl;
+                if (0.25..0.75).contains(&time) {
+                    (2.0 * std::f64::consts::PI as Smpl * 440.0 * time).sin() * 0.5
+                } else {
+                    0.0
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let notes = transcribe(&samples, sample_rate, NotesOptions::default()).unwrap();
+
+        assert!(!notes.is_empty());
+        assert!((notes[0].pitch - 69.0).abs() < 1.0);
+        assert!((notes[0].time - 0.25).abs() < 0.05);
+        assert!(notes[0].duration > 0.3);
+    }
+
+    #[test]
+    fn test_detect_onsets() {
+        let samples = clicks(44100, 2.0, 0.5);
+        let events = detect_onsets(&samples, 44100, OnsetOptions::default()).unwrap();
+
+        for expected in &[0.5, 1.0, 1.5] {
+            assert!(events
+                .iter()
+                .any(|event| (event.time - expected).abs() < 0.02));
+        }
+    }
+
+    #[test]
+    fn test_segment_silence() {
+        let sample_rate = 1000;
+        let mut samples = vec![0.0; 3000];
+
+        // active from 0.5 to 1.5 s with short gap at 1.0 s
+        for sample in &mut samples[500..1500] {
+            *sample = 0.5;
+        }
+        for sample in &mut samples[1000..1020] {
+            *sample = 0.0;
+        }
+        // short burst which should be dropped
+        for sample in &mut samples[2000..2020] {
+            *sample = 0.5;
+        }
+        // active until the end
+        for sample in &mut samples[2500..] {
+            *sample = 0.5;
+        }
+
+        assert_eq!(
+            segment_silence(&samples, sample_rate, -40.0, 0.1),
+            vec![500..1500, 2500..3000]
+        );
+        assert_eq!(
+            segment_silence(&samples, sample_rate, -40.0, 0.0),
+            vec![500..1000, 1020..1500, 2000..2020, 2500..3000]
+        );
+        assert!(segment_silence(&[], sample_rate, -40.0, 0.1).is_empty());
+    }
+
+    #[test]
+    fn test_bpm_histogram() {
+        let histogram = BpmHistogram::new(vec![0.0, 121.0, 120.2, 119.5, 120.6, 60.0]);
+
+        assert_eq!(histogram.min_bpm, 60.0);
+        assert_eq!(histogram.counts.len(), 62);
+        assert_eq!(histogram.counts[60], 2);
+        assert!((histogram.bpm - 120.4).abs() < 1e-4);
+        assert_eq!(histogram.estimates().len(), 5);
+        assert_eq!(histogram.percentile(0.0), Some(60.0));
+        assert_eq!(histogram.percentile(100.0), Some(121.0));
+        assert_eq!(histogram.median(), Some(120.2));
+
+        let empty = BpmHistogram::new(vec![]);
+        assert_eq!(empty.bpm, 0.0);
+        assert_eq!(empty.median(), None);
+
+        let samples = clicks(44100, 10.0, 0.5);
+        let histogram = bpm_histogram(&samples, 44100).unwrap();
+        assert!((histogram.bpm - 120.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_beats_bpm() {
+        let beats = [0.0, 0.5, 1.0, 1.5, 2.0, 2.25, 2.5, 2.75]
+            .iter()
+            .map(|&time| BeatEvent {
+                position: (time * 1000.0) as usize,
+                time,
+                confidence: 1.0,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(beats_bpm(&beats, 0.0, 2.0), 120.0);
+        assert_eq!(beats_bpm(&beats, 2.0, 3.0), 240.0);
+        assert_eq!(beats_bpm(&beats, 3.0, 4.0), 0.0);
+    }
+
+    #[test]
+    fn test_tempo_map() {
+        let mut samples = clicks(44100, 20.0, 0.5);
+        samples.extend(clicks(44100, 20.0, 0.4));
+
+        let segments = tempo_map(&samples, 44100, TempoMapOptions::default()).unwrap();
+
+        assert!(segments.len() >= 2);
+        assert!((segments[0].bpm - 120.0).abs() < 5.0);
+        assert!((segments.last().unwrap().bpm - 150.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_stretch_segment() {
+        let input = vec![0.5; 4000];
+
+        let output = stretch_segment(&input, 5000);
+        assert_eq!(output.len(), 5000);
+        assert!(output[..4000]
+            .iter()
+            .all(|&value| (value - 0.5).abs() < 1e-4));
+
+        assert_eq!(stretch_segment(&input, 3000).len(), 3000);
+        assert_eq!(stretch_segment(&[], 100), vec![0.0; 100]);
+    }
+
+    #[test]
+    fn test_stretch_to_bpm() {
+        let samples = clicks(44100, 4.0, 0.5);
+
+        let output = stretch_to_bpm(&samples, 44100, 120.0, 100.0).unwrap();
+        assert_eq!(output.len(), samples.len() * 6 / 5);
+
+        assert!(stretch_to_bpm(&samples, 44100, 0.0, 100.0).is_err());
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    #[test]
+    fn test_analyze_files() {
+        let reports = analyze_files(["/nonexistent/file.wav"], AnalysisConfig::default());
+        assert_eq!(reports.len(), 1);
+        assert!(reports[0].is_err());
+    }
+}
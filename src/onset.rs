@@ -1,10 +1,10 @@
 use crate::{
-    check_init, ffi,
-    vec::{FVec, FVecMut},
+    check_params, ffi, try_init,
+    vec::{FVec, FVecMut, Memory},
     AsNativeStr, Error, Result, Smpl, SpecMethod, Status,
 };
 
-use std::{
+use core::{
     fmt::{Display, Formatter, Result as FmtResult},
     str::FromStr,
 };
@@ -13,6 +13,8 @@ use std::{
  * Onset detection function
  */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum OnsetMode {
     /**
      * Energy based onset detection function
@@ -153,6 +155,10 @@ impl FromStr for OnsetMode {
  */
 pub struct Onset {
     onset: *mut ffi::aubio_onset_t,
+    method: OnsetMode,
+    buf_size: usize,
+    hop_size: usize,
+    sample_rate: u32,
 }
 
 impl Drop for Onset {
@@ -161,6 +167,9 @@ impl Drop for Onset {
     }
 }
 
+unsafe impl Send for Onset {}
+unsafe impl Sync for Onset {}
+
 impl Onset {
     /**
      * Create onset detection object
@@ -176,18 +185,42 @@ impl Onset {
         hop_size: usize,
         sample_rate: u32,
     ) -> Result<Self> {
-        let onset = unsafe {
+        check_params(buf_size, hop_size, sample_rate)?;
+
+        let onset = try_init(|| unsafe {
             ffi::new_aubio_onset(
                 method.as_native_cstr(),
                 buf_size as ffi::uint_t,
                 hop_size as ffi::uint_t,
                 sample_rate as ffi::uint_t,
             )
-        };
+        })?;
+
+        Ok(Self {
+            onset,
+            method,
+            buf_size,
+            hop_size,
+            sample_rate,
+        })
+    }
+
+    /**
+     * Create new onset detection object with the same parameters
+     *
+     * The state of analysis is not copied.
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        let mut onset = Self::new(self.method, self.buf_size, self.hop_size, self.sample_rate)?;
 
-        check_init(onset)?;
+        onset.set_awhitening(self.get_awhitening());
+        onset.set_compression(self.get_compression());
+        onset.set_silence(self.get_silence());
+        onset.set_threshold(self.get_threshold());
+        onset.set_minioi(self.get_minioi());
+        onset.set_delay(self.get_delay());
 
-        Ok(Self { onset })
+        Ok(onset)
     }
 
     /**
@@ -293,6 +326,7 @@ impl Onset {
 
         input.check_size(self.get_hop())?;
         onset.check_size(1)?;
+        input.check_overlap(&onset)?;
 
         unsafe { ffi::aubio_onset_do(self.onset, input.as_ptr(), onset.as_mut_ptr()) }
         Ok(())
@@ -314,7 +348,7 @@ impl Onset {
      * Get hop size
      */
     pub fn get_hop(&self) -> usize {
-        (unsafe { ffi::aubio_pvoc_get_hop(self.onset.cast::<ffi::aubio_pvoc_t>()) }) as usize
+        self.hop_size
     }
 
     /**
@@ -340,6 +374,10 @@ impl Onset {
 
     /**
      * Set onset detection adaptive whitening
+     *
+     * The internal whitening uses default relaxation time and floor of the C library,
+     * because the library does not expose the setters for them.
+     * See `SpectralWhitening` to apply the whitening with custom parameters.
      */
     pub fn set_awhitening(&mut self, enable: bool) {
         unsafe {
@@ -540,6 +578,22 @@ mod test {
         // TODO
     }
 
+    #[test]
+    fn test_try_clone() {
+        let onset = Onset::new(OnsetMode::SpecFlux, 1024, 512, 44100)
+            .unwrap()
+            .with_threshold(0.5)
+            .with_silence(-50.0)
+            .with_minioi(2048);
+
+        let clone = onset.try_clone().unwrap();
+
+        assert_eq!(clone.get_hop(), 512);
+        assert_eq!(clone.get_threshold(), 0.5);
+        assert_eq!(clone.get_silence(), -50.0);
+        assert_eq!(clone.get_minioi(), 2048);
+    }
+
     #[test]
     fn test_wrong_params() {
         const WIN_S: usize = 1024;
@@ -553,6 +607,21 @@ mod test {
         assert!(Onset::new(Default::default(), HOP_S, WIN_S, SAMPLERATE).is_err());
         // SAMPLERATE < 1
         assert!(Onset::new(Default::default(), 1024, 512, 0).is_err());
+        assert_eq!(
+            Onset::new(Default::default(), HOP_S, WIN_S, SAMPLERATE).err(),
+            Some(Error::InvalidParameter {
+                name: "hop_size",
+                value: WIN_S,
+                reason: "should not be greater than buffer size",
+            })
+        );
+        assert!(matches!(
+            Onset::new(Default::default(), 1024, 512, 0),
+            Err(Error::InvalidParameter {
+                name: "sample_rate",
+                ..
+            })
+        ));
         // pv creation might fail
         assert_eq!(
             Onset::new(Default::default(), 5, 2, SAMPLERATE).is_ok(),
@@ -0,0 +1,239 @@
+use crate::{MidiEvent, Smpl};
+
+use rosc::{encoder, OscError, OscMessage, OscPacket, OscType};
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Error as IoError,
+    net::{SocketAddr, ToSocketAddrs, UdpSocket},
+};
+
+use alloc::{format, string::String, vec, vec::Vec};
+
+/**
+ * OSC sending error
+ */
+#[derive(Debug)]
+pub enum OscSendError {
+    /**
+     * Network error
+     */
+    Io(IoError),
+
+    /**
+     * Failed to encode message
+     */
+    Encode(OscError),
+}
+
+impl StdError for OscSendError {}
+
+impl Display for OscSendError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        use self::OscSendError::*;
+        match self {
+            Io(error) => write!(f, "network error: {}", error),
+            Encode(error) => write!(f, "encoding error: {:?}", error),
+        }
+    }
+}
+
+impl From<IoError> for OscSendError {
+    fn from(error: IoError) -> Self {
+        OscSendError::Io(error)
+    }
+}
+
+impl From<OscError> for OscSendError {
+    fn from(error: OscError) -> Self {
+        OscSendError::Encode(error)
+    }
+}
+
+/**
+ * Broadcasting of detected events as OSC messages over UDP
+ *
+ * The messages are sent to the addresses under configurable prefix
+ * (`/aubio` by default):
+ *
+ * - `/aubio/onset f` Time of onset in seconds
+ * - `/aubio/beat ff` Time of beat in seconds and current tempo in BPM
+ * - `/aubio/pitch ff` Pitch and its confidence
+ * - `/aubio/note ii` MIDI key and velocity (`0` for note off)
+
+```no_run
+use aubio::OscSender;
+
+let osc = OscSender::new("127.0.0.1:57120").unwrap().with_prefix("/beats");
+
+osc.send_beat(0.5, 120.0).unwrap();
+```
+ */
+pub struct OscSender {
+    socket: UdpSocket,
+    target: SocketAddr,
+    prefix: String,
+}
+
+impl OscSender {
+    /**
+     * Create sender to given address
+     *
+     * The socket is bound to any local address.
+     */
+    pub fn new(target: impl ToSocketAddrs) -> Result<Self, OscSendError> {
+        let target = target
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| IoError::new(std::io::ErrorKind::InvalidInput, "no target address"))?;
+
+        let local: SocketAddr = if target.is_ipv4() {
+            ([0, 0, 0, 0], 0).into()
+        } else {
+            ([0u16; 8], 0).into()
+        };
+
+        Ok(Self::with_socket(UdpSocket::bind(local)?, target))
+    }
+
+    /**
+     * Create sender using bound socket
+     */
+    pub fn with_socket(socket: UdpSocket, target: SocketAddr) -> Self {
+        Self {
+            socket,
+            target,
+            prefix: "/aubio".into(),
+        }
+    }
+
+    /**
+     * Set prefix of message addresses
+     */
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.set_prefix(prefix);
+        self
+    }
+
+    /**
+     * Set prefix of message addresses
+     */
+    pub fn set_prefix(&mut self, prefix: impl Into<String>) {
+        let mut prefix = prefix.into();
+        while prefix.ends_with('/') {
+            prefix.pop();
+        }
+        self.prefix = prefix;
+    }
+
+    /**
+     * Get prefix of message addresses
+     */
+    pub fn get_prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /**
+     * Set target address
+     */
+    pub fn set_target(&mut self, target: SocketAddr) {
+        self.target = target;
+    }
+
+    /**
+     * Get target address
+     */
+    pub fn get_target(&self) -> SocketAddr {
+        self.target
+    }
+
+    /**
+     * Send onset
+     *
+     * - `time` Time of onset in seconds
+     */
+    pub fn send_onset(&self, time: Smpl) -> Result<(), OscSendError> {
+        self.send("onset", vec![float(time)])
+    }
+
+    /**
+     * Send beat
+     *
+     * - `time` Time of beat in seconds
+     * - `bpm` Current tempo in beats per minute
+     */
+    pub fn send_beat(&self, time: Smpl, bpm: Smpl) -> Result<(), OscSendError> {
+        self.send("beat", vec![float(time), float(bpm)])
+    }
+
+    /**
+     * Send pitch
+     *
+     * - `pitch` Detected pitch in units of detector
+     * - `confidence` Confidence of detection
+     */
+    pub fn send_pitch(&self, pitch: Smpl, confidence: Smpl) -> Result<(), OscSendError> {
+        self.send("pitch", vec![float(pitch), float(confidence)])
+    }
+
+    /**
+     * Send note event
+     */
+    pub fn send_note(&self, event: &MidiEvent) -> Result<(), OscSendError> {
+        let (key, velocity) = match *event {
+            MidiEvent::NoteOn { key, velocity, .. } => (key, velocity),
+            MidiEvent::NoteOff { key, .. } => (key, 0),
+        };
+
+        self.send(
+            "note",
+            vec![OscType::Int(key as i32), OscType::Int(velocity as i32)],
+        )
+    }
+
+    /**
+     * Send custom message
+     *
+     * - `name` Address of message relative to prefix
+     * - `args` Arguments of message
+     */
+    pub fn send(&self, name: &str, args: Vec<OscType>) -> Result<(), OscSendError> {
+        let packet = OscPacket::Message(OscMessage {
+            addr: self.address(name),
+            args,
+        });
+
+        self.socket
+            .send_to(&encoder::encode(&packet)?, self.target)?;
+
+        Ok(())
+    }
+
+    fn address(&self, name: &str) -> String {
+        format!("{}/{}", self.prefix, name.trim_start_matches('/'))
+    }
+}
+
+/**
+ * Convert sample to OSC float which is always single precision
+ */
+#[allow(clippy::unnecessary_cast)]
+fn float(value: Smpl) -> OscType {
+    OscType::Float(value as f32)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_address() {
+        let mut osc = OscSender::new("127.0.0.1:57120").unwrap();
+        assert_eq!(osc.address("onset"), "/aubio/onset");
+
+        osc.set_prefix("/live/");
+        assert_eq!(osc.get_prefix(), "/live");
+        assert_eq!(osc.address("/beat"), "/live/beat");
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{
+    ring::Consumer,
+    vec::downmix_into,
+    worker::{spawn_worker, WorkerEvent},
+    Analyzer, Error, Smpl,
+};
+
+use portaudio::{
+    stream::InputCallbackArgs, Continue, DeviceIndex, Input, InputStreamCallbackArgs,
+    InputStreamSettings, NonBlocking, PortAudio, Stream, StreamParameters,
+};
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::mpsc::{channel, Receiver},
+};
+
+use alloc::vec::Vec;
+
+/**
+ * PortAudio capture error
+ */
+#[derive(Debug)]
+pub enum PortAudioError {
+    /**
+     * Error reported by PortAudio
+     */
+    PortAudio(portaudio::Error),
+
+    /**
+     * Failed to create analyzer
+     */
+    Analyzer(Error),
+}
+
+impl StdError for PortAudioError {}
+
+impl Display for PortAudioError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        use self::PortAudioError::*;
+        match self {
+            PortAudio(error) => write!(f, "PortAudio error: {}", error),
+            Analyzer(error) => write!(f, "analyzer error: {}", error),
+        }
+    }
+}
+
+impl From<portaudio::Error> for PortAudioError {
+    fn from(error: portaudio::Error) -> Self {
+        PortAudioError::PortAudio(error)
+    }
+}
+
+/**
+ * Event delivered by PortAudio capture
+ */
+#[derive(Debug)]
+pub enum PortAudioEvent<T> {
+    /**
+     * Result of analysis of single frame
+     */
+    Frame {
+        /// Position of frame in samples from the start of capture
+        position: usize,
+        /// Analysis result
+        output: T,
+    },
+
+    /**
+     * Analysis failed, no more frames will be delivered
+     */
+    Error(Error),
+}
+
+impl<T> WorkerEvent<T> for PortAudioEvent<T> {
+    fn frame(position: usize, output: T) -> Self {
+        PortAudioEvent::Frame { position, output }
+    }
+
+    fn error(error: Error) -> Self {
+        PortAudioEvent::Error(error)
+    }
+}
+
+/**
+ * Real-time audio input analyzer based on _PortAudio_
+ *
+ * Works like `CpalCapture`: owns the non-blocking input stream, down-mixes
+ * the samples and feeds the analyzer running on the dedicated worker thread.
+ * The stream is opened with buffers of `hop_size` frames, so usually each
+ * callback delivers exactly one frame to analyzer.
+ *
+ * Like in `CpalCapture` the samples is passed through the preallocated buffer, and
+ * the samples which does not fit into it when the analysis is too slow are dropped.
+ */
+pub struct PortAudioCapture<T> {
+    stream: Stream<NonBlocking, Input<f32>>,
+    events: Receiver<PortAudioEvent<T>>,
+    sample_rate: u32,
+    channels: usize,
+    // should be dropped after stream
+    _portaudio: PortAudio,
+}
+
+impl<T: Send + 'static> PortAudioCapture<T> {
+    /**
+     * Start analysis of default input device
+     *
+     * - `factory` Function which creates the analyzer for given sampling rate
+     */
+    pub fn with_default_device<A, F>(factory: F) -> Result<Self, PortAudioError>
+    where
+        A: Analyzer<Output = T>,
+        F: FnOnce(u32) -> crate::Result<A> + Send + 'static,
+    {
+        let portaudio = PortAudio::new()?;
+        let device = portaudio.default_input_device()?;
+
+        Self::new(portaudio, device, factory)
+    }
+
+    /**
+     * Start analysis of input device
+     *
+     * - `portaudio` Initialized PortAudio instance
+     * - `device` Input device
+     * - `factory` Function which creates the analyzer for given sampling rate
+     */
+    pub fn new<A, F>(
+        portaudio: PortAudio,
+        device: DeviceIndex,
+        factory: F,
+    ) -> Result<Self, PortAudioError>
+    where
+        A: Analyzer<Output = T>,
+        F: FnOnce(u32) -> crate::Result<A> + Send + 'static,
+    {
+        let info = portaudio.device_info(device)?;
+        let sample_rate = info.default_sample_rate;
+        let channels = info.max_input_channels.clamp(1, 2);
+
+        let (events_tx, events) = channel();
+        let (samples, hop_size) =
+            spawn_worker::<_, _, Consumer, _>(sample_rate as u32, factory, events_tx)
+                .map_err(PortAudioError::Analyzer)?;
+
+        let parameters =
+            StreamParameters::<f32>::new(device, channels, true, info.default_low_input_latency);
+        let settings = InputStreamSettings::new(parameters, sample_rate, hop_size as u32);
+        let frame_size = channels as usize;
+        let mut interleaved = Vec::with_capacity(hop_size * frame_size);
+        let mut mono = Vec::with_capacity(hop_size);
+
+        let callback = move |args: InputStreamCallbackArgs<f32>| {
+            let InputCallbackArgs { buffer, .. } = args;
+            let length = buffer.len() - buffer.len() % frame_size;
+            interleaved.clear();
+            interleaved.extend(buffer[..length].iter().map(|&sample| Smpl::from(sample)));
+            mono.resize(length / frame_size, 0.0);
+
+            if downmix_into(&interleaved, frame_size, &mut mono).is_ok() {
+                samples.push(&mono);
+            }
+
+            Continue
+        };
+
+        let mut stream = portaudio.open_non_blocking_stream(settings, callback)?;
+        stream.start()?;
+
+        Ok(Self {
+            stream,
+            events,
+            sample_rate: sample_rate as u32,
+            channels: frame_size,
+            _portaudio: portaudio,
+        })
+    }
+}
+
+impl<T> PortAudioCapture<T> {
+    /**
+     * Get sampling rate of input stream
+     */
+    pub fn get_samplerate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /**
+     * Get number of channels of input stream
+     */
+    pub fn get_channels(&self) -> usize {
+        self.channels
+    }
+
+    /**
+     * Get receiver of analysis events
+     */
+    pub fn events(&self) -> &Receiver<PortAudioEvent<T>> {
+        &self.events
+    }
+
+    /**
+     * Resume capture
+     */
+    pub fn play(&mut self) -> Result<(), PortAudioError> {
+        Ok(self.stream.start()?)
+    }
+
+    /**
+     * Pause capture
+     */
+    pub fn pause(&mut self) -> Result<(), PortAudioError> {
+        Ok(self.stream.stop()?)
+    }
+}
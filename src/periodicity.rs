@@ -0,0 +1,300 @@
+use crate::{vec::FVec, Error, Onset, OnsetMode, Result, Smpl, Status};
+
+use alloc::{collections::VecDeque, vec::Vec};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/**
+ * Tempo hypothesis
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TempoCandidate {
+    /// Tempo in beats per minute
+    pub bpm: Smpl,
+    /// Relative weight of hypothesis, normalized over all found candidates
+    pub weight: Smpl,
+}
+
+/**
+ * Periodicity analysis object
+ *
+ * The beat tracker of _aubio_ doesn't expose its internal state, so this object
+ * reproduces its first step: it keeps a history of onset detection function
+ * and computes its autocorrelation. The peaks of autocorrelation are reported
+ * as tempo candidates, which allows to resolve half/double-time ambiguities
+ * on the application side.
+ */
+pub struct Periodicity {
+    onset: Onset,
+    hop_size: usize,
+    sample_rate: u32,
+    history: VecDeque<Smpl>,
+    length: usize,
+    min_bpm: Smpl,
+    max_bpm: Smpl,
+}
+
+impl Periodicity {
+    /**
+     * Create periodicity analysis object
+     *
+     * - `method` Onset detection function
+     * - `buf_size` Length of FFT
+     * - `hop_size` Number of frames between two consecutive runs
+     * - `sample_rate` Sampling rate of the signal to analyze
+     */
+    pub fn new(
+        method: OnsetMode,
+        buf_size: usize,
+        hop_size: usize,
+        sample_rate: u32,
+    ) -> Result<Self> {
+        let onset = Onset::new(method, buf_size, hop_size, sample_rate)?;
+
+        let mut periodicity = Self {
+            onset,
+            hop_size,
+            sample_rate,
+            history: VecDeque::new(),
+            length: 0,
+            min_bpm: 40.0,
+            max_bpm: 250.0,
+        };
+        periodicity.set_length_s(6.0);
+
+        Ok(periodicity)
+    }
+
+    /**
+     * Set length of analyzed history, in seconds
+     */
+    pub fn with_length_s(mut self, length: Smpl) -> Self {
+        self.set_length_s(length);
+        self
+    }
+
+    /**
+     * Set range of tempo candidates, in beats per minute
+     */
+    pub fn with_bpm_range(mut self, min_bpm: Smpl, max_bpm: Smpl) -> Result<Self> {
+        self.set_bpm_range(min_bpm, max_bpm).map(|_| self)
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.hop_size
+    }
+
+    /**
+     * Set length of analyzed history, in seconds
+     *
+     * Default is `6` seconds, which is close to the window of _aubio_ beat tracker.
+     */
+    pub fn set_length_s(&mut self, length: Smpl) {
+        self.length = ((length * self.frame_rate()) as usize).max(1);
+        while self.history.len() > self.length {
+            self.history.pop_front();
+        }
+    }
+
+    /**
+     * Get length of analyzed history, in seconds
+     */
+    pub fn get_length_s(&self) -> Smpl {
+        self.length as Smpl / self.frame_rate()
+    }
+
+    /**
+     * Set range of tempo candidates, in beats per minute
+     *
+     * Default range is from `40` to `250` BPM.
+     */
+    pub fn set_bpm_range(&mut self, min_bpm: Smpl, max_bpm: Smpl) -> Status {
+        if min_bpm <= 0.0 || max_bpm <= min_bpm {
+            return Err(Error::InvalidArg);
+        }
+        self.min_bpm = min_bpm;
+        self.max_bpm = max_bpm;
+        Ok(())
+    }
+
+    /**
+     * Get range of tempo candidates, in beats per minute
+     */
+    pub fn get_bpm_range(&self) -> (Smpl, Smpl) {
+        (self.min_bpm, self.max_bpm)
+    }
+
+    /**
+     * Analyze an input signal frame
+     *
+     * - `input` Input signal of size `hop_size`
+     */
+    pub fn do_<'i, I>(&mut self, input: I) -> Status
+    where
+        I: Into<FVec<'i>>,
+    {
+        self.onset.do_result(input)?;
+
+        if self.history.len() == self.length {
+            self.history.pop_front();
+        }
+        self.history.push_back(self.onset.get_descriptor());
+
+        Ok(())
+    }
+
+    /**
+     * Get the best tempo hypotheses
+     *
+     * - `count` Maximum number of candidates
+     *
+     * The candidates are sorted by weight in descending order.
+     */
+    pub fn tempo_candidates(&self, count: usize) -> Vec<TempoCandidate> {
+        let history = self.history.iter().copied().collect::<Vec<_>>();
+        tempo_candidates(
+            &history,
+            self.frame_rate(),
+            self.min_bpm,
+            self.max_bpm,
+            count,
+        )
+    }
+
+    /**
+     * Clear the history
+     */
+    pub fn reset(&mut self) {
+        self.history.clear();
+        self.onset.reset();
+    }
+
+    fn frame_rate(&self) -> Smpl {
+        self.sample_rate as Smpl / self.hop_size as Smpl
+    }
+}
+
+/**
+ * Find peaks of autocorrelation of detection function in range of tempo
+ *
+ * - `odf` Onset detection function
+ * - `frame_rate` Number of detection function values per second
+ */
+fn tempo_candidates(
+    odf: &[Smpl],
+    frame_rate: Smpl,
+    min_bpm: Smpl,
+    max_bpm: Smpl,
+    count: usize,
+) -> Vec<TempoCandidate> {
+    let min_lag = ((60.0 * frame_rate / max_bpm).floor() as usize).max(1);
+    let max_lag = (60.0 * frame_rate / min_bpm).ceil() as usize;
+
+    if odf.len() < 3 || min_lag + 1 >= odf.len() {
+        return Vec::new();
+    }
+
+    let max_lag = max_lag.min(odf.len() - 2);
+    let mean = odf.iter().sum::<Smpl>() / odf.len() as Smpl;
+
+    // biased autocorrelation of the signal without DC, including one lag on each side
+    let acf = (min_lag - 1..=max_lag + 1)
+        .map(|lag| {
+            odf.iter()
+                .zip(&odf[lag..])
+                .map(|(a, b)| (a - mean) * (b - mean))
+                .sum::<Smpl>()
+                / odf.len() as Smpl
+        })
+        .collect::<Vec<_>>();
+
+    let mut candidates = acf
+        .windows(3)
+        .enumerate()
+        .filter(|(index, values)| {
+            // the interpolated peak may slightly exceed the range at its bounds
+            let bpm = 60.0 * frame_rate / (min_lag + index) as Smpl;
+
+            bpm >= min_bpm
+                && bpm <= max_bpm
+                && values[1] > 0.0
+                && values[1] > values[0]
+                && values[1] >= values[2]
+        })
+        .map(|(index, values)| {
+            // parabolic interpolation of peak position
+            let curvature = values[0] - 2.0 * values[1] + values[2];
+            let offset = if curvature < 0.0 {
+                0.5 * (values[0] - values[2]) / curvature
+            } else {
+                0.0
+            };
+            let lag = (min_lag + index) as Smpl + offset;
+
+            TempoCandidate {
+                bpm: 60.0 * frame_rate / lag,
+                weight: values[1],
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let total = candidates
+        .iter()
+        .map(|candidate| candidate.weight)
+        .sum::<Smpl>();
+
+    for candidate in &mut candidates {
+        candidate.weight /= total;
+    }
+
+    candidates.sort_by(|a, b| b.weight.partial_cmp(&a.weight).unwrap());
+    candidates.truncate(count);
+    candidates
+}
+
+#[cfg(test)]
+mod test {
+    use super::tempo_candidates;
+    use crate::*;
+
+    #[test]
+    fn test_tempo_candidates() {
+        // pulses each 0.5 s at the rate of 40 frames per second
+        let odf = (0..400)
+            .map(|index| if index % 20 == 0 { 1.0 } else { 0.0 })
+            .collect::<Vec<_>>();
+
+        let candidates = tempo_candidates(&odf, 40.0, 40.0, 250.0, 3);
+
+        assert_eq!(candidates.len(), 3);
+        assert!((candidates[0].bpm - 120.0).abs() < 0.5);
+        assert!((candidates[1].bpm - 60.0).abs() < 0.5);
+        assert!((candidates[2].bpm - 40.0).abs() < 0.5);
+        assert!(candidates[0].weight > candidates[1].weight);
+        assert_eq!(tempo_candidates(&odf, 40.0, 40.0, 250.0, 1).len(), 1);
+
+        let total = candidates.iter().map(|c| c.weight).sum::<Smpl>();
+        assert!((total - 1.0).abs() < 1e-4);
+
+        assert!(tempo_candidates(&odf[..2], 40.0, 40.0, 250.0, 3).is_empty());
+    }
+
+    #[test]
+    fn test_periodicity() {
+        let mut periodicity = Periodicity::new(OnsetMode::default(), 1024, 512, 44100)
+            .unwrap()
+            .with_length_s(4.0);
+
+        assert!(periodicity.set_bpm_range(100.0, 50.0).is_err());
+        assert!(periodicity.tempo_candidates(3).is_empty());
+
+        periodicity.do_([0.0; 512].as_ref()).unwrap();
+        assert!(periodicity.do_([0.0; 256].as_ref()).is_err());
+    }
+}
@@ -0,0 +1,438 @@
+use crate::{
+    vec::FMatBuf, AsNativeStr, Error, Filter, PVoc, Pitch, PitchMode, PitchUnit, ResampleMode,
+    Result, Smpl, SpecDesc, SpecMethod, Status, StreamingResampler, WindowType, MFCC,
+};
+
+use alloc::{format, string::String, vec, vec::Vec};
+
+/**
+ * Spectral description method stored by name
+ */
+#[derive(Debug, Clone, Copy)]
+struct NativeMethod(&'static str);
+
+impl AsNativeStr for NativeMethod {
+    fn as_native_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl SpecMethod for NativeMethod {}
+
+/**
+ * Preprocessing filter stage
+ */
+#[derive(Debug, Clone, Copy)]
+enum PreFilter {
+    DcRemoval(Smpl),
+    PreEmphasis(Smpl),
+}
+
+/**
+ * Feature extraction pipeline builder
+ *
+ * Declares the stages of pipeline:
+ *
+ * 1. optional resampling of input signal,
+ * 2. optional preprocessing filters (DC removal, pre-emphasis),
+ * 3. phase vocoder with given window and hop sizes,
+ * 4. the set of features computed for each hop: MFCC, spectral descriptors and pitch.
+ */
+#[derive(Debug, Clone)]
+pub struct PipelineBuilder {
+    sample_rate: u32,
+    win_size: usize,
+    hop_size: usize,
+    window: Option<WindowType>,
+    resample: Option<(u32, ResampleMode)>,
+    filters: Vec<PreFilter>,
+    mfcc: Option<(usize, usize)>,
+    specdesc: Vec<NativeMethod>,
+    pitch: Option<(PitchMode, PitchUnit)>,
+}
+
+impl PipelineBuilder {
+    /**
+     * Create pipeline builder
+     *
+     * - `sample_rate` Sampling rate of the input signal
+     *
+     * The default window size is `1024` and hop size is `512`.
+     */
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate,
+            win_size: 1024,
+            hop_size: 512,
+            window: None,
+            resample: None,
+            filters: Vec::new(),
+            mfcc: None,
+            specdesc: Vec::new(),
+            pitch: None,
+        }
+    }
+
+    /**
+     * Resample input signal before analysis
+     *
+     * - `sample_rate` Sampling rate of the analyzed signal
+     * - `mode` Resampling method
+     */
+    pub fn with_resample(mut self, sample_rate: u32, mode: ResampleMode) -> Self {
+        self.resample = Some((sample_rate, mode));
+        self
+    }
+
+    /**
+     * Remove DC offset and low-frequency rumble before analysis
+     *
+     * - `cutoff` Cutoff frequency in Hz (see `Filter::new_dc_blocker()`)
+     */
+    pub fn with_dc_removal(mut self, cutoff: Smpl) -> Self {
+        self.filters.push(PreFilter::DcRemoval(cutoff));
+        self
+    }
+
+    /**
+     * Apply pre-emphasis filter before analysis
+     *
+     * - `coeff` Emphasis coefficient (see `Filter::new_pre_emphasis()`)
+     */
+    pub fn with_pre_emphasis(mut self, coeff: Smpl) -> Self {
+        self.filters.push(PreFilter::PreEmphasis(coeff));
+        self
+    }
+
+    /**
+     * Set phase vocoder parameters
+     *
+     * - `win_size` Size of analysis window (and length of the FFT transform)
+     * - `hop_size` Step size between two consecutive frames
+     */
+    pub fn with_pvoc(mut self, win_size: usize, hop_size: usize) -> Self {
+        self.win_size = win_size;
+        self.hop_size = hop_size;
+        self
+    }
+
+    /**
+     * Select window type of phase vocoder
+     */
+    pub fn with_window(mut self, window_type: WindowType) -> Self {
+        self.window = Some(window_type);
+        self
+    }
+
+    /**
+     * Add MFCC features
+     *
+     * - `n_filters` Number of desired filters
+     * - `n_coeffs` Number of desired coefficients
+     */
+    pub fn with_mfcc(mut self, n_filters: usize, n_coeffs: usize) -> Self {
+        self.mfcc = Some((n_filters, n_coeffs));
+        self
+    }
+
+    /**
+     * Add spectral description feature
+     *
+     * Can be called several times to add several descriptors.
+     */
+    pub fn with_specdesc(mut self, method: impl SpecMethod) -> Self {
+        self.specdesc.push(NativeMethod(method.as_native_str()));
+        self
+    }
+
+    /**
+     * Add pitch and pitch confidence features
+     */
+    pub fn with_pitch(mut self, mode: PitchMode, unit: PitchUnit) -> Self {
+        self.pitch = Some((mode, unit));
+        self
+    }
+
+    /**
+     * Create pipeline
+     */
+    pub fn build(&self) -> Result<Pipeline> {
+        if self.hop_size == 0 || self.hop_size > self.win_size {
+            return Err(Error::InvalidArg);
+        }
+
+        let (resampler, sample_rate) = match self.resample {
+            Some((sample_rate, mode)) => {
+                let ratio = sample_rate as Smpl / self.sample_rate as Smpl;
+                (Some(StreamingResampler::new(ratio, mode)?), sample_rate)
+            }
+            None => (None, self.sample_rate),
+        };
+
+        let filters = self
+            .filters
+            .iter()
+            .map(|filter| match *filter {
+                PreFilter::DcRemoval(cutoff) => Filter::new_dc_blocker(cutoff, sample_rate),
+                PreFilter::PreEmphasis(coeff) => Filter::new_pre_emphasis(coeff),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut pvoc = PVoc::new(self.win_size, self.hop_size)?;
+        if let Some(window) = self.window {
+            pvoc.set_window(window)?;
+        }
+
+        let mut names = Vec::new();
+
+        let mfcc = match self.mfcc {
+            Some((n_filters, n_coeffs)) => {
+                names.extend((0..n_coeffs).map(|index| format!("mfcc{}", index)));
+                Some((
+                    MFCC::new(self.win_size, n_filters, n_coeffs, sample_rate)?,
+                    n_coeffs,
+                ))
+            }
+            None => None,
+        };
+
+        let specdesc = self
+            .specdesc
+            .iter()
+            .map(|&method| {
+                names.push(method.as_rust_str().into());
+                SpecDesc::new(method, self.win_size)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let pitch = match self.pitch {
+            Some((mode, unit)) => {
+                names.push("pitch".into());
+                names.push("pitch_confidence".into());
+                Some(Pitch::new(mode, self.win_size, self.hop_size, sample_rate)?.with_unit(unit))
+            }
+            None => None,
+        };
+
+        Ok(Pipeline {
+            resampler,
+            filters,
+            pvoc,
+            mfcc,
+            specdesc,
+            pitch,
+            grain: vec![0.; self.win_size + 2],
+            buffer: vec![0.; self.hop_size],
+            filled: 0,
+            resampled: Vec::new(),
+            features: vec![0.; names.len()],
+            names,
+        })
+    }
+}
+
+/**
+ * Feature extraction pipeline
+ *
+ * Produces the feature vector for each hop of analyzed signal.
+ * The layout of feature vector is described by `Pipeline::get_names()`.
+ */
+pub struct Pipeline {
+    resampler: Option<StreamingResampler>,
+    filters: Vec<Filter>,
+    pvoc: PVoc,
+    mfcc: Option<(MFCC, usize)>,
+    specdesc: Vec<SpecDesc>,
+    pitch: Option<Pitch>,
+    grain: Vec<Smpl>,
+    buffer: Vec<Smpl>,
+    filled: usize,
+    resampled: Vec<Smpl>,
+    features: Vec<Smpl>,
+    names: Vec<String>,
+}
+
+impl Pipeline {
+    /**
+     * Get hop size of analyzed signal
+     */
+    pub fn get_hop(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /**
+     * Get number of features in each vector
+     */
+    pub fn get_size(&self) -> usize {
+        self.features.len()
+    }
+
+    /**
+     * Get names of features in order
+     */
+    pub fn get_names(&self) -> &[String] {
+        &self.names
+    }
+
+    /**
+     * Push input samples and pass feature vectors to callback
+     *
+     * The input can have any length. The incomplete tail of input
+     * is kept until the next push.
+     */
+    pub fn push_with<F>(&mut self, input: &[Smpl], mut func: F) -> Status
+    where
+        F: FnMut(&[Smpl]),
+    {
+        let mut resampled = core::mem::take(&mut self.resampled);
+        resampled.clear();
+
+        let result = match &mut self.resampler {
+            Some(resampler) => resampler
+                .push_to(input, &mut resampled)
+                .and_then(|_| self.push_frames(&resampled, &mut func)),
+            None => self.push_frames(input, &mut func),
+        };
+
+        self.resampled = resampled;
+        result
+    }
+
+    /**
+     * Process buffered samples padded by zeros and pass feature vectors to callback
+     */
+    pub fn flush_with<F>(&mut self, mut func: F) -> Status
+    where
+        F: FnMut(&[Smpl]),
+    {
+        if let Some(resampler) = &mut self.resampler {
+            let resampled = resampler.flush()?;
+            self.push_frames(&resampled, &mut func)?;
+        }
+
+        if self.filled > 0 {
+            for sample in &mut self.buffer[self.filled..] {
+                *sample = 0.;
+            }
+            self.filled = 0;
+            self.analyze()?;
+            func(&self.features);
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Extract features of the whole signal
+     *
+     * Returns matrix which has a row of features for each hop.
+     */
+    pub fn process(&mut self, samples: &[Smpl]) -> Result<FMatBuf> {
+        let mut data = Vec::new();
+        let mut frames = 0;
+
+        let mut collect = |features: &[Smpl]| {
+            data.extend_from_slice(features);
+            frames += 1;
+        };
+
+        self.push_with(samples, &mut collect)?;
+        self.flush_with(&mut collect)?;
+
+        FMatBuf::from_vec(frames, self.get_size(), data)
+    }
+
+    fn push_frames<F>(&mut self, mut input: &[Smpl], func: &mut F) -> Status
+    where
+        F: FnMut(&[Smpl]),
+    {
+        let hop_size = self.buffer.len();
+
+        while !input.is_empty() {
+            let count = input.len().min(hop_size - self.filled);
+            self.buffer[self.filled..self.filled + count].copy_from_slice(&input[..count]);
+            self.filled += count;
+            input = &input[count..];
+
+            if self.filled == hop_size {
+                self.filled = 0;
+                self.analyze()?;
+                func(&self.features);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn analyze(&mut self) -> Status {
+        let mut offset = 0;
+
+        for filter in &mut self.filters {
+            filter.do_(self.buffer.as_mut_slice());
+        }
+
+        self.pvoc
+            .do_(self.buffer.as_slice(), self.grain.as_mut_slice())?;
+
+        if let Some((mfcc, n_coeffs)) = &mut self.mfcc {
+            let output = &mut self.features[offset..offset + *n_coeffs];
+            mfcc.do_(self.grain.as_slice(), output)?;
+            offset += *n_coeffs;
+        }
+
+        for specdesc in &mut self.specdesc {
+            self.features[offset] = specdesc.do_result(self.grain.as_slice())?;
+            offset += 1;
+        }
+
+        if let Some(pitch) = &mut self.pitch {
+            self.features[offset] = pitch.do_result(self.buffer.as_slice())?;
+            self.features[offset + 1] = pitch.get_confidence();
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_pipeline() {
+        let mut pipeline = PipelineBuilder::new(44100)
+            .with_resample(22050, ResampleMode::Linear)
+            .with_dc_removal(20.0)
+            .with_pre_emphasis(0.97)
+            .with_pvoc(512, 256)
+            .with_window(WindowType::Hanning)
+            .with_mfcc(40, 13)
+            .with_specdesc(SpecShape::Centroid)
+            .with_specdesc(OnsetMode::Hfc)
+            .with_pitch(PitchMode::Yinfft, PitchUnit::Hz)
+            .build()
+            .unwrap();
+
+        assert_eq!(pipeline.get_size(), 17);
+        assert_eq!(pipeline.get_names()[13], "centroid");
+        assert_eq!(pipeline.get_names()[14], "hfc");
+        assert_eq!(pipeline.get_names()[16], "pitch_confidence");
+
+        let samples = vec![0.0; 44100];
+        let features = pipeline.process(&samples).unwrap();
+
+        assert_eq!(features.length(), 17);
+        // 22050 samples at hop of 256
+        assert_eq!(features.height(), 87);
+
+        assert!(PipelineBuilder::new(44100)
+            .with_pre_emphasis(1.5)
+            .build()
+            .is_err());
+
+        assert!(PipelineBuilder::new(44100)
+            .with_pvoc(256, 512)
+            .build()
+            .is_err());
+    }
+}
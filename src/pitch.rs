@@ -1,11 +1,12 @@
 use crate::{
-    check_init, ffi,
-    vec::{FVec, FVecMut},
+    check_params, ffi, try_init,
+    vec::{FVec, FVecMut, Memory},
     AsNativeStr, Error, Result, Smpl, Status,
 };
 
-use std::{
+use core::{
     fmt::{Display, Formatter, Result as FmtResult},
+    ops::RangeInclusive,
     str::FromStr,
 };
 
@@ -13,6 +14,8 @@ use std::{
  * Pitch detection method
  */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum PitchMode {
     /**
      * Schmitt trigger
@@ -134,10 +137,13 @@ impl FromStr for PitchMode {
  * Pitch output unit
  */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum PitchUnit {
     /**
      * Hertz
      */
+    #[cfg_attr(feature = "serde", serde(rename = "hertz"))]
     Hz,
 
     /**
@@ -208,7 +214,12 @@ impl FromStr for PitchUnit {
  */
 pub struct Pitch {
     pitch: *mut ffi::aubio_pitch_t,
+    method: PitchMode,
+    buf_size: usize,
     hop_size: usize,
+    sample_rate: u32,
+    unit: PitchUnit,
+    min_confidence: Smpl,
 }
 
 impl Drop for Pitch {
@@ -217,6 +228,9 @@ impl Drop for Pitch {
     }
 }
 
+unsafe impl Send for Pitch {}
+unsafe impl Sync for Pitch {}
+
 impl Pitch {
     /**
      * Creation of the pitch detection object
@@ -232,18 +246,26 @@ impl Pitch {
         hop_size: usize,
         sample_rate: u32,
     ) -> Result<Self> {
-        let pitch = unsafe {
+        check_params(buf_size, hop_size, sample_rate)?;
+
+        let pitch = try_init(|| unsafe {
             ffi::new_aubio_pitch(
                 method.as_native_cstr(),
                 buf_size as ffi::uint_t,
                 hop_size as ffi::uint_t,
                 sample_rate as ffi::uint_t,
             )
-        };
-
-        check_init(pitch)?;
-
-        Ok(Self { pitch, hop_size })
+        })?;
+
+        Ok(Self {
+            pitch,
+            method,
+            buf_size,
+            hop_size,
+            sample_rate,
+            unit: PitchUnit::default(),
+            min_confidence: 0.0,
+        })
     }
 
     /**
@@ -270,6 +292,14 @@ impl Pitch {
         self
     }
 
+    /**
+     * Set minimum confidence of detected pitch
+     */
+    pub fn with_min_confidence(mut self, min_confidence: Smpl) -> Self {
+        self.set_min_confidence(min_confidence);
+        self
+    }
+
     /**
      * Get hop size
      */
@@ -277,11 +307,28 @@ impl Pitch {
         self.hop_size
     }
 
+    /**
+     * Get range of accepted input sizes
+     *
+     * The `Mcomb` method passes the input to phase vocoder, so it requires
+     * exactly `hop_size` samples. Other methods slide the analysis buffer
+     * by the length of input, so it can have from one to `buf_size` samples.
+     */
+    pub fn get_input_size(&self) -> RangeInclusive<usize> {
+        match self.method {
+            PitchMode::Mcomb => self.hop_size..=self.hop_size,
+            _ => 1..=self.buf_size,
+        }
+    }
+
     /**
      * Execute pitch detection on an input signal frame
      *
-     * - `input` Input signal of size `hop_size`
+     * - `input` Input signal of size `hop_size` (see `Pitch::get_input_size()`)
      * - `output` Output pitch candidates of size 1
+     *
+     * The pitch is set to `0` when its confidence is below minimum
+     * (see `Pitch::set_min_confidence()`).
      */
     pub fn do_<'i, 'o, I, O>(&mut self, input: I, output: O) -> Status
     where
@@ -291,19 +338,41 @@ impl Pitch {
         let input = input.into();
         let mut output = output.into();
 
-        input.check_size(self.get_hop())?;
+        #[cfg(not(feature = "unchecked-size"))]
+        {
+            let range = self.get_input_size();
+            if !range.contains(&input.size()) {
+                return Err(Error::InvalidSize {
+                    name: "input",
+                    size: input.size(),
+                    min: *range.start(),
+                    max: *range.end(),
+                });
+            }
+        }
         output.check_size(1)?;
+        input.check_overlap(&output)?;
+
+        let mut pitch = [0.; 1];
+        {
+            let mut pitch = FVecMut::from(pitch.as_mut());
+            unsafe {
+                ffi::aubio_pitch_do(self.pitch, input.as_ptr(), pitch.as_mut_ptr());
+            }
+        }
 
-        unsafe {
-            ffi::aubio_pitch_do(self.pitch, input.as_ptr(), output.as_mut_ptr());
+        if self.min_confidence > 0.0 && self.get_confidence() < self.min_confidence {
+            pitch[0] = 0.0;
         }
+
+        output.as_mut_slice()[..1].copy_from_slice(&pitch);
         Ok(())
     }
 
     /**
      * Execute pitch detection on an input signal frame
      *
-     * - `input` Input signal of size `hop_size`
+     * - `input` Input signal of size `hop_size` (see `Pitch::get_input_size()`)
      */
     pub fn do_result<'i, I>(&mut self, input: I) -> Result<Smpl>
     where
@@ -353,6 +422,31 @@ impl Pitch {
         unsafe {
             ffi::aubio_pitch_set_unit(self.pitch, unit.as_native_cstr());
         }
+        self.unit = unit;
+    }
+
+    /**
+     * Get the output unit of the pitch detection object
+     */
+    pub fn get_unit(&self) -> PitchUnit {
+        self.unit
+    }
+
+    /**
+     * Set minimum confidence of detected pitch
+     *
+     * The pitch with lower confidence is reported as `0`.
+     * Default is `0` which means no gating.
+     */
+    pub fn set_min_confidence(&mut self, min_confidence: Smpl) {
+        self.min_confidence = min_confidence;
+    }
+
+    /**
+     * Get minimum confidence of detected pitch
+     */
+    pub fn get_min_confidence(&self) -> Smpl {
+        self.min_confidence
     }
 
     /**
@@ -361,4 +455,106 @@ impl Pitch {
     pub fn get_confidence(&self) -> Smpl {
         unsafe { ffi::aubio_pitch_get_confidence(self.pitch) }
     }
+
+    /**
+     * Create new pitch detection object with the same parameters
+     *
+     * The state of analysis is not copied.
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        Ok(
+            Self::new(self.method, self.buf_size, self.hop_size, self.sample_rate)?
+                .with_tolerance(self.get_tolerance())
+                .with_silence(self.get_silence())
+                .with_unit(self.unit)
+                .with_min_confidence(self.min_confidence),
+        )
+    }
+
+    /**
+     * Reset pitch detection
+     *
+     * The library can't reset the pitch detector, so the new one is created
+     * with the same parameters.
+     */
+    pub fn reset(&mut self) -> Status {
+        *self = self.try_clone()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_min_confidence() {
+        let mut pitch = Pitch::new(PitchMode::Yinfft, 2048, 512, 44100)
+            .unwrap()
+            .with_min_confidence(0.9);
+
+        assert_eq!(pitch.get_min_confidence(), 0.9);
+
+        let noise = (0..512)
+            .map(|index| ((index * 7919 % 1000) as Smpl / 500.0) - 1.0)
+            .collect::<Vec<_>>();
+
+        for _ in 0..8 {
+            let freq = pitch.do_result(noise.as_slice()).unwrap();
+            assert!(freq == 0.0 || pitch.get_confidence() >= 0.9);
+        }
+    }
+
+    #[test]
+    fn test_min_confidence_pass() {
+        let sample_rate = 44100;
+        let hop_size = 512;
+
+        let mut pitch = Pitch::new(PitchMode::Yinfft, 2048, hop_size, sample_rate)
+            .unwrap()
+            .with_unit(PitchUnit::Hz)
+            .with_min_confidence(0.5);
+
+        let samples = (0..hop_size * 16)
+            .map(|index| {
+                let phase = 2.0 * std::f64::consts::PI as Smpl * 440.0 * index as Smpl;
+                (phase / sample_rate as Smpl).sin() * 0.5
+            })
+            .collect::<Vec<_>>();
+
+        let mut freq = 0.0;
+        for frame in samples.chunks_exact(hop_size) {
+            freq = pitch.do_result(frame).unwrap();
+        }
+
+        assert!(pitch.get_confidence() >= 0.5);
+        assert!((freq - 440.0).abs() < 5.0);
+    }
+
+    #[cfg(not(feature = "unchecked-size"))]
+    #[test]
+    fn test_input_size() {
+        let mut pitch = Pitch::new(PitchMode::Yin, 2048, 512, 44100).unwrap();
+
+        assert_eq!(pitch.get_input_size(), 1..=2048);
+        assert!(pitch.do_result(&[0.0; 256][..]).is_ok());
+        assert_eq!(
+            pitch.do_result(&[0.0; 4096][..]),
+            Err(Error::InvalidSize {
+                name: "input",
+                size: 4096,
+                min: 1,
+                max: 2048,
+            })
+        );
+
+        let mut pitch = Pitch::new(PitchMode::Mcomb, 2048, 512, 44100).unwrap();
+
+        assert_eq!(pitch.get_input_size(), 512..=512);
+        assert!(pitch.do_result(&[0.0; 512][..]).is_ok());
+        assert_eq!(
+            pitch.do_result(&[0.0; 1024][..]).unwrap_err().to_string(),
+            "invalid size of input: 1024 (expected 512)"
+        );
+    }
 }
@@ -0,0 +1,326 @@
+use crate::{utils::median, PitchUnit, Smpl};
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/**
+ * Pitch track post-processor
+ *
+ * This object cleans the raw sequence of per-hop pitch estimates produced
+ * by `Pitch` using the following steps:
+ *
+ * 1. Confidence gating: the frames with confidence below threshold are
+ *    marked as unvoiced (pitch `0`).
+ * 2. Octave-jump correction: short runs of frames which differ from
+ *    the preceding pitch by whole octaves are moved back to its octave.
+ * 3. Median smoothing of the voiced frames.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PitchTrack {
+    min_confidence: Smpl,
+    median_size: usize,
+    octave_frames: usize,
+    unit: PitchUnit,
+}
+
+impl Default for PitchTrack {
+    fn default() -> Self {
+        Self {
+            min_confidence: 0.8,
+            median_size: 5,
+            octave_frames: 5,
+            unit: PitchUnit::Hz,
+        }
+    }
+}
+
+/// Maximum deviation from whole number of octaves to treat the jump as octave error
+const OCTAVE_TOLERANCE: Smpl = 0.05;
+
+impl PitchTrack {
+    /**
+     * Create pitch track post-processor with default parameters
+     */
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+     * Set minimum confidence of voiced frames
+     */
+    pub fn with_min_confidence(mut self, min_confidence: Smpl) -> Self {
+        self.set_min_confidence(min_confidence);
+        self
+    }
+
+    /**
+     * Set size of median filter in frames
+     */
+    pub fn with_median_size(mut self, median_size: usize) -> Self {
+        self.set_median_size(median_size);
+        self
+    }
+
+    /**
+     * Set maximum length of octave jumps to correct in frames
+     */
+    pub fn with_octave_frames(mut self, octave_frames: usize) -> Self {
+        self.set_octave_frames(octave_frames);
+        self
+    }
+
+    /**
+     * Set unit of pitch values
+     */
+    pub fn with_unit(mut self, unit: PitchUnit) -> Self {
+        self.set_unit(unit);
+        self
+    }
+
+    /**
+     * Set minimum confidence of voiced frames
+     *
+     * Default is `0.8`.
+     */
+    pub fn set_min_confidence(&mut self, min_confidence: Smpl) {
+        self.min_confidence = min_confidence;
+    }
+
+    /**
+     * Get minimum confidence of voiced frames
+     */
+    pub fn get_min_confidence(&self) -> Smpl {
+        self.min_confidence
+    }
+
+    /**
+     * Set size of median filter in frames
+     *
+     * Default is `5`. The values `0` and `1` disable smoothing.
+     */
+    pub fn set_median_size(&mut self, median_size: usize) {
+        self.median_size = median_size;
+    }
+
+    /**
+     * Get size of median filter in frames
+     */
+    pub fn get_median_size(&self) -> usize {
+        self.median_size
+    }
+
+    /**
+     * Set maximum length of octave jumps to correct in frames
+     *
+     * The jumps which last longer are treated as real pitch changes.
+     * Default is `5`. The value `0` disables octave correction.
+     */
+    pub fn set_octave_frames(&mut self, octave_frames: usize) {
+        self.octave_frames = octave_frames;
+    }
+
+    /**
+     * Get maximum length of octave jumps to correct in frames
+     */
+    pub fn get_octave_frames(&self) -> usize {
+        self.octave_frames
+    }
+
+    /**
+     * Set unit of pitch values
+     *
+     * The unit should be the same as configured for `Pitch` object.
+     * Default is `PitchUnit::Hz`.
+     */
+    pub fn set_unit(&mut self, unit: PitchUnit) {
+        self.unit = unit;
+    }
+
+    /**
+     * Get unit of pitch values
+     */
+    pub fn get_unit(&self) -> PitchUnit {
+        self.unit
+    }
+
+    /**
+     * Process pitch track
+     *
+     * - `track` Pairs of pitch and confidence for each hop
+     *
+     * Returns cleaned pitch contour where unvoiced frames have value `0`.
+     */
+    pub fn process(&self, track: &[(Smpl, Smpl)]) -> Vec<Smpl> {
+        let mut pitches = track
+            .iter()
+            .map(|&(pitch, confidence)| {
+                if pitch > 0.0 && confidence >= self.min_confidence {
+                    pitch
+                } else {
+                    0.0
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if self.octave_frames > 0 {
+            self.correct_octaves(&mut pitches);
+        }
+
+        if self.median_size > 1 {
+            pitches = self.smooth(&pitches);
+        }
+
+        pitches
+    }
+
+    fn octaves(&self, pitch: Smpl, reference: Smpl) -> Smpl {
+        match self.unit {
+            PitchUnit::Hz | PitchUnit::Bin => (pitch / reference).log2(),
+            PitchUnit::Midi => (pitch - reference) / 12.0,
+            PitchUnit::Cent => (pitch - reference) / 1200.0,
+        }
+    }
+
+    fn shift_octaves(&self, pitch: Smpl, octaves: Smpl) -> Smpl {
+        match self.unit {
+            PitchUnit::Hz | PitchUnit::Bin => pitch * octaves.exp2(),
+            PitchUnit::Midi => pitch + octaves * 12.0,
+            PitchUnit::Cent => pitch + octaves * 1200.0,
+        }
+    }
+
+    /// Get whole number of octaves between pitches or zero when the interval isn't octave
+    fn octave_jump(&self, pitch: Smpl, reference: Smpl) -> Smpl {
+        let octaves = self.octaves(pitch, reference);
+        let whole = octaves.round();
+
+        if (octaves - whole).abs() < OCTAVE_TOLERANCE {
+            whole
+        } else {
+            0.0
+        }
+    }
+
+    fn correct_octaves(&self, pitches: &mut [Smpl]) {
+        let mut reference: Option<Smpl> = None;
+        let mut index = 0;
+
+        while index < pitches.len() {
+            let pitch = pitches[index];
+
+            if pitch <= 0.0 {
+                index += 1;
+                continue;
+            }
+
+            let base = match reference {
+                Some(base) => base,
+                None => {
+                    reference = Some(pitch);
+                    index += 1;
+                    continue;
+                }
+            };
+
+            let jump = self.octave_jump(pitch, base);
+
+            if jump == 0.0 {
+                reference = Some(pitch);
+                index += 1;
+                continue;
+            }
+
+            let run = pitches[index..]
+                .iter()
+                .take_while(|&&pitch| pitch > 0.0 && self.octave_jump(pitch, base) == jump)
+                .count();
+
+            if run <= self.octave_frames {
+                for pitch in &mut pitches[index..index + run] {
+                    *pitch = self.shift_octaves(*pitch, -jump);
+                }
+            }
+
+            reference = Some(pitches[index + run - 1]);
+            index += run;
+        }
+    }
+
+    fn smooth(&self, pitches: &[Smpl]) -> Vec<Smpl> {
+        let half = self.median_size / 2;
+        let mut window = Vec::with_capacity(self.median_size);
+
+        pitches
+            .iter()
+            .enumerate()
+            .map(|(index, &pitch)| {
+                if pitch <= 0.0 {
+                    return 0.0;
+                }
+
+                let start = index.saturating_sub(half);
+                let end = (index + half + 1).min(pitches.len());
+
+                window.clear();
+                window.extend(pitches[start..end].iter().filter(|&&pitch| pitch > 0.0));
+
+                median(&mut window).unwrap_or(pitch)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_confidence_gating() {
+        let track = PitchTrack::new().with_median_size(1).with_octave_frames(0);
+
+        assert_eq!(
+            track.process(&[(440.0, 0.9), (440.0, 0.2), (0.0, 0.9), (220.0, 0.95)]),
+            vec![440.0, 0.0, 0.0, 220.0]
+        );
+    }
+
+    #[test]
+    fn test_octave_correction() {
+        let track = PitchTrack::new().with_median_size(1).with_octave_frames(2);
+
+        let input = [440.0, 440.0, 880.0, 440.0, 440.0, 220.0, 220.0, 220.0]
+            .iter()
+            .map(|&pitch| (pitch, 1.0))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            track.process(&input),
+            vec![440.0, 440.0, 440.0, 440.0, 440.0, 220.0, 220.0, 220.0]
+        );
+
+        let track = track.with_unit(PitchUnit::Midi);
+        assert_eq!(
+            track.process(&[(69.0, 1.0), (57.0, 1.0), (69.0, 1.0)]),
+            vec![69.0, 69.0, 69.0]
+        );
+    }
+
+    #[test]
+    fn test_median_smoothing() {
+        let track = PitchTrack::new().with_median_size(3).with_octave_frames(0);
+
+        assert_eq!(
+            track.process(&[
+                (440.0, 1.0),
+                (450.0, 1.0),
+                (440.0, 1.0),
+                (0.0, 0.0),
+                (300.0, 1.0)
+            ]),
+            vec![445.0, 440.0, 445.0, 0.0, 300.0]
+        );
+    }
+}
@@ -0,0 +1,382 @@
+/*!
+ * Visualization of analysis results
+ *
+ * Renders the onset detection function, spectrogram, beats and pitch contour
+ * using _plotters_. The plots can be drawn into any drawing area (to compose
+ * several plots in single image) or saved to PNG or SVG files directly.
+ *
+ * Such plots is the fastest way to see why the detection does not work
+ * as expected and to choose the thresholds.
+
+```no_run
+use aubio::{plot::Plot, Spectrogram};
+
+# let samples = vec![0.0; 44100];
+let mut spectrogram = Spectrogram::new(1024, 512).unwrap().with_db(true);
+spectrogram.push(&samples).unwrap();
+let matrix = spectrogram.into_matrix().unwrap();
+
+Plot::Spectrogram { matrix: &matrix, hop_size: 512, sample_rate: 44100 }
+    .save("spectrogram.png", (1024, 512), "Spectrogram")
+    .unwrap();
+```
+ */
+
+use crate::{
+    offline::{BeatEvent, OnsetEvent},
+    vec::FMatBuf,
+    Smpl,
+};
+
+use plotters::{
+    coord::Shift,
+    prelude::{
+        BitMapBackend, ChartBuilder, DrawingArea, DrawingAreaErrorKind, DrawingBackend, HSLColor,
+        IntoDrawingArea, LineSeries, PathElement, Rectangle, SVGBackend, BLUE, RED, WHITE,
+    },
+    style::Color,
+};
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    path::Path,
+};
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/**
+ * Plotting error
+ */
+#[derive(Debug, Clone)]
+pub struct PlotError(String);
+
+impl StdError for PlotError {}
+
+impl Display for PlotError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "plotting error: {}", self.0)
+    }
+}
+
+impl<E: StdError + Send + Sync> From<DrawingAreaErrorKind<E>> for PlotError {
+    fn from(error: DrawingAreaErrorKind<E>) -> Self {
+        PlotError(error.to_string())
+    }
+}
+
+/**
+ * Plot of analysis results
+ */
+#[derive(Clone, Copy)]
+pub enum Plot<'a> {
+    /**
+     * Onset detection function with optional threshold and detected onsets
+     */
+    Detection {
+        /// Values of detection function per hop
+        values: &'a [Smpl],
+        /// Number of samples between values
+        hop_size: usize,
+        /// Sampling rate of signal
+        sample_rate: u32,
+        /// Threshold of peak picking
+        threshold: Option<Smpl>,
+        /// Detected onsets
+        onsets: &'a [OnsetEvent],
+    },
+
+    /**
+     * Spectrogram with frame per row (see `Spectrogram::into_matrix()`)
+     */
+    Spectrogram {
+        /// Magnitudes of bins
+        matrix: &'a FMatBuf,
+        /// Number of samples between frames
+        hop_size: usize,
+        /// Sampling rate of signal
+        sample_rate: u32,
+    },
+
+    /**
+     * Markers of beats with heights of its confidence
+     */
+    Beats {
+        /// Detected beats
+        beats: &'a [BeatEvent],
+        /// Duration of signal in seconds
+        duration: Smpl,
+    },
+
+    /**
+     * Pitch contour, the values less or equal to zero (unvoiced) are not drawn
+     */
+    Pitch {
+        /// Pitch values per hop
+        pitches: &'a [Smpl],
+        /// Number of samples between values
+        hop_size: usize,
+        /// Sampling rate of signal
+        sample_rate: u32,
+    },
+}
+
+impl<'a> Plot<'a> {
+    /**
+     * Save plot to file
+     *
+     * The SVG is written when path has `.svg` extension, otherwise the bitmap
+     * format is determined by extension (like `.png`).
+     *
+     * - `path` Path to output file
+     * - `size` Width and height of image in pixels
+     * - `caption` Caption of chart
+     */
+    pub fn save(
+        &self,
+        path: impl AsRef<Path>,
+        size: (u32, u32),
+        caption: &str,
+    ) -> Result<(), PlotError> {
+        let path = path.as_ref();
+
+        if path
+            .extension()
+            .map(|extension| extension.eq_ignore_ascii_case("svg"))
+            .unwrap_or(false)
+        {
+            let area = SVGBackend::new(path, size).into_drawing_area();
+            self.draw(&area, caption)?;
+            area.present()?;
+        } else {
+            let area = BitMapBackend::new(path, size).into_drawing_area();
+            self.draw(&area, caption)?;
+            area.present()?;
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Draw plot into drawing area
+     *
+     * The area is filled by white background before drawing.
+     */
+    #[allow(clippy::unnecessary_cast)]
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        caption: &str,
+    ) -> Result<(), PlotError> {
+        area.fill(&WHITE)?;
+
+        let mut chart = ChartBuilder::on(area);
+        chart
+            .caption(caption, ("sans-serif", 20))
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(50);
+
+        match *self {
+            Plot::Detection {
+                values,
+                hop_size,
+                sample_rate,
+                threshold,
+                onsets,
+            } => {
+                let period = period(hop_size, sample_rate);
+                let duration = values.len() as f64 * period;
+                let (min, max) = range(values.iter().cloned().chain(threshold));
+
+                let mut chart = chart.build_cartesian_2d(0.0..duration.max(period), min..max)?;
+                chart
+                    .configure_mesh()
+                    .x_desc("time, s")
+                    .y_desc("descriptor")
+                    .draw()?;
+
+                chart.draw_series(LineSeries::new(
+                    values
+                        .iter()
+                        .enumerate()
+                        .map(|(index, &value)| (index as f64 * period, value as f64)),
+                    BLUE,
+                ))?;
+
+                if let Some(threshold) = threshold {
+                    chart.draw_series(LineSeries::new(
+                        [(0.0, threshold as f64), (duration, threshold as f64)],
+                        RED.mix(0.5),
+                    ))?;
+                }
+
+                chart.draw_series(onsets.iter().map(|onset| {
+                    let time = onset.time as f64;
+                    PathElement::new([(time, min), (time, max)], RED)
+                }))?;
+            }
+
+            Plot::Spectrogram {
+                matrix,
+                hop_size,
+                sample_rate,
+            } => {
+                let period = period(hop_size, sample_rate);
+                let duration = matrix.height() as f64 * period;
+                let bins = matrix.length();
+                // the bins cover frequencies from zero to Nyquist inclusive
+                let band = sample_rate as f64 / (2 * bins.max(2) - 2) as f64;
+                let (min, max) = range(matrix.as_slice().iter().cloned());
+
+                let mut chart = chart.build_cartesian_2d(
+                    0.0..duration.max(period),
+                    0.0..(bins as f64 * band).max(band),
+                )?;
+                chart
+                    .configure_mesh()
+                    .disable_mesh()
+                    .x_desc("time, s")
+                    .y_desc("frequency, Hz")
+                    .draw()?;
+
+                chart.draw_series(matrix.rows().enumerate().flat_map(|(frame, row)| {
+                    let time = frame as f64 * period;
+                    row.iter().enumerate().map(move |(bin, &value)| {
+                        let level = normalize(value as f64, min, max);
+                        let frequency = bin as f64 * band;
+                        Rectangle::new(
+                            [(time, frequency), (time + period, frequency + band)],
+                            heat(level).filled(),
+                        )
+                    })
+                }))?;
+            }
+
+            Plot::Beats { beats, duration } => {
+                let mut chart =
+                    chart.build_cartesian_2d(0.0..(duration as f64).max(1e-3), 0.0..1.0)?;
+                chart
+                    .configure_mesh()
+                    .x_desc("time, s")
+                    .y_desc("confidence")
+                    .draw()?;
+
+                chart.draw_series(beats.iter().map(|beat| {
+                    let time = beat.time as f64;
+                    let confidence = (beat.confidence as f64).clamp(0.0, 1.0);
+                    PathElement::new([(time, 0.0), (time, confidence)], BLUE.stroke_width(2))
+                }))?;
+            }
+
+            Plot::Pitch {
+                pitches,
+                hop_size,
+                sample_rate,
+            } => {
+                let period = period(hop_size, sample_rate);
+                let duration = pitches.len() as f64 * period;
+                let (min, max) = range(pitches.iter().cloned().filter(|&pitch| pitch > 0.0));
+
+                let mut chart = chart.build_cartesian_2d(0.0..duration.max(period), min..max)?;
+                chart
+                    .configure_mesh()
+                    .x_desc("time, s")
+                    .y_desc("pitch")
+                    .draw()?;
+
+                // the contour is interrupted at unvoiced frames
+                for segment in voiced_segments(pitches) {
+                    chart.draw_series(LineSeries::new(
+                        segment.map(|index| (index as f64 * period, pitches[index] as f64)),
+                        BLUE,
+                    ))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn period(hop_size: usize, sample_rate: u32) -> f64 {
+    hop_size as f64 / sample_rate.max(1) as f64
+}
+
+/**
+ * Get range of values expanded to be non-empty
+ */
+#[allow(clippy::unnecessary_cast)]
+fn range(values: impl Iterator<Item = Smpl>) -> (f64, f64) {
+    let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+        let value = value as f64;
+        (min.min(value), max.max(value))
+    });
+
+    if min > max {
+        (0.0, 1.0)
+    } else if min == max {
+        (min - 0.5, max + 0.5)
+    } else {
+        (min, max)
+    }
+}
+
+fn normalize(value: f64, min: f64, max: f64) -> f64 {
+    ((value - min) / (max - min)).clamp(0.0, 1.0)
+}
+
+/**
+ * Color of level from dark blue to bright yellow
+ */
+fn heat(level: f64) -> HSLColor {
+    HSLColor(0.65 - 0.5 * level, 0.9, 0.1 + 0.5 * level)
+}
+
+/**
+ * Get ranges of indexes of consecutive positive values
+ */
+fn voiced_segments(values: &[Smpl]) -> Vec<core::ops::Range<usize>> {
+    let mut segments = Vec::new();
+    let mut start = None;
+
+    for (index, &value) in values.iter().enumerate() {
+        match (value > 0.0, start) {
+            (true, None) => start = Some(index),
+            (false, Some(begin)) => {
+                segments.push(begin..index);
+                start = None;
+            }
+            _ => (),
+        }
+    }
+
+    if let Some(begin) = start {
+        segments.push(begin..values.len());
+    }
+
+    segments
+}
+
+#[cfg(test)]
+mod test {
+    use super::{range, voiced_segments};
+
+    #[test]
+    fn test_range() {
+        assert_eq!(range([0.5, -1.0, 2.0].iter().cloned()), (-1.0, 2.0));
+        assert_eq!(range([1.0].iter().cloned()), (0.5, 1.5));
+        assert_eq!(range([].iter().cloned()), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_voiced_segments() {
+        assert_eq!(
+            voiced_segments(&[0.0, 440.0, 441.0, 0.0, -1.0, 220.0]),
+            vec![1..3, 5..6]
+        );
+    }
+}
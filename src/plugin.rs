@@ -0,0 +1,210 @@
+/*!
+ * Analysis audio plugin
+ *
+ * Packages onset detection, beat tracking and pitch detection as _LV2_ plugin
+ * using _lv2_ crate. The audio is passed through unchanged and the results are
+ * reported via control output ports, so the DAW users can map it to parameters
+ * of other plugins or record it as automation.
+ *
+ * This crate cannot be the plugin library itself, so build the companion crate
+ * with `cdylib` type which exports the descriptor:
+
+```ignore
+use lv2::prelude::*;
+
+lv2_descriptors!(aubio::plugin::AnalyzerPlugin);
+```
+
+ * And install it into bundle directory with the `manifest.ttl` like this
+ * (the order of ports should match):
+
+```turtle
+@prefix doap: <http://usefulinc.com/ns/doap#> .
+@prefix lv2:  <http://lv2plug.in/ns/lv2core#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+<urn:aubio-rs:analyzer>
+    a lv2:Plugin, lv2:AnalyserPlugin ;
+    lv2:binary <libaubio_plugin.so> ;
+    doap:name "aubio analyzer" ;
+    lv2:optionalFeature lv2:hardRTCapable ;
+    lv2:port [
+        a lv2:AudioPort, lv2:InputPort ; lv2:index 0 ; lv2:symbol "in" ; lv2:name "In"
+    ] , [
+        a lv2:AudioPort, lv2:OutputPort ; lv2:index 1 ; lv2:symbol "out" ; lv2:name "Out"
+    ] , [
+        a lv2:ControlPort, lv2:InputPort ; lv2:index 2 ; lv2:symbol "threshold" ; lv2:name "Onset threshold" ;
+        lv2:default 0.3 ; lv2:minimum 0.0 ; lv2:maximum 1.0
+    ] , [
+        a lv2:ControlPort, lv2:InputPort ; lv2:index 3 ; lv2:symbol "silence" ; lv2:name "Silence, dB" ;
+        lv2:default -70.0 ; lv2:minimum -120.0 ; lv2:maximum 0.0
+    ] , [
+        a lv2:ControlPort, lv2:OutputPort ; lv2:index 4 ; lv2:symbol "bpm" ; lv2:name "Tempo, BPM"
+    ] , [
+        a lv2:ControlPort, lv2:OutputPort ; lv2:index 5 ; lv2:symbol "pitch" ; lv2:name "Pitch, Hz"
+    ] , [
+        a lv2:ControlPort, lv2:OutputPort ; lv2:index 6 ; lv2:symbol "onset" ; lv2:name "Onset gate" ;
+        lv2:portProperty lv2:toggled
+    ] .
+```
+ */
+
+use crate::{Onset, OnsetMode, Pitch, PitchMode, PitchUnit, Smpl, StreamingAnalyzer, Tempo};
+
+use lv2::prelude::*;
+
+use alloc::vec::Vec;
+
+/// Buffer and hop sizes of onset detection
+const ONSET_SIZES: (usize, usize) = (512, 256);
+
+/// Buffer and hop sizes of beat tracking
+const TEMPO_SIZES: (usize, usize) = (1024, 512);
+
+/// Buffer and hop sizes of pitch detection
+const PITCH_SIZES: (usize, usize) = (2048, 512);
+
+/// Duration of onset gate in seconds
+const GATE_DURATION: f64 = 0.05;
+
+/// Initial capacity of conversion buffer in samples
+const BLOCK_CAPACITY: usize = 8192;
+
+/**
+ * Ports of analyzer plugin
+ */
+#[derive(PortCollection)]
+pub struct AnalyzerPorts {
+    input: InputPort<Audio>,
+    output: OutputPort<Audio>,
+    threshold: InputPort<Control>,
+    silence: InputPort<Control>,
+    bpm: OutputPort<Control>,
+    pitch: OutputPort<Control>,
+    onset: OutputPort<Control>,
+}
+
+/**
+ * Analyzer plugin
+ *
+ * Has audio input and output (pass-through), onset threshold and silence
+ * control inputs, and tempo (BPM), pitch (Hz, zero when unvoiced) and onset
+ * gate (`1` during 50 ms after onset) control outputs.
+ */
+#[uri("urn:aubio-rs:analyzer")]
+pub struct AnalyzerPlugin {
+    onset: StreamingAnalyzer<Onset>,
+    tempo: StreamingAnalyzer<Tempo>,
+    pitch: StreamingAnalyzer<Pitch>,
+    block: Vec<Smpl>,
+    threshold: f32,
+    silence: f32,
+    gate_length: usize,
+    gate: usize,
+    value: f32,
+}
+
+// The plugin is `Send` without unsafe impl because the detectors implement `Send`
+// (the `Plugin` trait requires it, so it is checked by compiler).
+// The `Tempo` is not `Sync` but the plugin never accesses it via shared
+// reference and the host never runs single instance from several threads at once.
+unsafe impl Sync for AnalyzerPlugin {}
+
+impl Plugin for AnalyzerPlugin {
+    type Ports = AnalyzerPorts;
+    type InitFeatures = ();
+    type AudioFeatures = ();
+
+    fn new(info: &PluginInfo, _features: &mut ()) -> Option<Self> {
+        let sample_rate = info.sample_rate() as u32;
+
+        let onset = Onset::new(
+            OnsetMode::default(),
+            ONSET_SIZES.0,
+            ONSET_SIZES.1,
+            sample_rate,
+        )
+        .ok()?;
+        let tempo = Tempo::new(
+            OnsetMode::default(),
+            TEMPO_SIZES.0,
+            TEMPO_SIZES.1,
+            sample_rate,
+        )
+        .ok()?;
+        let pitch = Pitch::new(
+            PitchMode::default(),
+            PITCH_SIZES.0,
+            PITCH_SIZES.1,
+            sample_rate,
+        )
+        .ok()?
+        .with_unit(PitchUnit::Hz);
+
+        Some(Self {
+            onset: StreamingAnalyzer::new(onset),
+            tempo: StreamingAnalyzer::new(tempo),
+            pitch: StreamingAnalyzer::new(pitch),
+            block: Vec::with_capacity(BLOCK_CAPACITY),
+            threshold: f32::NAN,
+            silence: f32::NAN,
+            gate_length: (GATE_DURATION * info.sample_rate()) as usize,
+            gate: 0,
+            value: 0.0,
+        })
+    }
+
+    #[allow(clippy::unnecessary_cast)]
+    fn run(&mut self, ports: &mut AnalyzerPorts, _features: &mut (), sample_count: u32) {
+        self.update_controls(*ports.threshold, *ports.silence);
+
+        self.block.clear();
+        for (input, output) in ports.input.iter().zip(ports.output.iter_mut()) {
+            *output = *input;
+            self.block.push(*input as Smpl);
+        }
+
+        let mut onsets = false;
+        // the failed frames is skipped to keep audio thread running
+        let _ = self
+            .onset
+            .push_with(&self.block, |value| onsets |= value > 0.0);
+
+        let _ = self.tempo.push_with(&self.block, |_| ());
+
+        let mut pitch = self.value;
+        let _ = self
+            .pitch
+            .push_with(&self.block, |value| pitch = value as f32);
+        self.value = pitch.max(0.0);
+
+        if onsets {
+            self.gate = self.gate_length;
+        } else {
+            self.gate = self.gate.saturating_sub(sample_count as usize);
+        }
+
+        **ports.bpm = self.tempo.get_ref().get_bpm() as f32;
+        **ports.pitch = self.value;
+        **ports.onset = if self.gate > 0 { 1.0 } else { 0.0 };
+    }
+}
+
+impl AnalyzerPlugin {
+    /**
+     * Apply changed control values to detectors
+     */
+    fn update_controls(&mut self, threshold: f32, silence: f32) {
+        if threshold != self.threshold {
+            self.threshold = threshold;
+            self.onset.get_mut().set_threshold(threshold as Smpl);
+        }
+
+        if silence != self.silence {
+            self.silence = silence;
+            self.onset.get_mut().set_silence(silence as Smpl);
+            self.tempo.get_mut().set_silence(silence as Smpl);
+            self.pitch.get_mut().set_silence(silence as Smpl);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    check_init, ffi,
-    vec::{CVec, CVecMut, FVec, FVecMut},
+    check_window, ffi, try_init,
+    vec::{CVec, CVecMut, FVec, FVecMut, Memory},
     AsNativeStr, Error, Result, Status, WindowType,
 };
 
@@ -9,6 +9,7 @@ use crate::{
  */
 pub struct PVoc {
     pvoc: *mut ffi::aubio_pvoc_t,
+    window_type: Option<WindowType>,
 }
 
 impl Drop for PVoc {
@@ -19,6 +20,9 @@ impl Drop for PVoc {
     }
 }
 
+unsafe impl Send for PVoc {}
+unsafe impl Sync for PVoc {}
+
 impl PVoc {
     /**
      * Create phase vocoder object
@@ -27,11 +31,16 @@ impl PVoc {
      * - `hop_size` Step size between two consecutive analysis
      */
     pub fn new(win_size: usize, hop_size: usize) -> Result<Self> {
-        let pvoc = unsafe { ffi::new_aubio_pvoc(win_size as ffi::uint_t, hop_size as ffi::uint_t) };
+        check_window("win_size", win_size, hop_size)?;
 
-        check_init(pvoc)?;
+        let pvoc = try_init(|| unsafe {
+            ffi::new_aubio_pvoc(win_size as ffi::uint_t, hop_size as ffi::uint_t)
+        })?;
 
-        Ok(Self { pvoc })
+        Ok(Self {
+            pvoc,
+            window_type: None,
+        })
     }
 
     /**
@@ -77,6 +86,7 @@ impl PVoc {
 
         input.check_size(self.get_hop())?;
         fftgrain.check_size(self.get_win())?;
+        input.check_overlap(&fftgrain)?;
 
         unsafe {
             ffi::aubio_pvoc_do(self.pvoc, input.as_ptr(), fftgrain.as_mut_ptr());
@@ -103,6 +113,7 @@ impl PVoc {
 
         fftgrain.check_size(self.get_win())?;
         output.check_size(self.get_hop())?;
+        fftgrain.check_overlap(&output)?;
 
         // It seems the second arg have missing const qualifier so we need 'as *mut _' here
         unsafe {
@@ -116,11 +127,36 @@ impl PVoc {
      */
     pub fn set_window(&mut self, window_type: WindowType) -> Status {
         if 0 == unsafe { ffi::aubio_pvoc_set_window(self.pvoc, window_type.as_native_cstr()) } {
+            self.window_type = Some(window_type);
             Ok(())
         } else {
             Err(Error::InvalidArg)
         }
     }
+
+    /**
+     * Create new phase vocoder object with the same parameters
+     *
+     * The state of analysis is not copied.
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        let mut pvoc = Self::new(self.get_win(), self.get_hop())?;
+        if let Some(window_type) = self.window_type {
+            pvoc.set_window(window_type)?;
+        }
+        Ok(pvoc)
+    }
+
+    /**
+     * Reset phase vocoder
+     *
+     * The library can't clear the overlap buffers of phase vocoder,
+     * so the new one is created with the same parameters.
+     */
+    pub fn reset(&mut self) -> Status {
+        *self = self.try_clone()?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
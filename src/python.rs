@@ -0,0 +1,303 @@
+/*!
+ * Python bindings
+ *
+ * Exposes the offline analysis functions to _Python_ using _pyo3_.
+ * The analysis runs without holding the GIL, so the functions can be used
+ * from several Python threads.
+ *
+ * This crate cannot be the extension module itself, so build the companion
+ * crate with `cdylib` type which registers the functions:
+
+```ignore
+use pyo3::prelude::*;
+
+#[pymodule]
+fn aubio_rs(py: Python, module: &PyModule) -> PyResult<()> {
+    aubio::python::register(py, module)
+}
+```
+
+ * Then use it from _Python_ (the samples can be any sequence of numbers):
+
+```python
+import aubio_rs
+
+onsets = aubio_rs.detect_onsets(samples, 44100, method="specflux")
+print([onset["time"] for onset in onsets])
+
+print(aubio_rs.track_beats(samples, 44100)["bpm"])
+print(aubio_rs.analyze_file("music.wav")["key"])
+```
+ */
+
+use crate::{
+    offline::{
+        detect_onsets as detect_onsets_impl, track_beats as track_beats_impl,
+        transcribe as transcribe_impl, NotesOptions, OnsetOptions, TempoOptions,
+    },
+    Error, OnsetMode, Smpl,
+};
+
+#[cfg(not(feature = "no_std"))]
+use crate::offline::{analyze_file as analyze_file_impl, AnalysisConfig};
+
+use pyo3::{
+    exceptions::{PyNotImplementedError, PyRuntimeError, PyValueError},
+    prelude::*,
+    types::PyDict,
+    wrap_pyfunction,
+};
+
+use alloc::{format, string::ToString, vec::Vec};
+
+impl From<Error> for PyErr {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::InvalidArg
+            | Error::MismatchSize
+            | Error::InvalidSize { .. }
+            | Error::InvalidParameter { .. } => PyValueError::new_err(error.to_string()),
+            Error::FailedInit | Error::FailedInitReason(_) | Error::Backend(_) => {
+                PyRuntimeError::new_err(error.to_string())
+            }
+            Error::NotCompiled { .. } => PyNotImplementedError::new_err(error.to_string()),
+        }
+    }
+}
+
+fn onset_mode(method: Option<&str>) -> PyResult<OnsetMode> {
+    match method {
+        Some(method) => method
+            .parse()
+            .map_err(|_| PyValueError::new_err(format!("unknown onset method: {}", method))),
+        None => Ok(OnsetMode::default()),
+    }
+}
+
+/**
+ * Detect onsets in mono signal
+ *
+ * Returns the list of dicts with `position` (samples), `time` (seconds)
+ * and `descriptor` keys.
+ */
+#[pyfunction]
+#[pyo3(signature = (
+    samples,
+    sample_rate,
+    method = None,
+    buf_size = 512,
+    hop_size = 256,
+    threshold = None,
+    silence = None,
+    minioi_ms = None,
+))]
+#[allow(clippy::too_many_arguments)]
+fn detect_onsets(
+    py: Python,
+    samples: Vec<Smpl>,
+    sample_rate: u32,
+    method: Option<&str>,
+    buf_size: usize,
+    hop_size: usize,
+    threshold: Option<Smpl>,
+    silence: Option<Smpl>,
+    minioi_ms: Option<Smpl>,
+) -> PyResult<Vec<PyObject>> {
+    let options = OnsetOptions {
+        method: onset_mode(method)?,
+        buf_size,
+        hop_size,
+        threshold,
+        silence,
+        minioi_ms,
+        ..OnsetOptions::default()
+    };
+
+    let onsets = py.allow_threads(|| detect_onsets_impl(&samples, sample_rate, options))?;
+
+    onsets
+        .into_iter()
+        .map(|onset| {
+            let dict = PyDict::new(py);
+            dict.set_item("position", onset.position)?;
+            dict.set_item("time", onset.time)?;
+            dict.set_item("descriptor", onset.descriptor)?;
+            Ok(dict.into())
+        })
+        .collect()
+}
+
+/**
+ * Track beats in mono signal
+ *
+ * Returns the dict with `bpm` key and `beats` key containing the list of dicts
+ * with `position` (samples), `time` (seconds) and `confidence` keys.
+ */
+#[pyfunction]
+#[pyo3(signature = (
+    samples,
+    sample_rate,
+    method = None,
+    buf_size = 1024,
+    hop_size = 512,
+    threshold = None,
+    silence = None,
+))]
+#[allow(clippy::too_many_arguments)]
+fn track_beats(
+    py: Python,
+    samples: Vec<Smpl>,
+    sample_rate: u32,
+    method: Option<&str>,
+    buf_size: usize,
+    hop_size: usize,
+    threshold: Option<Smpl>,
+    silence: Option<Smpl>,
+) -> PyResult<PyObject> {
+    let options = TempoOptions {
+        method: onset_mode(method)?,
+        buf_size,
+        hop_size,
+        threshold,
+        silence,
+        ..TempoOptions::default()
+    };
+
+    let analysis = py.allow_threads(|| track_beats_impl(&samples, sample_rate, options))?;
+
+    let beats = analysis
+        .beats
+        .into_iter()
+        .map(|beat| {
+            let dict = PyDict::new(py);
+            dict.set_item("position", beat.position)?;
+            dict.set_item("time", beat.time)?;
+            dict.set_item("confidence", beat.confidence)?;
+            Ok(dict.into())
+        })
+        .collect::<PyResult<Vec<PyObject>>>()?;
+
+    let dict = PyDict::new(py);
+    dict.set_item("bpm", analysis.bpm)?;
+    dict.set_item("beats", beats)?;
+    Ok(dict.into())
+}
+
+/**
+ * Transcribe notes of mono signal
+ *
+ * Returns the list of dicts with `pitch` (MIDI), `velocity`, `time` and
+ * `duration` (seconds) keys.
+ */
+#[pyfunction]
+#[pyo3(signature = (
+    samples,
+    sample_rate,
+    buf_size = 512,
+    hop_size = 256,
+    silence = None,
+    minioi_ms = None,
+    release_drop = None,
+))]
+#[allow(clippy::too_many_arguments)]
+fn transcribe(
+    py: Python,
+    samples: Vec<Smpl>,
+    sample_rate: u32,
+    buf_size: usize,
+    hop_size: usize,
+    silence: Option<Smpl>,
+    minioi_ms: Option<Smpl>,
+    release_drop: Option<Smpl>,
+) -> PyResult<Vec<PyObject>> {
+    let options = NotesOptions {
+        buf_size,
+        hop_size,
+        silence,
+        minioi_ms,
+        release_drop,
+    };
+
+    let notes = py.allow_threads(|| transcribe_impl(&samples, sample_rate, options))?;
+
+    notes
+        .into_iter()
+        .map(|note| {
+            let dict = PyDict::new(py);
+            dict.set_item("pitch", note.pitch)?;
+            dict.set_item("velocity", note.velocity)?;
+            dict.set_item("time", note.time)?;
+            dict.set_item("duration", note.duration)?;
+            Ok(dict.into())
+        })
+        .collect()
+}
+
+/**
+ * Analyze audio file
+ *
+ * Returns the dict with `sample_rate`, `channels`, `duration`, `bpm`,
+ * `key` (like `"A minor"`) and `onsets` (count) keys. The missing
+ * estimates are `None`.
+ */
+#[cfg(not(feature = "no_std"))]
+#[pyfunction]
+#[pyo3(signature = (path, sample_rate = 0, onsets = true, tempo = true, key = true))]
+fn analyze_file(
+    py: Python,
+    path: &str,
+    sample_rate: u32,
+    onsets: bool,
+    tempo: bool,
+    key: bool,
+) -> PyResult<PyObject> {
+    let config = AnalysisConfig {
+        sample_rate,
+        onsets: if onsets {
+            Some(OnsetOptions::default())
+        } else {
+            None
+        },
+        tempo: if tempo {
+            Some(TempoOptions::default())
+        } else {
+            None
+        },
+        key,
+    };
+
+    let report = py.allow_threads(|| analyze_file_impl(path, &config))?;
+
+    let dict = PyDict::new(py);
+    dict.set_item("sample_rate", report.sample_rate)?;
+    dict.set_item("channels", report.channels)?;
+    dict.set_item("duration", report.duration)?;
+    dict.set_item("bpm", report.bpm)?;
+    dict.set_item("key", report.key.map(|key| key.to_string()))?;
+    dict.set_item("onsets", report.onsets)?;
+    Ok(dict.into())
+}
+
+/**
+ * Register analysis functions in Python module
+ */
+pub fn register(_py: Python, module: &PyModule) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(detect_onsets, module)?)?;
+    module.add_function(wrap_pyfunction!(track_beats, module)?)?;
+    module.add_function(wrap_pyfunction!(transcribe, module)?)?;
+    #[cfg(not(feature = "no_std"))]
+    module.add_function(wrap_pyfunction!(analyze_file, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::onset_mode;
+    use crate::OnsetMode;
+
+    #[test]
+    fn test_onset_mode() {
+        assert_eq!(onset_mode(None).unwrap(), OnsetMode::default());
+        assert_eq!(onset_mode(Some("specflux")).unwrap(), OnsetMode::SpecFlux);
+    }
+}
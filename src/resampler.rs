@@ -1,18 +1,26 @@
 use crate::{
-    check_init, ffi,
-    vec::{FVec, FVecMut},
+    ffi, try_init,
+    vec::{FVec, FVecMut, Memory},
     Error, Result, Smpl, Status,
 };
 
-use std::{
+use core::{
     fmt::{Display, Formatter, Result as FmtResult},
     str::FromStr,
 };
 
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
 /**
  * Resampling method
  */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[repr(u32)]
 pub enum ResampleMode {
     BestQuality = 0,
@@ -71,6 +79,7 @@ impl FromStr for ResampleMode {
 pub struct Resampler {
     resampler: *mut ffi::aubio_resampler_t,
     ratio: Smpl,
+    mode: ResampleMode,
 }
 
 impl Drop for Resampler {
@@ -79,6 +88,9 @@ impl Drop for Resampler {
     }
 }
 
+unsafe impl Send for Resampler {}
+unsafe impl Sync for Resampler {}
+
 impl Resampler {
     /**
      * Create resampler object
@@ -87,11 +99,23 @@ impl Resampler {
      * - `type` Resampling method
      */
     pub fn new(ratio: Smpl, mode: ResampleMode) -> Result<Self> {
-        let resampler = unsafe { ffi::new_aubio_resampler(ratio, mode as ffi::uint_t) };
+        let resampler =
+            try_init(|| unsafe { ffi::new_aubio_resampler(ratio, mode as ffi::uint_t) })?;
 
-        check_init(resampler)?;
+        Ok(Self {
+            resampler,
+            ratio,
+            mode,
+        })
+    }
 
-        Ok(Self { resampler, ratio })
+    /**
+     * Create new resampler object with the same parameters
+     *
+     * The state of resampling is not copied.
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        Self::new(self.ratio, self.mode)
     }
 
     /**
@@ -116,8 +140,191 @@ impl Resampler {
         let mut output = output.into();
 
         output.check_size((input.size() as Smpl * self.ratio).floor() as usize)?;
+        input.check_overlap(&output)?;
 
         unsafe { ffi::aubio_resampler_do(self.resampler, input.as_ptr(), output.as_mut_ptr()) }
         Ok(())
     }
 }
+
+/// Maximum input block size to search for integral output size
+const MAX_BLOCK_SIZE: usize = 4096;
+
+/// Preferred minimum input block size
+const MIN_BLOCK_SIZE: usize = 256;
+
+/**
+ * Streaming resampler
+ *
+ * Unlike `Resampler` this object accepts input chunks of any length. The input
+ * is processed in blocks which are resampled to the whole number of samples
+ * (like 147 to 160 samples for 44100 to 48000 Hz conversion), so the fractional
+ * remainders are kept between calls instead of being dropped.
+ */
+pub struct StreamingResampler {
+    resampler: Resampler,
+    buffer: Vec<Smpl>,
+    filled: usize,
+    output: Vec<Smpl>,
+}
+
+impl StreamingResampler {
+    /**
+     * Create streaming resampler object
+     *
+     * - `ratio` The `output_sample_rate` / `input_sample_rate`
+     * - `type` Resampling method
+     */
+    pub fn new(ratio: Smpl, mode: ResampleMode) -> Result<Self> {
+        if ratio <= 0.0 {
+            return Err(Error::InvalidArg);
+        }
+
+        let resampler = Resampler::new(ratio, mode)?;
+        let block_size = block_size(ratio);
+        let output_size = (block_size as Smpl * ratio).round() as usize;
+
+        Ok(Self {
+            resampler,
+            buffer: vec![0.; block_size],
+            filled: 0,
+            output: vec![0.; output_size],
+        })
+    }
+
+    /**
+     * Create new streaming resampler object with the same parameters
+     *
+     * The buffered samples is not copied.
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        Self::new(self.resampler.ratio, self.resampler.mode)
+    }
+
+    /**
+     * Get ratio
+     */
+    pub fn get_ratio(&self) -> Smpl {
+        self.resampler.get_ratio()
+    }
+
+    /**
+     * Get number of input samples which is waiting for the block completion
+     */
+    pub fn get_pending(&self) -> usize {
+        self.filled
+    }
+
+    /**
+     * Resample input chunk
+     *
+     * Returns resampled samples of all completed blocks.
+     */
+    pub fn push(&mut self, input: &[Smpl]) -> Result<Vec<Smpl>> {
+        let mut output = Vec::new();
+        self.push_to(input, &mut output)?;
+        Ok(output)
+    }
+
+    /**
+     * Resample input chunk appending the result to `output`
+     */
+    pub fn push_to(&mut self, mut input: &[Smpl], output: &mut Vec<Smpl>) -> Status {
+        let block_size = self.buffer.len();
+
+        while !input.is_empty() {
+            let count = input.len().min(block_size - self.filled);
+            self.buffer[self.filled..self.filled + count].copy_from_slice(&input[..count]);
+            self.filled += count;
+            input = &input[count..];
+
+            if self.filled == block_size {
+                self.filled = 0;
+                self.process(output, self.output.len())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Resample buffered samples padded by zeros
+     *
+     * Returns the resampled samples which correspond to the buffered input.
+     */
+    pub fn flush(&mut self) -> Result<Vec<Smpl>> {
+        let mut output = Vec::new();
+
+        if self.filled > 0 {
+            for sample in &mut self.buffer[self.filled..] {
+                *sample = 0.;
+            }
+            let count = (self.filled as Smpl * self.get_ratio()).round() as usize;
+            self.filled = 0;
+            self.process(&mut output, count.min(self.output.len()))?;
+        }
+
+        Ok(output)
+    }
+
+    fn process(&mut self, output: &mut Vec<Smpl>, count: usize) -> Status {
+        let input = FVec::from(self.buffer.as_slice());
+        let mut resampled = FVecMut::from(self.output.as_mut_slice());
+
+        // the size of output block is rounded instead of truncated so it is passed directly
+        unsafe {
+            ffi::aubio_resampler_do(
+                self.resampler.resampler,
+                input.as_ptr(),
+                resampled.as_mut_ptr(),
+            )
+        }
+
+        output.extend_from_slice(&self.output[..count]);
+        Ok(())
+    }
+}
+
+/**
+ * Find input block size which is resampled to the whole number of samples
+ */
+fn block_size(ratio: Smpl) -> usize {
+    (1..=MAX_BLOCK_SIZE)
+        .find(|&size| {
+            let output = size as Smpl * ratio;
+            output >= 1.0 && (output - output.round()).abs() < 1e-3
+        })
+        .map(|size| size * MIN_BLOCK_SIZE.div_ceil(size))
+        .unwrap_or(MAX_BLOCK_SIZE)
+}
+
+#[cfg(test)]
+mod test {
+    use super::block_size;
+    use crate::*;
+
+    #[test]
+    fn test_block_size() {
+        assert_eq!(block_size(2.0), 256);
+        assert_eq!(block_size(0.5), 256);
+        assert_eq!(block_size(48000.0 / 44100.0), 294);
+    }
+
+    #[test]
+    fn test_streaming_resampler() {
+        let mut resampler =
+            StreamingResampler::new(48000.0 / 44100.0, ResampleMode::Linear).unwrap();
+
+        let input = vec![0.5; 44000];
+        let mut output = Vec::new();
+
+        for chunk in input.chunks(1000) {
+            resampler.push_to(chunk, &mut output).unwrap();
+        }
+        assert!(resampler.get_pending() > 0);
+
+        output.extend(resampler.flush().unwrap());
+        assert_eq!(resampler.get_pending(), 0);
+        assert_eq!(output.len(), 47891);
+    }
+}
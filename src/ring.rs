@@ -0,0 +1,195 @@
+use crate::Smpl;
+
+use std::{
+    cell::UnsafeCell,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    thread::{self, Thread},
+};
+
+use alloc::{boxed::Box, vec::Vec};
+
+struct Shared {
+    data: Box<[UnsafeCell<Smpl>]>,
+    // total number of written samples, modified by producer only
+    head: AtomicUsize,
+    // total number of read samples, modified by consumer only
+    tail: AtomicUsize,
+    closed: AtomicBool,
+    consumer: Thread,
+}
+
+// the slots between tail and head are accessed by consumer only,
+// and the rest ones by producer only
+unsafe impl Sync for Shared {}
+
+/**
+ * Create preallocated single-producer single-consumer ring buffer of samples
+ *
+ * - `capacity` Maximum number of samples which is buffered
+ *
+ * The consumer should be used by the calling thread, so the producer can wake it.
+ */
+pub(crate) fn channel(capacity: usize) -> (Producer, Consumer) {
+    let shared = Arc::new(Shared {
+        data: (0..capacity.max(1)).map(|_| UnsafeCell::new(0.0)).collect(),
+        head: AtomicUsize::new(0),
+        tail: AtomicUsize::new(0),
+        closed: AtomicBool::new(false),
+        consumer: thread::current(),
+    });
+
+    (
+        Producer {
+            shared: shared.clone(),
+        },
+        Consumer { shared },
+    )
+}
+
+/**
+ * Writing side of ring buffer
+ *
+ * Never blocks and never allocates, so it can be used in the audio callbacks.
+ * The consumer finishes reading when the producer is dropped.
+ */
+pub(crate) struct Producer {
+    shared: Arc<Shared>,
+}
+
+impl Producer {
+    /**
+     * Write samples and wake consumer
+     *
+     * The samples which does not fit into buffer are dropped.
+     * Returns the number of written samples.
+     */
+    pub fn push(&self, data: &[Smpl]) -> usize {
+        let shared = &*self.shared;
+        let capacity = shared.data.len();
+        let head = shared.head.load(Ordering::Relaxed);
+        let tail = shared.tail.load(Ordering::Acquire);
+        let length = data.len().min(capacity - head.wrapping_sub(tail));
+
+        for (index, &sample) in data[..length].iter().enumerate() {
+            let slot = &shared.data[head.wrapping_add(index) % capacity];
+            unsafe { *slot.get() = sample };
+        }
+
+        shared
+            .head
+            .store(head.wrapping_add(length), Ordering::Release);
+        shared.consumer.unpark();
+
+        length
+    }
+}
+
+impl Drop for Producer {
+    fn drop(&mut self) {
+        self.shared.closed.store(true, Ordering::Release);
+        self.shared.consumer.unpark();
+    }
+}
+
+/**
+ * Reading side of ring buffer
+ */
+pub(crate) struct Consumer {
+    shared: Arc<Shared>,
+}
+
+impl Consumer {
+    /**
+     * Wait for samples and move all available ones to `output`
+     *
+     * Returns `false` when the producer is dropped and all samples is read.
+     */
+    pub fn read(&mut self, output: &mut Vec<Smpl>) -> bool {
+        let shared = &*self.shared;
+        let capacity = shared.data.len();
+        let tail = shared.tail.load(Ordering::Relaxed);
+
+        output.clear();
+
+        loop {
+            // all samples is written before producer is closed
+            let closed = shared.closed.load(Ordering::Acquire);
+            let head = shared.head.load(Ordering::Acquire);
+
+            if head != tail {
+                let length = head.wrapping_sub(tail);
+                output.extend((0..length).map(|index| {
+                    let slot = &shared.data[tail.wrapping_add(index) % capacity];
+                    unsafe { *slot.get() }
+                }));
+                shared.tail.store(head, Ordering::Release);
+                return true;
+            }
+
+            if closed {
+                return false;
+            }
+
+            thread::park();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::channel;
+    use std::thread;
+
+    #[test]
+    fn test_ring() {
+        let (producer, mut consumer) = channel(4);
+        let mut output = Vec::new();
+
+        assert_eq!(producer.push(&[1.0, 2.0, 3.0]), 3);
+        assert!(consumer.read(&mut output));
+        assert_eq!(output, vec![1.0, 2.0, 3.0]);
+
+        // wraps around and drops the samples which does not fit
+        assert_eq!(producer.push(&[4.0, 5.0, 6.0, 7.0, 8.0]), 4);
+        assert_eq!(producer.push(&[9.0]), 0);
+        assert!(consumer.read(&mut output));
+        assert_eq!(output, vec![4.0, 5.0, 6.0, 7.0]);
+
+        producer.push(&[9.0]);
+        drop(producer);
+        assert!(consumer.read(&mut output));
+        assert_eq!(output, vec![9.0]);
+        assert!(!consumer.read(&mut output));
+    }
+
+    #[test]
+    fn test_ring_thread() {
+        let (producer, mut consumer) = channel(16);
+
+        let writer = thread::spawn(move || {
+            for chunk in (0..100).collect::<Vec<_>>().chunks(10) {
+                let chunk = chunk.iter().map(|&value| value as _).collect::<Vec<_>>();
+                let mut data = &chunk[..];
+                while !data.is_empty() {
+                    data = &data[producer.push(data)..];
+                    thread::yield_now();
+                }
+            }
+        });
+
+        let mut output = Vec::new();
+        let mut received = Vec::new();
+        while consumer.read(&mut output) {
+            received.extend_from_slice(&output);
+        }
+        writer.join().unwrap();
+
+        assert_eq!(
+            received,
+            (0..100).map(|value| value as _).collect::<Vec<_>>()
+        );
+    }
+}
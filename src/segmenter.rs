@@ -0,0 +1,310 @@
+use crate::{
+    level_detection, utils::median, vec::FVec, Onset, OnsetMode, Pitch, PitchMode, PitchTrack,
+    PitchUnit, Result, Smpl, Status,
+};
+
+use alloc::vec::Vec;
+
+/**
+ * Note segmented by `NoteSegmenter`
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NoteSegment {
+    /// Position of the note start in samples
+    pub start: usize,
+    /// Note duration in samples
+    pub duration: usize,
+    /// Median MIDI pitch of the note
+    pub pitch: Smpl,
+    /// Peak level of the note in dB SPL
+    pub level: Smpl,
+}
+
+/**
+ * Note segmentation object for monophonic signals
+ *
+ * Unlike `Notes` this object combines the results of several detectors:
+ *
+ * - the note starts are taken from `Onset`,
+ * - the note ends when the level drops below silence threshold
+ *   (see `level_detection()`) or when the next onset is detected,
+ * - the note pitch is the median of the `Pitch` estimates over the note,
+ *   which are cleaned using `PitchTrack` before,
+ * - the note level is the peak level over the note.
+ *
+ * The segments without voiced frames are dropped.
+ */
+pub struct NoteSegmenter {
+    onset: Onset,
+    pitch: Pitch,
+    track: PitchTrack,
+    silence: Smpl,
+    hop_size: usize,
+    position: usize,
+    current: Option<Segment>,
+}
+
+struct Segment {
+    start: usize,
+    track: Vec<(Smpl, Smpl)>,
+    level: Smpl,
+}
+
+impl NoteSegmenter {
+    /**
+     * Create note segmentation object
+     *
+     * - `buf_size` Buffer size for phase vocoder
+     * - `hop_size` Hop size for phase vocoder
+     * - `sample_rate` Sampling rate of the input signal
+     */
+    pub fn new(buf_size: usize, hop_size: usize, sample_rate: u32) -> Result<Self> {
+        let onset = Onset::new(OnsetMode::default(), buf_size, hop_size, sample_rate)?;
+        let pitch = Pitch::new(PitchMode::default(), buf_size, hop_size, sample_rate)?
+            .with_unit(PitchUnit::Midi);
+
+        Ok(Self {
+            onset,
+            pitch,
+            track: PitchTrack::new().with_unit(PitchUnit::Midi),
+            silence: -70.0,
+            hop_size,
+            position: 0,
+            current: None,
+        })
+    }
+
+    /**
+     * Set silence threshold, in dB SPL
+     */
+    pub fn with_silence(mut self, silence: Smpl) -> Self {
+        self.set_silence(silence);
+        self
+    }
+
+    /**
+     * Set onset detection threshold
+     */
+    pub fn with_threshold(mut self, threshold: Smpl) -> Self {
+        self.set_threshold(threshold);
+        self
+    }
+
+    /**
+     * Set minimum inter-onset interval, in millisecond
+     */
+    pub fn with_minioi_ms(mut self, minioi: Smpl) -> Self {
+        self.set_minioi_ms(minioi);
+        self
+    }
+
+    /**
+     * Set pitch track post-processor
+     */
+    pub fn with_pitch_track(mut self, track: PitchTrack) -> Self {
+        self.set_pitch_track(track);
+        self
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.hop_size
+    }
+
+    /**
+     * Execute note segmentation on an input signal frame
+     *
+     * - `input` Input signal of size `hop_size`
+     *
+     * Returns the note which has been finished by this frame.
+     */
+    pub fn do_result<'i, I>(&mut self, input: I) -> Result<Option<NoteSegment>>
+    where
+        I: Into<FVec<'i>>,
+    {
+        let input = input.into();
+        let input = input.as_slice();
+
+        let onset = self.onset.do_result(input)?;
+        let pitch = self.pitch.do_result(input)?;
+        let confidence = self.pitch.get_confidence();
+        let level = level_detection(input, self.silence);
+        // level_detection returns 1.0 for the frames below threshold
+        let silent = level == 1.0;
+
+        let mut finished = None;
+
+        if silent {
+            finished = self.finish(self.position);
+        } else if onset > 0.0 {
+            let start = self.onset.get_last();
+            finished = self.finish(start);
+            self.current = Some(Segment {
+                start,
+                track: Vec::new(),
+                level,
+            });
+        }
+
+        if !silent {
+            if let Some(current) = &mut self.current {
+                current.track.push((pitch, confidence));
+                current.level = current.level.max(level);
+            }
+        }
+
+        self.position += self.hop_size;
+
+        Ok(finished)
+    }
+
+    /**
+     * Finish the current note at the end of processed signal
+     */
+    pub fn flush(&mut self) -> Option<NoteSegment> {
+        self.finish(self.position)
+    }
+
+    /**
+     * Get the position of the next frame in samples
+     */
+    pub fn get_position(&self) -> usize {
+        self.position
+    }
+
+    /**
+     * Reset segmentation to start with new signal
+     *
+     * The current note is discarded.
+     */
+    pub fn reset(&mut self) -> Status {
+        self.onset.reset();
+        self.pitch.reset()?;
+        self.position = 0;
+        self.current = None;
+        Ok(())
+    }
+
+    /**
+     * Set silence threshold, in dB SPL
+     *
+     * Default is `-70`.
+     */
+    pub fn set_silence(&mut self, silence: Smpl) {
+        self.silence = silence;
+        self.onset.set_silence(silence);
+        self.pitch.set_silence(silence);
+    }
+
+    /**
+     * Get silence threshold, in dB SPL
+     */
+    pub fn get_silence(&self) -> Smpl {
+        self.silence
+    }
+
+    /**
+     * Set onset detection threshold
+     */
+    pub fn set_threshold(&mut self, threshold: Smpl) {
+        self.onset.set_threshold(threshold);
+    }
+
+    /**
+     * Get onset detection threshold
+     */
+    pub fn get_threshold(&self) -> Smpl {
+        self.onset.get_threshold()
+    }
+
+    /**
+     * Set minimum inter-onset interval, in millisecond
+     */
+    pub fn set_minioi_ms(&mut self, minioi: Smpl) {
+        self.onset.set_minioi_ms(minioi);
+    }
+
+    /**
+     * Get minimum inter-onset interval, in millisecond
+     */
+    pub fn get_minioi_ms(&self) -> Smpl {
+        self.onset.get_minioi_ms()
+    }
+
+    /**
+     * Set pitch track post-processor
+     *
+     * The unit of track is always forced to `PitchUnit::Midi`.
+     */
+    pub fn set_pitch_track(&mut self, track: PitchTrack) {
+        self.track = track.with_unit(PitchUnit::Midi);
+    }
+
+    /**
+     * Get pitch track post-processor
+     */
+    pub fn get_pitch_track(&self) -> PitchTrack {
+        self.track
+    }
+
+    fn finish(&mut self, end: usize) -> Option<NoteSegment> {
+        let current = self.current.take()?;
+
+        let mut pitches = self
+            .track
+            .process(&current.track)
+            .into_iter()
+            .filter(|&pitch| pitch > 0.0)
+            .collect::<Vec<_>>();
+
+        median(&mut pitches).map(|pitch| NoteSegment {
+            start: current.start,
+            duration: end.saturating_sub(current.start),
+            pitch,
+            level: current.level,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    fn tone(sample_rate: u32, length: usize, freq: Smpl) -> Vec<Smpl> {
+        (0..length)
+            .map(|index| {
+                let phase = 2.0 * std::f64::consts::PI as Smpl * freq * index as Smpl;
+                0.5 * (phase / sample_rate as Smpl).sin()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_note_segmenter() {
+        let sample_rate = 44100;
+        let hop_size = 256;
+
+        let mut samples = vec![0.0; sample_rate as usize / 4];
+        samples.extend(tone(sample_rate, sample_rate as usize / 2, 440.0));
+        samples.extend(vec![0.0; sample_rate as usize / 4]);
+        samples.extend(tone(sample_rate, sample_rate as usize / 2, 220.0));
+        samples.extend(vec![0.0; sample_rate as usize / 4]);
+
+        let mut segmenter = NoteSegmenter::new(1024, hop_size, sample_rate).unwrap();
+        let mut notes = Vec::new();
+
+        for frame in samples.chunks_exact(hop_size) {
+            notes.extend(segmenter.do_result(frame).unwrap());
+        }
+        notes.extend(segmenter.flush());
+
+        assert_eq!(notes.len(), 2);
+        assert!((notes[0].pitch - 69.0).abs() < 0.5);
+        assert!((notes[1].pitch - 57.0).abs() < 0.5);
+        assert!(notes[0].start < notes[1].start);
+        assert!(notes[0].duration > 0 && notes[0].duration < sample_rate as usize);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{Analyzer, Error, Smpl, Status, StreamingAnalyzer};
+
+use dasp_frame::Frame;
+use dasp_sample::{Sample, ToSample};
+use dasp_signal::Signal;
+
+/**
+ * Analyzing tap of _dasp_ signal
+ *
+ * Yields the frames of wrapped signal unchanged while its down-mixed copy
+ * is fed to the analyzer sample by sample, so the analysis can be inserted
+ * into any point of signal graph without the intermediate buffers.
+ * The results are passed to callback with positions of frames in samples.
+ *
+ * The signal cannot report the errors, so the analysis stops at the first
+ * error which can be taken using `AnalyzerSignal::take_error()`.
+
+```ignore
+use aubio::{AnalyzerSignal, Onset, OnsetMode};
+use dasp_signal::{self as signal, Signal};
+
+let onset = Onset::new(OnsetMode::Hfc, 1024, 512, 44100).unwrap();
+let source = signal::rate(44100.0).const_hz(440.0).sine();
+
+let mut tapped = AnalyzerSignal::new(source, onset, |position, value| {
+    println!("{}: {}", position, value);
+});
+
+let output: Vec<f64> = tapped.by_ref().take(44100).collect();
+```
+ */
+pub struct AnalyzerSignal<S, A, F> {
+    signal: S,
+    analyzer: StreamingAnalyzer<A>,
+    callback: F,
+    error: Option<Error>,
+}
+
+impl<S, A, F> AnalyzerSignal<S, A, F>
+where
+    S: Signal,
+    <S::Frame as Frame>::Sample: ToSample<Smpl>,
+    A: Analyzer,
+    F: FnMut(usize, A::Output),
+{
+    /**
+     * Wrap signal
+     *
+     * - `signal` Signal to analyze
+     * - `analyzer` Analyzer to process the signal
+     * - `callback` Function which receives the position of frame and result of analysis
+     */
+    pub fn new(signal: S, analyzer: A, callback: F) -> Self {
+        Self {
+            signal,
+            analyzer: StreamingAnalyzer::new(analyzer),
+            callback,
+            error: None,
+        }
+    }
+
+    /**
+     * Process buffered samples padded by zeros
+     *
+     * Call this when the signal ends to get the result for last incomplete frame.
+     */
+    pub fn flush(&mut self) -> Status {
+        let position = self.analyzer.get_position();
+
+        if let Some(output) = self.analyzer.flush()? {
+            (self.callback)(position, output);
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Take the error which stopped analysis
+     */
+    pub fn take_error(&mut self) -> Option<Error> {
+        self.error.take()
+    }
+
+    /**
+     * Get reference to wrapped signal
+     */
+    pub fn get_ref(&self) -> &S {
+        &self.signal
+    }
+
+    /**
+     * Get reference to analyzer
+     */
+    pub fn get_analyzer(&self) -> &A {
+        self.analyzer.get_ref()
+    }
+
+    /**
+     * Unwrap signal and analyzer
+     */
+    pub fn into_inner(self) -> (S, A) {
+        (self.signal, self.analyzer.into_inner())
+    }
+
+    fn feed(&mut self, frame: S::Frame) {
+        if self.error.is_some() {
+            return;
+        }
+
+        let scale = 1.0 / <S::Frame as Frame>::CHANNELS as Smpl;
+        let sample = frame
+            .channels()
+            .map(|sample| sample.to_sample::<Smpl>())
+            .sum::<Smpl>()
+            * scale;
+
+        let result = self
+            .analyzer
+            .push_with_position(&[sample], &mut self.callback);
+
+        if let Err(error) = result {
+            self.error = Some(error);
+        }
+    }
+}
+
+impl<S, A, F> Signal for AnalyzerSignal<S, A, F>
+where
+    S: Signal,
+    <S::Frame as Frame>::Sample: ToSample<Smpl>,
+    A: Analyzer,
+    F: FnMut(usize, A::Output),
+{
+    type Frame = S::Frame;
+
+    fn next(&mut self) -> Self::Frame {
+        let frame = self.signal.next();
+        self.feed(frame);
+        frame
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::testing::Sum;
+    use crate::*;
+    use dasp_signal::{self as signal, Signal};
+
+    #[test]
+    fn test_signal() {
+        let frames = [
+            [1.0f32, 0.0],
+            [0.5, 0.5],
+            [0.25, 0.25],
+            [1.0, 1.0],
+            [2.0, 0.0],
+        ];
+        let mut results = Vec::new();
+
+        let mut tapped = AnalyzerSignal::new(
+            signal::from_iter(frames.iter().cloned()),
+            Sum(2),
+            |position, output| results.push((position, output)),
+        );
+
+        let output = tapped.by_ref().until_exhausted().collect::<Vec<_>>();
+        assert_eq!(output, frames);
+
+        tapped.flush().unwrap();
+        assert!(tapped.take_error().is_none());
+        drop(tapped);
+
+        assert_eq!(results, vec![(0, 1.0), (2, 1.25), (4, 1.0)]);
+    }
+}
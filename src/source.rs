@@ -0,0 +1,205 @@
+use crate::{
+    check_non_zero, ffi, try_init, vec::FVecMut, Error, MediaSource, Result, Smpl, Status,
+};
+
+use std::{ffi::CString, path::Path};
+
+/**
+ * Media source object
+ *
+ * Reads audio files using the backends which _aubio_ was built with
+ * (native WAV reader, _libsndfile_, _libav_ or _CoreAudio_). The multichannel
+ * sources are down-mixed to mono.
+ */
+pub struct Source {
+    source: *mut ffi::aubio_source_t,
+    hop_size: usize,
+    block: Vec<Smpl>,
+}
+
+impl Drop for Source {
+    fn drop(&mut self) {
+        unsafe { ffi::del_aubio_source(self.source) }
+    }
+}
+
+// Some backends (like _CoreAudio_) query the file when reading properties,
+// so it is not `Sync`
+unsafe impl Send for Source {}
+
+impl Source {
+    /**
+     * Open media source
+     *
+     * - `path` The file path to read from
+     * - `sample_rate` Sampling rate to read the file at, `0` to use the rate of the file
+     * - `hop_size` The size of the blocks to read
+     *
+     * Fails with `Error::NotCompiled` when library is built without media sources.
+     */
+    pub fn new(path: impl AsRef<Path>, sample_rate: u32, hop_size: usize) -> Result<Self> {
+        check_non_zero("hop_size", hop_size)?;
+
+        let path = path.as_ref().to_str().ok_or(Error::InvalidArg)?;
+        let path = CString::new(path).map_err(|_| Error::InvalidArg)?;
+
+        let source = try_init(|| unsafe {
+            ffi::new_aubio_source(
+                path.as_ptr(),
+                sample_rate as ffi::uint_t,
+                hop_size as ffi::uint_t,
+            )
+        })
+        .map_err(|error| match error {
+            // library is built without any media backend
+            Error::FailedInitReason(reason) if reason.contains("no source built-in") => {
+                Error::NotCompiled { feature: "source" }
+            }
+            error => error,
+        })?;
+
+        Ok(Self {
+            source,
+            hop_size,
+            block: vec![0.; hop_size],
+        })
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.hop_size
+    }
+
+    /**
+     * Get sampling rate of source, in Hz
+     */
+    pub fn get_samplerate(&self) -> u32 {
+        unsafe { ffi::aubio_source_get_samplerate(self.source) }
+    }
+
+    /**
+     * Get number of channels of source
+     */
+    pub fn get_channels(&self) -> usize {
+        (unsafe { ffi::aubio_source_get_channels(self.source) }) as usize
+    }
+
+    /**
+     * Get duration of source, in frames
+     */
+    pub fn get_duration(&self) -> usize {
+        (unsafe { ffi::aubio_source_get_duration(self.source) }) as usize
+    }
+
+    /**
+     * Seek to position, in frames
+     */
+    pub fn seek(&mut self, position: usize) -> Status {
+        if 0 == unsafe { ffi::aubio_source_seek(self.source, position as ffi::uint_t) } {
+            Ok(())
+        } else {
+            Err(Error::InvalidArg)
+        }
+    }
+
+    /**
+     * Read the next block of mono samples
+     *
+     * - `output` Output vector (`hop_size` long)
+     *
+     * Returns the number of frames actually read, which is less than `hop_size`
+     * at the end of source.
+     */
+    pub fn do_<'o, O>(&mut self, output: O) -> Result<usize>
+    where
+        O: Into<FVecMut<'o>>,
+    {
+        let mut output = output.into();
+        let mut read = 0;
+
+        output.check_size(self.hop_size)?;
+
+        unsafe {
+            ffi::aubio_source_do(self.source, output.as_mut_ptr(), &mut read);
+        }
+        Ok(read as usize)
+    }
+
+    /**
+     * Read the rest of source into vector of mono samples
+     */
+    pub fn read_all(&mut self) -> Result<Vec<Smpl>> {
+        let mut samples = Vec::with_capacity(self.get_duration());
+        let mut block = vec![0.; self.hop_size];
+
+        loop {
+            let read = self.do_(block.as_mut_slice())?;
+            samples.extend_from_slice(&block[..read]);
+            if read < self.hop_size {
+                break;
+            }
+        }
+
+        Ok(samples)
+    }
+
+    /**
+     * Close source
+     *
+     * The source is also closed when dropped.
+     */
+    pub fn close(&mut self) -> Status {
+        if 0 == unsafe { ffi::aubio_source_close(self.source) } {
+            Ok(())
+        } else {
+            Err(Error::InvalidArg)
+        }
+    }
+}
+
+impl MediaSource for Source {
+    type Error = Error;
+
+    fn sample_rate(&self) -> u32 {
+        self.get_samplerate()
+    }
+
+    fn channels(&self) -> usize {
+        self.get_channels()
+    }
+
+    /**
+     * Read the next block of `hop_size` samples
+     *
+     * The last block can be shorter.
+     */
+    fn read_frame(&mut self) -> Result<Option<&[Smpl]>> {
+        let mut output: FVecMut = self.block.as_mut_slice().into();
+        let mut read = 0;
+
+        unsafe {
+            ffi::aubio_source_do(self.source, output.as_mut_ptr(), &mut read);
+        }
+
+        Ok(match read as usize {
+            0 => None,
+            read => Some(&self.block[..read]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_source() {
+        assert!(Source::new("/nonexistent/file.wav", 0, 512).is_err());
+        assert_eq!(
+            Source::new("invalid\0path.wav", 0, 512).err(),
+            Some(Error::InvalidArg)
+        );
+    }
+}
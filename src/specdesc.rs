@@ -1,10 +1,10 @@
 use crate::{
-    check_init, ffi,
-    vec::{CVec, FVecMut},
+    check_buf_size, ffi, try_init,
+    vec::{CVec, FVecMut, Memory},
     AsNativeStr, Error, Result, Smpl, Status,
 };
 
-use std::{
+use core::{
     fmt::{Display, Formatter, Result as FmtResult},
     str::FromStr,
 };
@@ -22,6 +22,8 @@ pub trait SpecMethod: AsNativeStr {}
  * Geoffroy Peeters, A large set of audio features for sound description (similarity and classification) in the CUIDADO project, CUIDADO I.S.T. Project Report 2004 ([pdf](http://www.ircam.fr/anasyn/peeters/ARTICLES/Peeters_2003_cuidadoaudiofeatures.pdf))
  */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum SpecShape {
     /**
      * Spectral centroid
@@ -135,6 +137,8 @@ impl FromStr for SpecShape {
  */
 pub struct SpecDesc {
     specdesc: *mut ffi::aubio_specdesc_t,
+    method: &'static str,
+    buf_size: usize,
 }
 
 impl Drop for SpecDesc {
@@ -143,6 +147,9 @@ impl Drop for SpecDesc {
     }
 }
 
+unsafe impl Send for SpecDesc {}
+unsafe impl Sync for SpecDesc {}
+
 impl SpecDesc {
     /**
      * Creation of a spectral description object
@@ -151,18 +158,44 @@ impl SpecDesc {
      * - `buf_size` Length of the input spectrum frame
      */
     pub fn new(method: impl SpecMethod, buf_size: usize) -> Result<Self> {
-        let specdesc =
-            unsafe { ffi::new_aubio_specdesc(method.as_native_cstr(), buf_size as ffi::uint_t) };
+        Self::from_native(method.as_native_str(), buf_size)
+    }
 
-        check_init(specdesc)?;
+    /**
+     * Create new spectral description object with the same parameters
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        Self::from_native(self.method, self.buf_size)
+    }
 
-        Ok(Self { specdesc })
+    fn from_native(method: &'static str, buf_size: usize) -> Result<Self> {
+        check_buf_size("buf_size", buf_size)?;
+
+        let specdesc = try_init(|| unsafe {
+            ffi::new_aubio_specdesc(method.as_ptr() as *const _, buf_size as ffi::uint_t)
+        })?;
+
+        Ok(Self {
+            specdesc,
+            method,
+            buf_size,
+        })
+    }
+
+    /**
+     * Get buffer size
+     */
+    pub fn get_buf_size(&self) -> usize {
+        self.buf_size
     }
 
     /**
      * Execute spectral description function on a spectral frame
      *
      * Generic function to compute spectral description.
+     *
+     * - `fftgrain` Input spectrum (`buf_size / 2 + 1` bins)
+     * - `desc` Output descriptor of size 1
      */
     pub fn do_<'i, 'o, I, O>(&mut self, fftgrain: I, desc: O) -> Status
     where
@@ -172,7 +205,9 @@ impl SpecDesc {
         let fftgrain = fftgrain.into();
         let mut desc = desc.into();
 
+        fftgrain.check_size(self.buf_size)?;
         desc.check_size(1)?;
+        fftgrain.check_overlap(&desc)?;
 
         unsafe {
             ffi::aubio_specdesc_do(self.specdesc, fftgrain.as_ptr(), desc.as_mut_ptr());
@@ -245,4 +280,14 @@ mod test {
         let mut o = SpecDesc::new(SpecShape::Rolloff, WIN).unwrap();
         o.do_(in_.as_ref(), out.as_mut()).unwrap();
     }
+
+    #[cfg(not(feature = "unchecked-size"))]
+    #[test]
+    fn test_wrong_size() {
+        let mut o = SpecDesc::new(OnsetMode::Hfc, 1024).unwrap();
+
+        assert_eq!(o.get_buf_size(), 1024);
+        assert_eq!(o.do_result(carr!(512).as_ref()), Err(Error::MismatchSize));
+        assert!(o.do_result(carr!(1024).as_ref()).is_ok());
+    }
 }
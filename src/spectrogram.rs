@@ -0,0 +1,238 @@
+use crate::{
+    vec::{FMatBuf, FVec},
+    PVoc, Result, Smpl, Status, WindowType,
+};
+
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/// The lowest level of spectrogram in dB scale
+const MIN_DB: Smpl = -120.0;
+
+/**
+ * Spectrogram builder
+ *
+ * Consumes input samples, computes magnitude spectrum for each hop using
+ * phase vocoder and accumulates frames into a matrix where each row
+ * contains `win_size / 2 + 1` bins of single frame.
+ */
+pub struct Spectrogram {
+    pvoc: PVoc,
+    grain: Vec<Smpl>,
+    buffer: Vec<Smpl>,
+    filled: usize,
+    data: Vec<Smpl>,
+    frames: usize,
+    db: bool,
+}
+
+impl Spectrogram {
+    /**
+     * Create spectrogram builder
+     *
+     * - `win_size` Size of analysis window (and length of the FFT transform)
+     * - `hop_size` Step size between two consecutive frames
+     */
+    pub fn new(win_size: usize, hop_size: usize) -> Result<Self> {
+        let pvoc = PVoc::new(win_size, hop_size)?;
+
+        Ok(Self {
+            pvoc,
+            grain: vec![0.; win_size + 2],
+            buffer: vec![0.; hop_size],
+            filled: 0,
+            data: Vec::new(),
+            frames: 0,
+            db: false,
+        })
+    }
+
+    /**
+     * Select window type
+     */
+    pub fn with_window(mut self, window_type: WindowType) -> Result<Self> {
+        self.set_window(window_type).map(|_| self)
+    }
+
+    /**
+     * Enable or disable dB scaling of magnitudes
+     */
+    pub fn with_db(mut self, enable: bool) -> Self {
+        self.set_db(enable);
+        self
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /**
+     * Get window size
+     */
+    pub fn get_win(&self) -> usize {
+        self.grain.len() - 2
+    }
+
+    /**
+     * Get number of bins in each frame
+     */
+    pub fn get_bins(&self) -> usize {
+        self.get_win() / 2 + 1
+    }
+
+    /**
+     * Get number of accumulated frames
+     */
+    pub fn get_frames(&self) -> usize {
+        self.frames
+    }
+
+    /**
+     * Set window type
+     */
+    pub fn set_window(&mut self, window_type: WindowType) -> Status {
+        self.pvoc.set_window(window_type)
+    }
+
+    /**
+     * Enable or disable dB scaling of magnitudes
+     *
+     * When enabled magnitudes are converted to `20 * log10(norm)` limited
+     * to `-120` dB from below. Default is `false`.
+     */
+    pub fn set_db(&mut self, enable: bool) {
+        self.db = enable;
+    }
+
+    /**
+     * Get dB scaling of magnitudes
+     */
+    pub fn get_db(&self) -> bool {
+        self.db
+    }
+
+    /**
+     * Push input samples
+     *
+     * The input can have any length. The incomplete tail of input
+     * is kept until the next push.
+     */
+    pub fn push<'i, I>(&mut self, input: I) -> Status
+    where
+        I: Into<FVec<'i>>,
+    {
+        let input = input.into();
+        let mut input = input.as_slice();
+        let hop_size = self.get_hop();
+
+        while !input.is_empty() {
+            let count = input.len().min(hop_size - self.filled);
+            self.buffer[self.filled..self.filled + count].copy_from_slice(&input[..count]);
+            self.filled += count;
+            input = &input[count..];
+
+            if self.filled == hop_size {
+                self.filled = 0;
+                self.analyze()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Process buffered samples padded by zeros
+     */
+    pub fn flush(&mut self) -> Status {
+        if self.filled == 0 {
+            return Ok(());
+        }
+
+        for sample in &mut self.buffer[self.filled..] {
+            *sample = 0.;
+        }
+        self.filled = 0;
+
+        self.analyze()
+    }
+
+    /**
+     * Get accumulated magnitudes in row-major order
+     */
+    pub fn as_slice(&self) -> &[Smpl] {
+        &self.data
+    }
+
+    /**
+     * Flush buffered samples and convert into matrix of `frames` rows by `bins` columns
+     */
+    pub fn into_matrix(mut self) -> Result<FMatBuf> {
+        self.flush()?;
+        let bins = self.get_bins();
+        FMatBuf::from_vec(self.frames, bins, self.data)
+    }
+
+    fn analyze(&mut self) -> Status {
+        self.pvoc
+            .do_(self.buffer.as_slice(), self.grain.as_mut_slice())?;
+
+        let bins = self.get_win() / 2 + 1;
+        let norm = &self.grain[..bins];
+
+        if self.db {
+            self.data
+                .extend(norm.iter().map(|&norm| (20.0 * norm.log10()).max(MIN_DB)));
+        } else {
+            self.data.extend_from_slice(norm);
+        }
+        self.frames += 1;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_spectrogram() {
+        let win_size = 512;
+        let hop_size = 256;
+
+        // sine at the frequency of 16th bin
+        let samples = (0..4096)
+            .map(|index| {
+                let phase = 2.0 * std::f64::consts::PI as Smpl * 16.0 * index as Smpl;
+                (phase / win_size as Smpl).sin()
+            })
+            .collect::<Vec<_>>();
+
+        let mut spectrogram = Spectrogram::new(win_size, hop_size)
+            .unwrap()
+            .with_window(WindowType::Hanning)
+            .unwrap()
+            .with_db(true);
+
+        spectrogram.push(&samples[..1000]).unwrap();
+        spectrogram.push(&samples[1000..]).unwrap();
+        assert_eq!(spectrogram.get_frames(), 16);
+
+        let matrix = spectrogram.into_matrix().unwrap();
+        assert_eq!(matrix.height(), 16);
+        assert_eq!(matrix.length(), 257);
+
+        let row = matrix.row(8).unwrap();
+        let peak = (0..row.len())
+            .max_by(|&a, &b| row[a].partial_cmp(&row[b]).unwrap())
+            .unwrap();
+        assert_eq!(peak, 16);
+        assert!(row.iter().all(|&value| value >= -120.0));
+    }
+}
@@ -0,0 +1,485 @@
+use crate::{
+    Dispatcher, Envelope, EnvelopeFrame, MediaError, MediaSource, Note, NoteSegment, NoteSegmenter,
+    Notes, Onset, Pitch, Result, Smpl, Status, Tempo, TemporalFeatures, TemporalFrame,
+};
+
+use core::result::Result as StdResult;
+
+use alloc::{vec, vec::Vec};
+
+/**
+ * Hop-based analysis object
+ *
+ * This trait is implemented by detectors which consume input signal
+ * in frames of fixed size (`hop_size`) and produce single result per frame.
+ */
+pub trait Analyzer {
+    /**
+     * The result of analysis of single frame
+     */
+    type Output;
+
+    /**
+     * Get number of samples in single input frame
+     */
+    fn hop_size(&self) -> usize;
+
+    /**
+     * Analyze single input frame of `hop_size` length
+     */
+    fn analyze(&mut self, input: &[Smpl]) -> Result<Self::Output>;
+
+    /**
+     * Reset state to start analysis of new signal
+     *
+     * The analyzers without state between frames can keep default implementation.
+     */
+    fn reset(&mut self) -> Status {
+        Ok(())
+    }
+}
+
+impl<A: Analyzer + ?Sized> Analyzer for &mut A {
+    type Output = A::Output;
+
+    fn hop_size(&self) -> usize {
+        (**self).hop_size()
+    }
+
+    fn analyze(&mut self, input: &[Smpl]) -> Result<Self::Output> {
+        (**self).analyze(input)
+    }
+
+    fn reset(&mut self) -> Status {
+        (**self).reset()
+    }
+}
+
+impl Analyzer for Onset {
+    type Output = Smpl;
+
+    fn hop_size(&self) -> usize {
+        self.get_hop()
+    }
+
+    fn analyze(&mut self, input: &[Smpl]) -> Result<Self::Output> {
+        self.do_result(input)
+    }
+
+    fn reset(&mut self) -> Status {
+        Onset::reset(self);
+        Ok(())
+    }
+}
+
+impl Analyzer for Tempo {
+    type Output = Smpl;
+
+    fn hop_size(&self) -> usize {
+        self.get_hop()
+    }
+
+    fn analyze(&mut self, input: &[Smpl]) -> Result<Self::Output> {
+        self.do_result(input)
+    }
+
+    fn reset(&mut self) -> Status {
+        Tempo::reset(self)
+    }
+}
+
+impl Analyzer for Pitch {
+    type Output = Smpl;
+
+    fn hop_size(&self) -> usize {
+        self.get_hop()
+    }
+
+    fn analyze(&mut self, input: &[Smpl]) -> Result<Self::Output> {
+        self.do_result(input)
+    }
+
+    fn reset(&mut self) -> Status {
+        Pitch::reset(self)
+    }
+}
+
+impl Analyzer for Notes {
+    type Output = Vec<Note>;
+
+    fn hop_size(&self) -> usize {
+        self.get_hop()
+    }
+
+    fn analyze(&mut self, input: &[Smpl]) -> Result<Self::Output> {
+        self.do_result(input)
+    }
+
+    fn reset(&mut self) -> Status {
+        Notes::reset(self)
+    }
+}
+
+impl<'a> Analyzer for Dispatcher<'a> {
+    type Output = ();
+
+    fn hop_size(&self) -> usize {
+        self.get_hop()
+    }
+
+    fn analyze(&mut self, input: &[Smpl]) -> Result<Self::Output> {
+        self.do_(input)
+    }
+
+    fn reset(&mut self) -> Status {
+        Dispatcher::reset(self)
+    }
+}
+
+impl Analyzer for Envelope {
+    type Output = EnvelopeFrame;
+
+    fn hop_size(&self) -> usize {
+        self.get_hop()
+    }
+
+    fn analyze(&mut self, input: &[Smpl]) -> Result<Self::Output> {
+        self.do_result(input)
+    }
+
+    fn reset(&mut self) -> Status {
+        Envelope::reset(self);
+        Ok(())
+    }
+}
+
+impl Analyzer for TemporalFeatures {
+    type Output = TemporalFrame;
+
+    fn hop_size(&self) -> usize {
+        self.get_hop()
+    }
+
+    fn analyze(&mut self, input: &[Smpl]) -> Result<Self::Output> {
+        self.do_result(input)
+    }
+}
+
+impl Analyzer for NoteSegmenter {
+    type Output = Option<NoteSegment>;
+
+    fn hop_size(&self) -> usize {
+        self.get_hop()
+    }
+
+    fn analyze(&mut self, input: &[Smpl]) -> Result<Self::Output> {
+        self.do_result(input)
+    }
+
+    fn reset(&mut self) -> Status {
+        NoteSegmenter::reset(self)
+    }
+}
+
+/**
+ * Streaming front-end for analysis objects
+ *
+ * Accepts input chunks of any length (like packets produced by decoders),
+ * slices it into frames of `hop_size` samples and runs the wrapped analyzer
+ * on each complete frame. The incomplete tail of input is kept until the
+ * next push.
+ */
+pub struct StreamingAnalyzer<A> {
+    analyzer: A,
+    buffer: Vec<Smpl>,
+    filled: usize,
+    frames: usize,
+}
+
+impl<A: Analyzer> StreamingAnalyzer<A> {
+    /**
+     * Wrap analyzer
+     */
+    pub fn new(analyzer: A) -> Self {
+        let buffer = vec![0.; analyzer.hop_size()];
+
+        Self {
+            analyzer,
+            buffer,
+            filled: 0,
+            frames: 0,
+        }
+    }
+
+    /**
+     * Push input samples
+     *
+     * Returns results for all frames which have been completed by this input.
+     */
+    pub fn push(&mut self, input: &[Smpl]) -> Result<Vec<A::Output>> {
+        let mut results = Vec::new();
+        self.push_with(input, |output| results.push(output))?;
+        Ok(results)
+    }
+
+    /**
+     * Push input samples and pass results to callback
+     *
+     * Unlike `push()` this method does not allocate memory.
+     */
+    pub fn push_with<F>(&mut self, input: &[Smpl], mut func: F) -> Result<()>
+    where
+        F: FnMut(A::Output),
+    {
+        self.push_with_position(input, |_, output| func(output))
+    }
+
+    /**
+     * Push input samples and pass positions of frames with results to callback
+     *
+     * The position of frame is counted in samples from the start of signal
+     * (or from the last `reset()`).
+     */
+    pub fn push_with_position<F>(&mut self, mut input: &[Smpl], mut func: F) -> Result<()>
+    where
+        F: FnMut(usize, A::Output),
+    {
+        let hop_size = self.buffer.len();
+
+        if hop_size == 0 {
+            return Ok(());
+        }
+
+        if self.filled > 0 {
+            let count = input.len().min(hop_size - self.filled);
+            self.buffer[self.filled..self.filled + count].copy_from_slice(&input[..count]);
+            self.filled += count;
+            input = &input[count..];
+
+            if self.filled < hop_size {
+                return Ok(());
+            }
+
+            self.filled = 0;
+            let output = self.analyzer.analyze(&self.buffer)?;
+            let position = self.get_position();
+            self.frames += 1;
+            func(position, output);
+        }
+
+        let mut frames = input.chunks_exact(hop_size);
+
+        for frame in &mut frames {
+            let output = self.analyzer.analyze(frame)?;
+            let position = self.get_position();
+            self.frames += 1;
+            func(position, output);
+        }
+
+        let rest = frames.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.filled = rest.len();
+
+        Ok(())
+    }
+
+    /**
+     * Read all frames of media source and pass results to callback
+     *
+     * The buffered samples is not flushed at the end of media,
+     * so call `flush()` to process the trailing samples.
+     */
+    pub fn push_source<S, F>(
+        &mut self,
+        mut source: S,
+        mut func: F,
+    ) -> StdResult<(), MediaError<S::Error>>
+    where
+        S: MediaSource,
+        F: FnMut(A::Output),
+    {
+        while let Some(frame) = source.read_frame().map_err(MediaError::Media)? {
+            self.push_with(frame, &mut func)?;
+        }
+        Ok(())
+    }
+
+    /**
+     * Process buffered samples padded by zeros
+     *
+     * Returns the result for last incomplete frame if there are any buffered samples.
+     */
+    pub fn flush(&mut self) -> Result<Option<A::Output>> {
+        if self.filled == 0 {
+            return Ok(None);
+        }
+
+        for sample in &mut self.buffer[self.filled..] {
+            *sample = 0.;
+        }
+        self.filled = 0;
+        let output = self.analyzer.analyze(&self.buffer)?;
+        self.frames += 1;
+
+        Ok(Some(output))
+    }
+
+    /**
+     * Reset wrapped analyzer and discard buffered samples
+     *
+     * Use it to analyze the next signal from the beginning.
+     */
+    pub fn reset(&mut self) -> Status {
+        self.analyzer.reset()?;
+        self.filled = 0;
+        self.frames = 0;
+        Ok(())
+    }
+
+    /**
+     * Get number of samples which is waiting for the frame completion
+     */
+    pub fn get_pending(&self) -> usize {
+        self.filled
+    }
+
+    /**
+     * Get number of processed frames
+     */
+    pub fn get_frames(&self) -> usize {
+        self.frames
+    }
+
+    /**
+     * Get the position of the current frame in samples
+     */
+    pub fn get_position(&self) -> usize {
+        self.frames * self.buffer.len()
+    }
+
+    /**
+     * Get reference to wrapped analyzer
+     */
+    pub fn get_ref(&self) -> &A {
+        &self.analyzer
+    }
+
+    /**
+     * Get mutable reference to wrapped analyzer
+     */
+    pub fn get_mut(&mut self) -> &mut A {
+        &mut self.analyzer
+    }
+
+    /**
+     * Unwrap analyzer
+     */
+    pub fn into_inner(self) -> A {
+        self.analyzer
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::testing::Sum;
+    use crate::*;
+
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn test_thread_safety() {
+        assert_send::<StreamingAnalyzer<Onset>>();
+        assert_send::<StreamingAnalyzer<Tempo>>();
+        assert_send::<StreamingAnalyzer<Pitch>>();
+        assert_send::<StreamingAnalyzer<Notes>>();
+        assert_send::<PVoc>();
+        assert_send::<FFT>();
+        assert_send::<SpectralWhitening>();
+
+        assert_sync::<Onset>();
+        assert_sync::<Pitch>();
+        assert_sync::<Notes>();
+        assert_sync::<PVoc>();
+        assert_sync::<FFT>();
+        assert_sync::<Filter>();
+        assert_sync::<FilterBank>();
+        assert_sync::<MFCC>();
+        assert_sync::<Resampler>();
+        assert_sync::<SpecDesc>();
+        assert_sync::<Tss>();
+        assert_sync::<SpectralWhitening>();
+    }
+
+    #[test]
+    fn test_streaming() {
+        let mut stream = StreamingAnalyzer::new(Sum(4));
+
+        assert_eq!(stream.push(&[1.0, 1.0, 1.0]).unwrap(), vec![]);
+        assert_eq!(stream.get_pending(), 3);
+
+        assert_eq!(
+            stream.push(&[1.0, 2.0, 2.0, 2.0, 2.0, 3.0]).unwrap(),
+            vec![4.0, 8.0]
+        );
+        assert_eq!(stream.get_pending(), 1);
+        assert_eq!(stream.get_frames(), 2);
+
+        assert_eq!(stream.push(&[]).unwrap(), vec![]);
+        assert_eq!(stream.flush().unwrap(), Some(3.0));
+        assert_eq!(stream.flush().unwrap(), None);
+        assert_eq!(stream.get_position(), 12);
+
+        let mut results = Vec::new();
+        stream
+            .push_with_position(&[1.0; 10], |position, output| {
+                results.push((position, output))
+            })
+            .unwrap();
+        assert_eq!(results, vec![(12, 4.0), (16, 4.0)]);
+        assert_eq!(stream.get_pending(), 2);
+
+        stream.reset().unwrap();
+        assert_eq!(stream.get_pending(), 0);
+        assert_eq!(stream.get_position(), 0);
+    }
+
+    #[test]
+    fn test_streaming_error() {
+        let mut stream = StreamingAnalyzer::new(Sum(2));
+        let mut results = Vec::new();
+
+        // the failed frame is not counted
+        assert_eq!(
+            stream.push_with_position(&[1.0, 1.0, Smpl::NAN, 1.0, 1.0, 1.0], |position, output| {
+                results.push((position, output))
+            }),
+            Err(Error::InvalidArg)
+        );
+        assert_eq!(results, vec![(0, 2.0)]);
+        assert_eq!(stream.get_frames(), 1);
+        assert_eq!(stream.get_position(), 2);
+
+        stream
+            .push_with_position(&[2.0], |_, _| unreachable!())
+            .unwrap();
+        assert_eq!(
+            stream.push_with_position(&[Smpl::NAN], |_, _| unreachable!()),
+            Err(Error::InvalidArg)
+        );
+        assert_eq!(stream.get_position(), 2);
+
+        stream
+            .push_with_position(&[3.0, 3.0, 3.0], |position, output| {
+                results.push((position, output))
+            })
+            .unwrap();
+        assert_eq!(results, vec![(0, 2.0), (2, 6.0)]);
+        assert_eq!(stream.flush(), Ok(Some(3.0)));
+        assert_eq!(stream.get_position(), 6);
+
+        stream.push(&[Smpl::NAN]).unwrap();
+        assert_eq!(stream.flush(), Err(Error::InvalidArg));
+        assert_eq!(stream.get_position(), 6);
+    }
+}
@@ -0,0 +1,515 @@
+/*!
+ * Music structure segmentation
+ *
+ * The whole signal is split into sections using the self-similarity of its features:
+ *
+ * 1. MFCC and chroma features are computed for each hop and averaged over blocks,
+ * 2. the self-similarity matrix of blocks is computed using cosine similarity,
+ * 3. the novelty curve is computed by correlating the checkerboard kernel along
+ *    the diagonal of self-similarity matrix,
+ * 4. the peaks of novelty curve become section boundaries,
+ * 5. the sections with similar mean features receive the same label.
+ */
+
+use crate::{vec::FMatBuf, Chroma, Error, PVoc, Result, Smpl, CHROMA_SIZE, MFCC};
+
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/// Number of mel filters used for MFCC
+const N_FILTERS: usize = 40;
+
+/**
+ * Structure segmentation options
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct StructureOptions {
+    /**
+     * Size of analysis buffer
+     */
+    pub buf_size: usize,
+
+    /**
+     * Number of samples between two consecutive analysis frames
+     */
+    pub hop_size: usize,
+
+    /**
+     * Number of MFCC coefficients (without the first one), `0` to disable
+     */
+    pub n_coeffs: usize,
+
+    /**
+     * Use chroma features
+     */
+    pub chroma: bool,
+
+    /**
+     * Length of feature block in seconds
+     */
+    pub block_s: Smpl,
+
+    /**
+     * Width of checkerboard kernel in seconds
+     */
+    pub kernel_s: Smpl,
+
+    /**
+     * Minimum length of section in seconds
+     */
+    pub min_segment_s: Smpl,
+
+    /**
+     * Minimum novelty of boundary relative to the highest novelty
+     */
+    pub threshold: Smpl,
+
+    /**
+     * Minimum cosine similarity of sections which get the same label
+     */
+    pub label_similarity: Smpl,
+}
+
+impl Default for StructureOptions {
+    fn default() -> Self {
+        Self {
+            buf_size: 2048,
+            hop_size: 1024,
+            n_coeffs: 12,
+            chroma: true,
+            block_s: 0.5,
+            kernel_s: 16.0,
+            min_segment_s: 4.0,
+            threshold: 0.2,
+            label_similarity: 0.9,
+        }
+    }
+}
+
+/**
+ * Detected section
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StructureSegment {
+    /**
+     * Start position in samples
+     */
+    pub start: usize,
+
+    /**
+     * End position in samples
+     */
+    pub end: usize,
+
+    /**
+     * Label of section
+     *
+     * The sections with the same label are similar. The labels are
+     * numbered in order of first appearance starting from `0`.
+     */
+    pub label: usize,
+}
+
+/**
+ * Compute features of blocks
+ *
+ * - `samples` Mono input signal
+ * - `sample_rate` Sampling rate of the signal
+ * - `options` Segmentation options
+ *
+ * Returns matrix which has a row of standardized features for each block.
+ */
+pub fn block_features(
+    samples: &[Smpl],
+    sample_rate: u32,
+    options: &StructureOptions,
+) -> Result<FMatBuf> {
+    if options.hop_size == 0 || (options.n_coeffs == 0 && !options.chroma) {
+        return Err(Error::InvalidArg);
+    }
+
+    let n_mfcc = if options.n_coeffs > 0 {
+        options.n_coeffs + 1
+    } else {
+        0
+    };
+    let n_chroma = if options.chroma { CHROMA_SIZE } else { 0 };
+    let size = options.n_coeffs + n_chroma;
+
+    let mut pvoc = PVoc::new(options.buf_size, options.hop_size)?;
+    let mut mfcc = if n_mfcc > 0 {
+        Some(MFCC::new(options.buf_size, N_FILTERS, n_mfcc, sample_rate)?)
+    } else {
+        None
+    };
+    let mut chroma = if options.chroma {
+        Some(Chroma::new(
+            options.buf_size,
+            options.hop_size,
+            sample_rate,
+        )?)
+    } else {
+        None
+    };
+
+    let block_frames = block_frames(sample_rate, options);
+
+    let mut grain = vec![0.; options.buf_size + 2];
+    let mut coeffs = vec![0.; n_mfcc];
+    let mut frame = vec![0.; options.hop_size];
+    let mut block = vec![0.; size];
+    let mut frames = 0;
+    let mut data = Vec::new();
+
+    for chunk in samples.chunks(options.hop_size) {
+        frame[..chunk.len()].copy_from_slice(chunk);
+        frame[chunk.len()..]
+            .iter_mut()
+            .for_each(|sample| *sample = 0.0);
+
+        if let Some(mfcc) = &mut mfcc {
+            pvoc.do_(frame.as_slice(), grain.as_mut_slice())?;
+            mfcc.do_(grain.as_slice(), coeffs.as_mut_slice())?;
+            // the first coefficient reflects the energy only
+            for (value, coeff) in block.iter_mut().zip(&coeffs[1..]) {
+                *value += coeff;
+            }
+        }
+
+        if let Some(chroma) = &mut chroma {
+            let values = chroma.do_result(frame.as_slice())?;
+            let total = values.iter().sum::<Smpl>();
+            if total > 0.0 {
+                for (value, class) in block[options.n_coeffs..].iter_mut().zip(&values) {
+                    *value += class / total;
+                }
+            }
+        }
+
+        frames += 1;
+
+        if frames == block_frames {
+            data.extend(block.iter().map(|value| value / frames as Smpl));
+            block.iter_mut().for_each(|value| *value = 0.0);
+            frames = 0;
+        }
+    }
+
+    if frames > 0 {
+        data.extend(block.iter().map(|value| value / frames as Smpl));
+    }
+
+    standardize(&mut data, size);
+
+    FMatBuf::from_vec(data.len() / size, size, data)
+}
+
+/**
+ * Compute self-similarity matrix
+ *
+ * - `features` Feature matrix which has a row for each frame
+ *
+ * Returns square matrix of cosine similarities between rows.
+ */
+pub fn self_similarity(features: &FMatBuf) -> Result<FMatBuf> {
+    let rows = normalized_rows(features);
+    let height = rows.len();
+
+    let mut data = vec![0.; height * height];
+
+    for (i, a) in rows.iter().enumerate() {
+        for (j, b) in rows.iter().enumerate().skip(i) {
+            let similarity = dot(a, b);
+            data[i * height + j] = similarity;
+            data[j * height + i] = similarity;
+        }
+    }
+
+    FMatBuf::from_vec(height, height, data)
+}
+
+/**
+ * Compute novelty curve of self-similarity matrix
+ *
+ * - `ssm` Self-similarity matrix
+ * - `kernel_size` Width of Gaussian-tapered checkerboard kernel, in frames
+ *
+ * The values are high at the frames where the similar regions change.
+ */
+pub fn novelty(ssm: &FMatBuf, kernel_size: usize) -> Vec<Smpl> {
+    let size = ssm.height().min(ssm.length());
+    let half = (kernel_size / 2).max(1) as isize;
+    let sigma = half as Smpl / 2.0;
+
+    let weight = |offset: isize| {
+        // the center of kernel lies between the frames -1 and 0
+        let distance = offset as Smpl + 0.5;
+        let sign = if offset < 0 { -1.0 } else { 1.0 };
+        sign * (-0.5 * (distance / sigma).powi(2)).exp()
+    };
+    let weights = (-half..half).map(weight).collect::<Vec<_>>();
+
+    (0..size as isize)
+        .map(|center| {
+            let mut value = 0.0;
+
+            for (a, weight_a) in (-half..half).zip(&weights) {
+                let row = match ssm.row((center + a) as usize) {
+                    Some(row) if center + a >= 0 => row,
+                    _ => continue,
+                };
+
+                for (b, weight_b) in (-half..half).zip(&weights) {
+                    let column = center + b;
+                    if column >= 0 && (column as usize) < size {
+                        value += weight_a * weight_b * row[column as usize];
+                    }
+                }
+            }
+
+            value
+        })
+        .collect()
+}
+
+/**
+ * Split the whole signal into labeled sections
+ *
+ * - `samples` Mono input signal
+ * - `sample_rate` Sampling rate of the signal
+ * - `options` Segmentation options
+ */
+pub fn segment_structure(
+    samples: &[Smpl],
+    sample_rate: u32,
+    options: StructureOptions,
+) -> Result<Vec<StructureSegment>> {
+    if samples.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let features = block_features(samples, sample_rate, &options)?;
+    let ssm = self_similarity(&features)?;
+
+    let block_size = block_frames(sample_rate, &options) * options.hop_size;
+    let to_blocks = |seconds: Smpl| {
+        (seconds * sample_rate as Smpl / block_size as Smpl)
+            .round()
+            .max(1.0) as usize
+    };
+
+    let curve = novelty(&ssm, to_blocks(options.kernel_s));
+    let mut bounds = pick_boundaries(&curve, options.threshold, to_blocks(options.min_segment_s));
+    bounds.insert(0, 0);
+    bounds.push(features.height());
+
+    let rows = normalized_rows(&features);
+    let mut labels: Vec<Vec<Smpl>> = Vec::new();
+
+    Ok(bounds
+        .windows(2)
+        .map(|pair| {
+            let mut mean = vec![0.; features.length()];
+            for row in &rows[pair[0]..pair[1]] {
+                for (mean, value) in mean.iter_mut().zip(row) {
+                    *mean += value;
+                }
+            }
+            normalize(&mut mean);
+
+            let label = labels
+                .iter()
+                .enumerate()
+                .map(|(label, other)| (label, dot(&mean, other)))
+                .filter(|(_, similarity)| *similarity >= options.label_similarity)
+                .fold(None, |best: Option<(usize, Smpl)>, current| match best {
+                    Some(best) if best.1 >= current.1 => Some(best),
+                    _ => Some(current),
+                })
+                .map(|(label, _)| label)
+                .unwrap_or_else(|| {
+                    labels.push(mean);
+                    labels.len() - 1
+                });
+
+            StructureSegment {
+                start: pair[0] * block_size,
+                end: (pair[1] * block_size).min(samples.len()),
+                label,
+            }
+        })
+        .collect())
+}
+
+/**
+ * Get number of analysis frames in block
+ */
+fn block_frames(sample_rate: u32, options: &StructureOptions) -> usize {
+    ((options.block_s * sample_rate as Smpl / options.hop_size as Smpl).round() as usize).max(1)
+}
+
+/**
+ * Find peaks of novelty curve separated by at least `min_distance` frames
+ */
+fn pick_boundaries(novelty: &[Smpl], threshold: Smpl, min_distance: usize) -> Vec<usize> {
+    let max = novelty.iter().copied().fold(0.0, Smpl::max);
+
+    if max <= 0.0 {
+        return Vec::new();
+    }
+
+    let mut peaks = (1..novelty.len().saturating_sub(1))
+        .filter(|&index| {
+            let value = novelty[index];
+            value >= threshold * max && value > novelty[index - 1] && value >= novelty[index + 1]
+        })
+        .collect::<Vec<_>>();
+
+    // keep the strongest peaks first
+    peaks.sort_by(|a, b| novelty[*b].partial_cmp(&novelty[*a]).unwrap());
+
+    let mut bounds: Vec<usize> = Vec::new();
+
+    for peak in peaks {
+        if peak >= min_distance
+            && peak + min_distance <= novelty.len()
+            && bounds
+                .iter()
+                .all(|bound| peak.max(*bound) - peak.min(*bound) >= min_distance)
+        {
+            bounds.push(peak);
+        }
+    }
+
+    bounds.sort_unstable();
+    bounds
+}
+
+/**
+ * Standardize each column of row-major data to zero mean and unit variance
+ */
+fn standardize(data: &mut [Smpl], size: usize) {
+    let height = data.len() / size;
+
+    if height == 0 {
+        return;
+    }
+
+    for column in 0..size {
+        let values = || data.iter().skip(column).step_by(size);
+        let mean = values().sum::<Smpl>() / height as Smpl;
+        let variance = values().map(|value| (value - mean).powi(2)).sum::<Smpl>() / height as Smpl;
+        let scale = if variance > 0.0 {
+            1.0 / variance.sqrt()
+        } else {
+            0.0
+        };
+
+        for value in data.iter_mut().skip(column).step_by(size) {
+            *value = (*value - mean) * scale;
+        }
+    }
+}
+
+fn normalized_rows(features: &FMatBuf) -> Vec<Vec<Smpl>> {
+    features
+        .rows()
+        .map(|row| {
+            let mut row = row.to_vec();
+            normalize(&mut row);
+            row
+        })
+        .collect()
+}
+
+fn normalize(values: &mut [Smpl]) {
+    let norm = dot(values, values).sqrt();
+    if norm > 0.0 {
+        values.iter_mut().for_each(|value| *value /= norm);
+    }
+}
+
+fn dot(a: &[Smpl], b: &[Smpl]) -> Smpl {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{pick_boundaries, standardize};
+    use crate::{structure::*, vec::FMatBuf, Smpl};
+
+    #[test]
+    fn test_self_similarity() {
+        let features = FMatBuf::from_vec(3, 2, vec![1.0, 0.0, 2.0, 0.0, 0.0, 1.0]).unwrap();
+        let ssm = self_similarity(&features).unwrap();
+
+        assert_eq!(ssm.height(), 3);
+        assert_eq!(ssm.row(0).unwrap(), &[1.0, 1.0, 0.0]);
+        assert_eq!(ssm.row(2).unwrap(), &[0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_novelty() {
+        // two homogeneous sections of 10 frames
+        let data = (0..20)
+            .flat_map(|i| (0..20).map(move |j| if (i < 10) == (j < 10) { 1.0 } else { 0.0 }))
+            .collect();
+        let ssm = FMatBuf::from_vec(20, 20, data).unwrap();
+
+        let curve = novelty(&ssm, 8);
+        let peak = (0..curve.len())
+            .max_by(|a, b| curve[*a].partial_cmp(&curve[*b]).unwrap())
+            .unwrap();
+        assert_eq!(peak, 10);
+
+        assert_eq!(pick_boundaries(&curve, 0.5, 4), vec![10]);
+    }
+
+    #[test]
+    fn test_standardize() {
+        let mut data = vec![1.0, 5.0, 3.0, 5.0];
+        standardize(&mut data, 2);
+        assert_eq!(data, vec![-1.0, 0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_segment_structure() {
+        let sample_rate = 22050;
+
+        // A-B-A sections of 8 seconds
+        let samples = [220.0, 330.0, 220.0]
+            .iter()
+            .flat_map(|freq| {
+                (0..sample_rate as usize * 8).map(move |index| {
+                    let phase = 2.0 * std::f64::consts::PI as Smpl * freq * index as Smpl;
+                    (phase / sample_rate as Smpl).sin() * 0.5
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let options = StructureOptions {
+            kernel_s: 4.0,
+            min_segment_s: 2.0,
+            ..StructureOptions::default()
+        };
+
+        let segments = segment_structure(&samples, sample_rate, options).unwrap();
+
+        assert_eq!(
+            segments
+                .iter()
+                .map(|segment| segment.label)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 0]
+        );
+        let second = sample_rate as usize;
+        assert!((segments[1].start as isize - 8 * second as isize).abs() <= second as isize);
+        assert_eq!(segments[2].end, samples.len());
+    }
+}
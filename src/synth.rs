@@ -0,0 +1,213 @@
+/*!
+ * Synthesis utilities for verification of analysis results
+ *
+ * With __hound__ feature the click tracks can be written to WAV files directly
+ * to audition the results of beat tracking.
+ */
+
+use crate::Smpl;
+
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/// Duration of single click in seconds
+const CLICK_DURATION: Smpl = 0.03;
+
+/// Peak amplitude of click
+const CLICK_AMPLITUDE: Smpl = 0.5;
+
+/// Frequency of click tone in WAV click tracks
+#[cfg(feature = "hound")]
+const CLICK_FREQ: Smpl = 1000.0;
+
+/**
+ * Render clicks at given times
+ *
+ * - `beat_times` Times of clicks in seconds (like `BeatEvent::time` or `OnsetEvent::time`)
+ * - `sample_rate` Sampling rate of the output signal
+ * - `click_freq` Frequency of click tone in Hz
+ *
+ * Each click is a short sine burst with exponential decay. The output lasts until
+ * the end of the last click and can be mixed with the analyzed signal using `mix_clicks()`.
+ */
+pub fn render_clicks(beat_times: &[Smpl], sample_rate: u32, click_freq: Smpl) -> Vec<Smpl> {
+    let click_length = click_length(sample_rate);
+    let length = beat_times
+        .iter()
+        .map(|time| to_position(*time, sample_rate) + click_length)
+        .max()
+        .unwrap_or(0);
+
+    let mut output = vec![0.; length];
+    mix_clicks(&mut output, beat_times, sample_rate, click_freq);
+    output
+}
+
+/**
+ * Mix clicks into existing signal
+ *
+ * - `output` Signal to mix clicks into (like the analyzed signal)
+ * - `beat_times` Times of clicks in seconds
+ * - `sample_rate` Sampling rate of the signal
+ * - `click_freq` Frequency of click tone in Hz
+ *
+ * The clicks which exceed the end of signal are truncated.
+ */
+pub fn mix_clicks(output: &mut [Smpl], beat_times: &[Smpl], sample_rate: u32, click_freq: Smpl) {
+    let click = click(sample_rate, click_freq);
+
+    for &time in beat_times {
+        let start = to_position(time, sample_rate);
+        if start >= output.len() {
+            continue;
+        }
+
+        for (output, value) in output[start..].iter_mut().zip(&click) {
+            *output += value;
+        }
+    }
+}
+
+/**
+ * Write clicks at given times to WAV file
+ *
+ * - `path` Path to output file
+ * - `beat_times` Times of clicks in seconds (like `BeatEvent::time`)
+ * - `sample_rate` Sampling rate of the output
+
+```no_run
+use aubio::{offline::{track_beats, TempoOptions}, synth::write_click_track};
+
+# let samples = vec![0.0; 44100];
+let analysis = track_beats(&samples, 44100, TempoOptions::default()).unwrap();
+let times = analysis.beats.iter().map(|beat| beat.time).collect::<Vec<_>>();
+
+write_click_track("clicks.wav", &times, 44100).unwrap();
+```
+
+ * The output is mono 16-bit WAV which lasts until the end of the last click.
+ */
+#[cfg(feature = "hound")]
+pub fn write_click_track(
+    path: impl AsRef<std::path::Path>,
+    beat_times: &[Smpl],
+    sample_rate: u32,
+) -> Result<(), hound::Error> {
+    write_wav(
+        path,
+        &render_clicks(beat_times, sample_rate, CLICK_FREQ),
+        sample_rate,
+    )
+}
+
+/**
+ * Write source signal mixed with clicks at given times to WAV file
+ *
+ * - `path` Path to output file
+ * - `source` Mono source signal (like the analyzed signal)
+ * - `beat_times` Times of clicks in seconds (like `BeatEvent::time`)
+ * - `sample_rate` Sampling rate of the source signal
+ *
+ * The output is mono 16-bit WAV with the same length as source,
+ * the mixed samples are clipped to the range from `-1` to `1`.
+ */
+#[cfg(feature = "hound")]
+pub fn write_click_mix(
+    path: impl AsRef<std::path::Path>,
+    source: &[Smpl],
+    beat_times: &[Smpl],
+    sample_rate: u32,
+) -> Result<(), hound::Error> {
+    let mut output = source.to_vec();
+    mix_clicks(&mut output, beat_times, sample_rate, CLICK_FREQ);
+    write_wav(path, &output, sample_rate)
+}
+
+#[cfg(feature = "hound")]
+fn write_wav(
+    path: impl AsRef<std::path::Path>,
+    samples: &[Smpl],
+    sample_rate: u32,
+) -> Result<(), hound::Error> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+
+    let mut writer = hound::WavWriter::create(path, spec)?;
+
+    for &sample in samples {
+        writer.write_sample(to_i16(sample))?;
+    }
+
+    writer.finalize()
+}
+
+#[cfg(feature = "hound")]
+fn to_i16(sample: Smpl) -> i16 {
+    (sample.clamp(-1.0, 1.0) * i16::MAX as Smpl).round() as i16
+}
+
+fn click_length(sample_rate: u32) -> usize {
+    (CLICK_DURATION * sample_rate as Smpl).round() as usize
+}
+
+fn to_position(time: Smpl, sample_rate: u32) -> usize {
+    (time.max(0.0) * sample_rate as Smpl).round() as usize
+}
+
+/**
+ * Render single click with exponential decay
+ */
+fn click(sample_rate: u32, click_freq: Smpl) -> Vec<Smpl> {
+    let length = click_length(sample_rate);
+    // decay by 60 dB over the click
+    let decay = (1e-3 as Smpl).powf(1.0 / length.max(1) as Smpl);
+    let step = 2.0 * core::f64::consts::PI as Smpl * click_freq / sample_rate as Smpl;
+
+    (0..length)
+        .map(|index| CLICK_AMPLITUDE * decay.powi(index as i32) * (step * index as Smpl).sin())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::synth::*;
+
+    #[test]
+    fn test_render_clicks() {
+        let clicks = render_clicks(&[0.0, 0.5, 1.0], 1000, 100.0);
+
+        // the last click starts at 1000 and lasts 30 samples
+        assert_eq!(clicks.len(), 1030);
+        assert!(clicks[501..530].iter().any(|value| value.abs() > 0.1));
+        assert!(clicks[100..500].iter().all(|value| *value == 0.0));
+        assert!(clicks.iter().all(|value| value.abs() <= 0.5));
+
+        assert!(render_clicks(&[], 1000, 100.0).is_empty());
+    }
+
+    #[test]
+    fn test_mix_clicks() {
+        let mut signal = vec![0.1; 100];
+        mix_clicks(&mut signal, &[0.09, 2.0], 1000, 100.0);
+
+        assert_eq!(signal[..90], [0.1; 90][..]);
+        assert_ne!(signal[91], 0.1);
+    }
+
+    #[cfg(feature = "hound")]
+    #[test]
+    fn test_to_i16() {
+        use super::to_i16;
+
+        assert_eq!(to_i16(0.0), 0);
+        assert_eq!(to_i16(1.5), i16::MAX);
+        assert_eq!(to_i16(-1.5), -i16::MAX);
+    }
+}
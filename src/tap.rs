@@ -0,0 +1,243 @@
+use crate::{
+    worker::{spawn_worker, WorkerEvent},
+    Analyzer, Error, Result, Smpl,
+};
+
+use rodio::{cpal::FromSample, Sample, Source};
+
+use std::{
+    sync::mpsc::{channel, Receiver, Sender},
+    time::Duration,
+};
+
+use alloc::vec::Vec;
+
+/**
+ * Event delivered by playback tap
+ */
+#[derive(Debug)]
+pub enum TapEvent<T> {
+    /**
+     * Result of analysis of single frame
+     */
+    Frame {
+        /// Position of frame in samples from the start of source
+        position: usize,
+        /// Analysis result
+        output: T,
+    },
+
+    /**
+     * Analysis failed, no more frames will be delivered
+     */
+    Error(Error),
+}
+
+impl<T> WorkerEvent<T> for TapEvent<T> {
+    fn frame(position: usize, output: T) -> Self {
+        TapEvent::Frame { position, output }
+    }
+
+    fn error(error: Error) -> Self {
+        TapEvent::Error(error)
+    }
+}
+
+/**
+ * Analyzing wrapper of _rodio_ source
+ *
+ * Passes the audio through unchanged while the down-mixed copy of signal is
+ * analyzed on the dedicated worker thread. The results are delivered through
+ * the channel returned by `AnalyzerTap::new()`.
+ *
+ * Note that the sink pulls the samples ahead of the actual playback, so the
+ * events should be scheduled using its `position` rather than time of arrival.
+ * The analyzer is created for the initial sampling rate of source.
+ */
+pub struct AnalyzerTap<S> {
+    source: S,
+    samples: Option<Sender<Vec<Smpl>>>,
+    batch: Vec<Smpl>,
+    batch_size: usize,
+    frame_sum: Smpl,
+    frame_len: usize,
+    channels: usize,
+}
+
+impl<S> AnalyzerTap<S>
+where
+    S: Source,
+    S::Item: Sample,
+    Smpl: FromSample<S::Item>,
+{
+    /**
+     * Wrap source
+     *
+     * - `source` Source to play
+     * - `factory` Function which creates the analyzer for given sampling rate
+     *
+     * Returns the wrapped source and the receiver of analysis events.
+     */
+    pub fn new<A, F, T>(source: S, factory: F) -> Result<(Self, Receiver<TapEvent<T>>)>
+    where
+        A: Analyzer<Output = T>,
+        F: FnOnce(u32) -> Result<A> + Send + 'static,
+        T: Send + 'static,
+    {
+        let (events_tx, events) = channel();
+        let (samples, batch_size) =
+            spawn_worker::<_, _, Receiver<_>, _>(source.sample_rate(), factory, events_tx)?;
+
+        Ok((
+            Self {
+                source,
+                samples: Some(samples),
+                batch: Vec::with_capacity(batch_size),
+                batch_size,
+                frame_sum: 0.0,
+                frame_len: 0,
+                channels: 1,
+            },
+            events,
+        ))
+    }
+
+    /**
+     * Get reference to wrapped source
+     */
+    pub fn get_ref(&self) -> &S {
+        &self.source
+    }
+
+    /**
+     * Get mutable reference to wrapped source
+     */
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.source
+    }
+
+    fn feed(&mut self, sample: S::Item) {
+        if self.samples.is_none() {
+            return;
+        }
+
+        if self.frame_len == 0 {
+            // channels count can only change at frame boundaries
+            self.channels = (self.source.channels() as usize).max(1);
+        }
+
+        self.frame_sum += Smpl::from_sample_(sample);
+        self.frame_len += 1;
+
+        if self.frame_len < self.channels {
+            return;
+        }
+
+        self.batch.push(self.frame_sum / self.channels as Smpl);
+        self.frame_sum = 0.0;
+        self.frame_len = 0;
+
+        if self.batch.len() >= self.batch_size {
+            self.send();
+        }
+    }
+
+    fn send(&mut self) {
+        if self.batch.is_empty() {
+            return;
+        }
+
+        let batch = std::mem::replace(&mut self.batch, Vec::with_capacity(self.batch_size));
+
+        if let Some(samples) = &self.samples {
+            if samples.send(batch).is_err() {
+                // analysis stopped
+                self.samples = None;
+            }
+        }
+    }
+}
+
+impl<S> Iterator for AnalyzerTap<S>
+where
+    S: Source,
+    S::Item: Sample,
+    Smpl: FromSample<S::Item>,
+{
+    type Item = S::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.source.next() {
+            Some(sample) => {
+                self.feed(sample);
+                Some(sample)
+            }
+            None => {
+                // the worker finishes analysis when the sender is dropped
+                self.send();
+                self.samples = None;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S> Source for AnalyzerTap<S>
+where
+    S: Source,
+    S::Item: Sample,
+    Smpl: FromSample<S::Item>,
+{
+    fn current_frame_len(&self) -> Option<usize> {
+        self.source.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.source.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.source.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.source.total_duration()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::testing::Sum;
+    use crate::*;
+    use rodio::{buffer::SamplesBuffer, Source};
+
+    #[test]
+    fn test_tap() {
+        let samples = vec![1.0f32, 3.0, 2.0, 2.0, 0.5, 0.5, 1.0, 1.0, 4.0, 0.0];
+        let source = SamplesBuffer::new(2, 44100, samples.clone());
+
+        let (tap, events) = AnalyzerTap::new(source, |_| Ok(Sum(2))).unwrap();
+        assert_eq!(tap.sample_rate(), 44100);
+        assert_eq!(tap.collect::<Vec<_>>(), samples);
+
+        let frames = events
+            .iter()
+            .map(|event| match event {
+                TapEvent::Frame { position, output } => (position, output),
+                TapEvent::Error(error) => panic!("{}", error),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(frames, vec![(0, 4.0), (2, 1.5), (4, 2.0)]);
+    }
+
+    #[test]
+    fn test_tap_init_error() {
+        let source = SamplesBuffer::new(1, 44100, vec![0.0f32; 4]);
+        let result = AnalyzerTap::new(source, |_| Err::<Sum, _>(Error::InvalidArg));
+        assert!(matches!(result, Err(Error::InvalidArg)));
+    }
+}
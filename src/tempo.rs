@@ -1,6 +1,6 @@
 use crate::{
-    check_init, ffi,
-    vec::{FVec, FVecMut},
+    check_params, ffi, try_init,
+    vec::{FVec, FVecMut, Memory},
     AsNativeStr, OnsetMode, Result, Smpl, Status,
 };
 
@@ -9,7 +9,11 @@ use crate::{
  */
 pub struct Tempo {
     tempo: *mut ffi::aubio_tempo_t,
+    method: OnsetMode,
+    buf_size: usize,
     hop_size: usize,
+    sample_rate: u32,
+    tatum_signature: Option<u32>,
 }
 
 impl Drop for Tempo {
@@ -18,6 +22,9 @@ impl Drop for Tempo {
     }
 }
 
+// The `was_tatum()` updates the state of tracker, so it is not `Sync`
+unsafe impl Send for Tempo {}
+
 impl Tempo {
     /**
      * Create tempo detection object
@@ -34,18 +41,25 @@ impl Tempo {
         sample_rate: u32,
     ) -> Result<Self> {
         // TODO: Use `SpecFlux` as default method for Tempo
-        let tempo = unsafe {
+        check_params(buf_size, hop_size, sample_rate)?;
+
+        let tempo = try_init(|| unsafe {
             ffi::new_aubio_tempo(
                 method.as_native_cstr(),
                 buf_size as ffi::uint_t,
                 hop_size as ffi::uint_t,
                 sample_rate as ffi::uint_t,
             )
-        };
-
-        check_init(tempo)?;
-
-        Ok(Self { tempo, hop_size })
+        })?;
+
+        Ok(Self {
+            tempo,
+            method,
+            buf_size,
+            hop_size,
+            sample_rate,
+            tatum_signature: None,
+        })
     }
 
     /**
@@ -108,6 +122,7 @@ impl Tempo {
 
         input.check_size(self.get_hop())?;
         output.check_size(1)?;
+        input.check_overlap(&output)?;
 
         unsafe {
             ffi::aubio_tempo_do(self.tempo, input.as_ptr(), output.as_mut_ptr());
@@ -215,6 +230,7 @@ impl Tempo {
         unsafe {
             ffi::aubio_tempo_set_tatum_signature(self.tempo, signature);
         }
+        self.tatum_signature = Some(signature);
     }
 
     /**
@@ -278,4 +294,51 @@ impl Tempo {
     pub fn get_delay_ms(&self) -> Smpl {
         unsafe { ffi::aubio_tempo_get_delay_ms(self.tempo) }
     }
+
+    /**
+     * Create new tempo detection object with the same parameters
+     *
+     * The state of analysis is not copied.
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        let mut tempo = Self::new(self.method, self.buf_size, self.hop_size, self.sample_rate)?
+            .with_silence(self.get_silence())
+            .with_threshold(self.get_threshold())
+            .with_delay(self.get_delay() as isize);
+        if let Some(signature) = self.tatum_signature {
+            tempo.set_tatum_signature(signature);
+        }
+        Ok(tempo)
+    }
+
+    /**
+     * Reset tempo detection
+     *
+     * The library can't reset the beat tracker, so the new one is created
+     * with the same parameters.
+     */
+    pub fn reset(&mut self) -> Status {
+        *self = self.try_clone()?;
+        Ok(())
+    }
+}
+
+#[cfg(all(test, not(feature = "unchecked-size")))]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_wrong_size() {
+        let mut tempo = Tempo::new(OnsetMode::default(), 1024, 512, 44100).unwrap();
+        let mut output = [0.0; 1];
+        assert_eq!(
+            tempo.do_(&[0.0; 256][..], &mut output[..]),
+            Err(Error::MismatchSize)
+        );
+        assert_eq!(
+            tempo.do_(&[0.0; 512][..], &mut [][..]),
+            Err(Error::MismatchSize)
+        );
+        assert!(tempo.do_(&[0.0; 512][..], &mut output[..]).is_ok());
+    }
 }
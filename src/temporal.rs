@@ -0,0 +1,174 @@
+use crate::{vec::FVec, Error, Result, Smpl};
+
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/**
+ * Temporal features of single frame
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TemporalFrame {
+    /// Zero-crossing rate (see `zero_crossing_rate()`)
+    pub zcr: Smpl,
+    /// Root mean square of signal
+    pub rms: Smpl,
+    /// Level in dB SPL (see `db_spl()`)
+    pub db_spl: Smpl,
+    /// Absolute peak value
+    pub peak: Smpl,
+}
+
+/**
+ * Temporal features extractor
+ *
+ * Computes zero-crossing rate, RMS, dB SPL and peak of each hop in a single
+ * pass over the samples. The values are consistent with `zero_crossing_rate()`,
+ * `level_lin()` and `db_spl()`.
+ */
+#[derive(Debug, Clone)]
+pub struct TemporalFeatures {
+    hop_size: usize,
+}
+
+impl TemporalFeatures {
+    /**
+     * Create temporal features extractor
+     *
+     * - `hop_size` Number of samples in each frame
+     */
+    pub fn new(hop_size: usize) -> Result<Self> {
+        if hop_size == 0 {
+            return Err(Error::InvalidArg);
+        }
+
+        Ok(Self { hop_size })
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.hop_size
+    }
+
+    /**
+     * Compute features of an input signal frame
+     *
+     * - `input` Input signal of size `hop_size`
+     */
+    pub fn do_result<'i, I>(&self, input: I) -> Result<TemporalFrame>
+    where
+        I: Into<FVec<'i>>,
+    {
+        let input = input.into();
+
+        if input.size() != self.hop_size {
+            return Err(Error::MismatchSize);
+        }
+
+        Ok(frame_features(input.as_slice()))
+    }
+
+    /**
+     * Compute features of the whole signal
+     *
+     * The trailing partial frame is padded by zeros.
+     */
+    pub fn process(&self, samples: &[Smpl]) -> Vec<TemporalFrame> {
+        let mut frames = Vec::with_capacity(samples.len().div_ceil(self.hop_size));
+
+        self.process_with(samples, |frame| frames.push(frame));
+        frames
+    }
+
+    /**
+     * Compute features of the whole signal and pass it to callback
+     */
+    pub fn process_with<F>(&self, samples: &[Smpl], mut func: F)
+    where
+        F: FnMut(TemporalFrame),
+    {
+        let mut chunks = samples.chunks_exact(self.hop_size);
+
+        for chunk in &mut chunks {
+            func(frame_features(chunk));
+        }
+
+        let rest = chunks.remainder();
+        if !rest.is_empty() {
+            let mut frame = vec![0.; self.hop_size];
+            frame[..rest.len()].copy_from_slice(rest);
+            func(frame_features(&frame));
+        }
+    }
+}
+
+/**
+ * Compute features of frame in single pass
+ */
+fn frame_features(input: &[Smpl]) -> TemporalFrame {
+    let mut crossings = 0;
+    let mut energy = 0.0;
+    let mut peak: Smpl = 0.0;
+    let mut previous = None;
+
+    for &sample in input {
+        if let Some(previous) = previous {
+            // the same rule as used by aubio
+            if (previous < 0.0) != (sample < 0.0) {
+                crossings += 1;
+            }
+        }
+        previous = Some(sample);
+
+        energy += sample * sample;
+        peak = peak.max(sample.abs());
+    }
+
+    let length = input.len().max(1) as Smpl;
+    let level = energy / length;
+
+    TemporalFrame {
+        zcr: crossings as Smpl / length,
+        rms: level.sqrt(),
+        db_spl: 10.0 * level.log10(),
+        peak,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::frame_features;
+    use crate::*;
+
+    #[test]
+    fn test_frame_features() {
+        let frame = frame_features(&[0.5, -0.5, 0.5, -0.5]);
+
+        assert_eq!(frame.zcr, 0.75);
+        assert_eq!(frame.rms, 0.5);
+        assert!((frame.db_spl - 10.0 * (0.25 as Smpl).log10()).abs() < 1e-5);
+        assert_eq!(frame.peak, 0.5);
+
+        assert_eq!(frame_features(&[0.0; 4]).db_spl, Smpl::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_temporal_features() {
+        let features = TemporalFeatures::new(4).unwrap();
+
+        let frames = features.process(&[1.0, -1.0, 1.0, -1.0, 0.5, 0.5]);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].peak, 1.0);
+        // the trailing frame is padded by zeros
+        assert_eq!(frames[1].zcr, 0.0);
+        assert_eq!(frames[1].rms, (0.125 as Smpl).sqrt());
+
+        assert!(features.do_result([0.0; 3].as_ref()).is_err());
+        assert!(TemporalFeatures::new(0).is_err());
+    }
+}
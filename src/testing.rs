@@ -0,0 +1,24 @@
+use crate::{Analyzer, Error, Result, Smpl};
+
+/**
+ * Analyzer which sums the samples of each frame
+ *
+ * Fails with `Error::InvalidArg` when the frame contains `NaN`.
+ */
+pub(crate) struct Sum(pub usize);
+
+impl Analyzer for Sum {
+    type Output = Smpl;
+
+    fn hop_size(&self) -> usize {
+        self.0
+    }
+
+    fn analyze(&mut self, input: &[Smpl]) -> Result<Smpl> {
+        assert_eq!(input.len(), self.0);
+        if input.iter().any(|sample| sample.is_nan()) {
+            return Err(Error::InvalidArg);
+        }
+        Ok(input.iter().sum())
+    }
+}
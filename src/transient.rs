@@ -0,0 +1,196 @@
+use crate::{
+    vec::{FVec, FVecMut},
+    PVoc, Result, Smpl, Status, Tss,
+};
+
+use alloc::{vec, vec::Vec};
+
+/**
+ * Transient shaping effect
+ *
+ * Splits each spectral frame into transient and steady-state components using `Tss`,
+ * scales both components by its gains and resynthesizes the signal using `PVoc`.
+ * Both components share the phase of input frame, so the magnitudes are mixed directly.
+ *
+ * The output is delayed by the analysis window of phase vocoder.
+ */
+pub struct TransientShaper {
+    pvoc: PVoc,
+    tss: Tss,
+    grain: Vec<Smpl>,
+    trans: Vec<Smpl>,
+    stead: Vec<Smpl>,
+    transient_gain: Smpl,
+    steady_gain: Smpl,
+}
+
+impl TransientShaper {
+    /**
+     * Create transient shaper
+     *
+     * - `buf_size` Size of analysis buffer (and length the FFT transform)
+     * - `hop_size` Step size between two consecutive frames
+     */
+    pub fn new(buf_size: usize, hop_size: usize) -> Result<Self> {
+        let pvoc = PVoc::new(buf_size, hop_size)?;
+        let tss = Tss::new(buf_size, hop_size)?;
+
+        Ok(Self {
+            pvoc,
+            tss,
+            grain: vec![0.; buf_size + 2],
+            trans: vec![0.; buf_size + 2],
+            stead: vec![0.; buf_size + 2],
+            transient_gain: 1.0,
+            steady_gain: 1.0,
+        })
+    }
+
+    /**
+     * Set gain of transient component, on a linear scale
+     */
+    pub fn with_transient_gain(mut self, gain: Smpl) -> Self {
+        self.set_transient_gain(gain);
+        self
+    }
+
+    /**
+     * Set gain of steady-state component, on a linear scale
+     */
+    pub fn with_steady_gain(mut self, gain: Smpl) -> Self {
+        self.set_steady_gain(gain);
+        self
+    }
+
+    /**
+     * Set separation threshold
+     */
+    pub fn with_threshold(mut self, threshold: Smpl) -> Result<Self> {
+        self.set_threshold(threshold).map(|_| self)
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.pvoc.get_hop()
+    }
+
+    /**
+     * Set gain of transient component, on a linear scale
+     *
+     * Use values below `1` to soften attacks and above `1` to emphasize them. Default is `1`.
+     */
+    pub fn set_transient_gain(&mut self, gain: Smpl) {
+        self.transient_gain = gain;
+    }
+
+    /**
+     * Get gain of transient component, on a linear scale
+     */
+    pub fn get_transient_gain(&self) -> Smpl {
+        self.transient_gain
+    }
+
+    /**
+     * Set gain of steady-state component, on a linear scale
+     *
+     * Default is `1`.
+     */
+    pub fn set_steady_gain(&mut self, gain: Smpl) {
+        self.steady_gain = gain;
+    }
+
+    /**
+     * Get gain of steady-state component, on a linear scale
+     */
+    pub fn get_steady_gain(&self) -> Smpl {
+        self.steady_gain
+    }
+
+    /**
+     * Set separation threshold
+     */
+    pub fn set_threshold(&mut self, threshold: Smpl) -> Status {
+        self.tss.set_threshold(threshold)
+    }
+
+    /**
+     * Get access to wrapped separation object
+     */
+    pub fn get_tss_mut(&mut self) -> &mut Tss {
+        &mut self.tss
+    }
+
+    /**
+     * Process input signal frame
+     *
+     * - `input` Input signal (`hop_size` long)
+     * - `output` Output signal (`hop_size` long)
+     */
+    pub fn do_<'i, 'o, I, O>(&mut self, input: I, output: O) -> Status
+    where
+        I: Into<FVec<'i>>,
+        O: Into<FVecMut<'o>>,
+    {
+        self.pvoc.do_(input, self.grain.as_mut_slice())?;
+        self.tss.do_(
+            self.grain.as_slice(),
+            self.trans.as_mut_slice(),
+            self.stead.as_mut_slice(),
+        )?;
+
+        let bins = self.grain.len() / 2;
+        for ((norm, trans), stead) in self.grain[..bins]
+            .iter_mut()
+            .zip(&self.trans[..bins])
+            .zip(&self.stead[..bins])
+        {
+            *norm = trans * self.transient_gain + stead * self.steady_gain;
+        }
+
+        self.pvoc.rdo(self.grain.as_slice(), output)
+    }
+
+    /**
+     * Process the whole signal
+     *
+     * The input is padded by zeros to multiple of `hop_size`, so the output
+     * can be longer than input.
+     */
+    pub fn process(&mut self, samples: &[Smpl]) -> Result<Vec<Smpl>> {
+        let hop_size = self.get_hop();
+        let mut output = vec![0.; samples.len().div_ceil(hop_size) * hop_size];
+        let mut frame = vec![0.; hop_size];
+
+        for (input, output) in samples.chunks(hop_size).zip(output.chunks_mut(hop_size)) {
+            frame[..input.len()].copy_from_slice(input);
+            frame[input.len()..]
+                .iter_mut()
+                .for_each(|sample| *sample = 0.0);
+            self.do_(frame.as_slice(), output)?;
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_transient_shaper() {
+        let mut shaper = TransientShaper::new(1024, 256)
+            .unwrap()
+            .with_transient_gain(0.5)
+            .with_steady_gain(1.0);
+
+        assert_eq!(shaper.get_hop(), 256);
+        assert_eq!(shaper.get_transient_gain(), 0.5);
+
+        let output = shaper.process(&[0.0; 1000]).unwrap();
+        assert_eq!(output.len(), 1024);
+        assert!(output.iter().all(|&sample| sample == 0.0));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    check_window, ffi, try_init,
+    vec::{CVec, CVecMut, Memory},
+    Error, Result, Smpl, Status,
+};
+
+/**
+ * Transient / Steady-state Separation object
+ *
+ * Splits spectral frames computed by `PVoc` into transient and steady-state
+ * components using phase vocoder based criteria.
+ */
+pub struct Tss {
+    tss: *mut ffi::aubio_tss_t,
+    buf_size: usize,
+    hop_size: usize,
+    threshold: Option<Smpl>,
+    alpha: Option<Smpl>,
+    beta: Option<Smpl>,
+}
+
+impl Drop for Tss {
+    fn drop(&mut self) {
+        unsafe { ffi::del_aubio_tss(self.tss) }
+    }
+}
+
+unsafe impl Send for Tss {}
+unsafe impl Sync for Tss {}
+
+impl Tss {
+    /**
+     * Create transient / steady-state separation object
+     *
+     * - `buf_size` Size of spectral frames (length of the FFT transform)
+     * - `hop_size` Step size between two consecutive frames
+     */
+    pub fn new(buf_size: usize, hop_size: usize) -> Result<Self> {
+        check_window("buf_size", buf_size, hop_size)?;
+
+        let tss = try_init(|| unsafe {
+            ffi::new_aubio_tss(buf_size as ffi::uint_t, hop_size as ffi::uint_t)
+        })?;
+
+        Ok(Self {
+            tss,
+            buf_size,
+            hop_size,
+            threshold: None,
+            alpha: None,
+            beta: None,
+        })
+    }
+
+    /**
+     * Create new transient / steady-state separation object with the same parameters
+     *
+     * The state of analysis is not copied.
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        let mut tss = Self::new(self.buf_size, self.hop_size)?;
+
+        if let Some(threshold) = self.threshold {
+            tss.set_threshold(threshold)?;
+        }
+        if let Some(alpha) = self.alpha {
+            tss.set_alpha(alpha)?;
+        }
+        if let Some(beta) = self.beta {
+            tss.set_beta(beta)?;
+        }
+
+        Ok(tss)
+    }
+
+    /**
+     * Set separation threshold
+     */
+    pub fn with_threshold(mut self, threshold: Smpl) -> Result<Self> {
+        self.set_threshold(threshold).map(|_| self)
+    }
+
+    /**
+     * Split spectral frame into transient and steady-state components
+     *
+     * - `input` Input spectral frame (`buf_size` long)
+     * - `trans` Output transient components (`buf_size` long)
+     * - `stead` Output steady-state components (`buf_size` long)
+     */
+    pub fn do_<'i, 't, 's, I, T, S>(&mut self, input: I, trans: T, stead: S) -> Status
+    where
+        I: Into<CVec<'i>>,
+        T: Into<CVecMut<'t>>,
+        S: Into<CVecMut<'s>>,
+    {
+        let input = input.into();
+        let mut trans = trans.into();
+        let mut stead = stead.into();
+
+        input.check_size(self.buf_size)?;
+        trans.check_size(self.buf_size)?;
+        stead.check_size(self.buf_size)?;
+        input.check_overlap(&trans)?;
+        input.check_overlap(&stead)?;
+        trans.check_overlap(&stead)?;
+
+        unsafe {
+            ffi::aubio_tss_do(
+                self.tss,
+                input.as_ptr(),
+                trans.as_mut_ptr(),
+                stead.as_mut_ptr(),
+            );
+        }
+        Ok(())
+    }
+
+    /**
+     * Set separation threshold
+     */
+    pub fn set_threshold(&mut self, threshold: Smpl) -> Status {
+        if 0 == unsafe { ffi::aubio_tss_set_threshold(self.tss, threshold) } {
+            self.threshold = Some(threshold);
+            Ok(())
+        } else {
+            Err(Error::InvalidArg)
+        }
+    }
+
+    /**
+     * Set alpha parameter
+     *
+     * Default is `3`.
+     */
+    pub fn set_alpha(&mut self, alpha: Smpl) -> Status {
+        if 0 == unsafe { ffi::aubio_tss_set_alpha(self.tss, alpha) } {
+            self.alpha = Some(alpha);
+            Ok(())
+        } else {
+            Err(Error::InvalidArg)
+        }
+    }
+
+    /**
+     * Set beta parameter
+     *
+     * Default is `3`.
+     */
+    pub fn set_beta(&mut self, beta: Smpl) -> Status {
+        if 0 == unsafe { ffi::aubio_tss_set_beta(self.tss, beta) } {
+            self.beta = Some(beta);
+            Ok(())
+        } else {
+            Err(Error::InvalidArg)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_tss() {
+        const WIN_S: usize = 32;
+        const HOP_S: usize = WIN_S / 4;
+
+        let mut pv = PVoc::new(WIN_S, HOP_S).unwrap();
+        let mut tss = Tss::new(WIN_S, HOP_S)
+            .unwrap()
+            .with_threshold(0.25)
+            .unwrap();
+        tss.set_alpha(3.0).unwrap();
+        tss.set_beta(3.0).unwrap();
+
+        let input = [1.; HOP_S];
+        let mut grain = carr!(WIN_S);
+        let mut trans = carr!(WIN_S);
+        let mut stead = carr!(WIN_S);
+
+        for _ in 0..4 {
+            pv.do_(input.as_ref(), grain.as_mut()).unwrap();
+            tss.do_(grain.as_ref(), trans.as_mut(), stead.as_mut())
+                .unwrap();
+        }
+    }
+}
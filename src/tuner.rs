@@ -0,0 +1,255 @@
+use crate::{vec::FVec, Pitch, PitchMode, PitchUnit, Result, Smpl};
+
+#[cfg(feature = "no_std")]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/// Names of pitch classes starting from C
+pub(crate) const NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/**
+ * Tuner reading
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TunerNote {
+    /// MIDI number of the nearest note
+    pub midi: i32,
+    /// Name of note like `"C#"`
+    pub name: &'static str,
+    /// Octave number in scientific pitch notation (A4 is `4`)
+    pub octave: i32,
+    /// Signed deviation from the note, in cents
+    pub cents: Smpl,
+    /// Detected frequency, in Hz
+    pub freq: Smpl,
+}
+
+/**
+ * Tuner object
+ *
+ * Wraps `Pitch` and converts the detected frequency into the nearest note and
+ * deviation in cents. To avoid flicker between neighbour notes the current note
+ * is kept until the deviation exceeds a half of semitone by hysteresis.
+ */
+pub struct Tuner {
+    pitch: Pitch,
+    a4: Smpl,
+    min_confidence: Smpl,
+    hysteresis: Smpl,
+    current: Option<i32>,
+}
+
+impl Tuner {
+    /**
+     * Create tuner object
+     *
+     * - `buf_size` Size of the input buffer to analyse
+     * - `hop_size` Step size between two consecutive analysis instant
+     * - `sample_rate` Sampling rate of the signal
+     */
+    pub fn new(buf_size: usize, hop_size: usize, sample_rate: u32) -> Result<Self> {
+        let pitch = Pitch::new(PitchMode::default(), buf_size, hop_size, sample_rate)?
+            .with_unit(PitchUnit::Hz);
+
+        Ok(Self {
+            pitch,
+            a4: 440.0,
+            min_confidence: 0.8,
+            hysteresis: 10.0,
+            current: None,
+        })
+    }
+
+    /**
+     * Set frequency of A4 reference note, in Hz
+     */
+    pub fn with_a4(mut self, a4: Smpl) -> Self {
+        self.set_a4(a4);
+        self
+    }
+
+    /**
+     * Set minimum confidence of detected pitch
+     */
+    pub fn with_min_confidence(mut self, min_confidence: Smpl) -> Self {
+        self.set_min_confidence(min_confidence);
+        self
+    }
+
+    /**
+     * Set note switching hysteresis, in cents
+     */
+    pub fn with_hysteresis(mut self, hysteresis: Smpl) -> Self {
+        self.set_hysteresis(hysteresis);
+        self
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.pitch.get_hop()
+    }
+
+    /**
+     * Set frequency of A4 reference note, in Hz
+     *
+     * Default is `440`.
+     */
+    pub fn set_a4(&mut self, a4: Smpl) {
+        self.a4 = a4;
+    }
+
+    /**
+     * Get frequency of A4 reference note, in Hz
+     */
+    pub fn get_a4(&self) -> Smpl {
+        self.a4
+    }
+
+    /**
+     * Set minimum confidence of detected pitch
+     *
+     * Default is `0.8`.
+     */
+    pub fn set_min_confidence(&mut self, min_confidence: Smpl) {
+        self.min_confidence = min_confidence;
+    }
+
+    /**
+     * Get minimum confidence of detected pitch
+     */
+    pub fn get_min_confidence(&self) -> Smpl {
+        self.min_confidence
+    }
+
+    /**
+     * Set note switching hysteresis, in cents
+     *
+     * Default is `10`.
+     */
+    pub fn set_hysteresis(&mut self, hysteresis: Smpl) {
+        self.hysteresis = hysteresis.max(0.0);
+    }
+
+    /**
+     * Get note switching hysteresis, in cents
+     */
+    pub fn get_hysteresis(&self) -> Smpl {
+        self.hysteresis
+    }
+
+    /**
+     * Get access to wrapped pitch detection object
+     */
+    pub fn get_pitch_mut(&mut self) -> &mut Pitch {
+        &mut self.pitch
+    }
+
+    /**
+     * Execute tuner on an input signal frame
+     *
+     * - `input` Input signal of size `hop_size`
+     *
+     * Returns `None` when no pitch with enough confidence was detected.
+     */
+    pub fn do_result<'i, I>(&mut self, input: I) -> Result<Option<TunerNote>>
+    where
+        I: Into<FVec<'i>>,
+    {
+        let freq = self.pitch.do_result(input)?;
+
+        if freq <= 0.0 || self.pitch.get_confidence() < self.min_confidence {
+            self.current = None;
+            return Ok(None);
+        }
+
+        let (midi, cents) = nearest_note(freq, self.a4, self.current, self.hysteresis);
+        self.current = Some(midi);
+
+        Ok(Some(TunerNote {
+            midi,
+            name: NOTE_NAMES[midi.rem_euclid(12) as usize],
+            octave: midi.div_euclid(12) - 1,
+            cents,
+            freq,
+        }))
+    }
+}
+
+/**
+ * Find nearest MIDI note and deviation in cents keeping the current note within hysteresis
+ */
+fn nearest_note(freq: Smpl, a4: Smpl, current: Option<i32>, hysteresis: Smpl) -> (i32, Smpl) {
+    let pitch = 69.0 + 12.0 * (freq / a4).log2();
+
+    if let Some(current) = current {
+        let cents = (pitch - current as Smpl) * 100.0;
+        if cents.abs() <= 50.0 + hysteresis {
+            return (current, cents);
+        }
+    }
+
+    let midi = pitch.round();
+    (midi as i32, (pitch - midi) * 100.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::nearest_note;
+    use crate::*;
+
+    #[test]
+    fn test_nearest_note() {
+        assert_eq!(nearest_note(440.0, 440.0, None, 10.0), (69, 0.0));
+
+        let (midi, cents) = nearest_note(261.63, 440.0, None, 10.0);
+        assert_eq!(midi, 60);
+        assert!(cents.abs() < 0.1);
+
+        let (midi, cents) = nearest_note(442.0, 442.0 * (2.0 as Smpl).powf(0.55 / 12.0), None, 0.0);
+        assert_eq!(midi, 68);
+        assert!((cents - 45.0).abs() < 0.1);
+
+        // 55 cents above A4 stays on A4 with hysteresis of 10 cents
+        let freq = 440.0 * (2.0 as Smpl).powf(0.55 / 12.0);
+        let (midi, cents) = nearest_note(freq, 440.0, Some(69), 10.0);
+        assert_eq!(midi, 69);
+        assert!((cents - 55.0).abs() < 0.1);
+
+        let (midi, _) = nearest_note(freq, 440.0, Some(69), 0.0);
+        assert_eq!(midi, 70);
+    }
+
+    #[test]
+    fn test_tuner() {
+        let sample_rate = 44100;
+        let hop_size = 512;
+
+        let mut tuner = Tuner::new(2048, hop_size, sample_rate)
+            .unwrap()
+            .with_a4(442.0);
+
+        let samples = (0..hop_size * 16)
+            .map(|index| {
+                let phase = 2.0 * std::f64::consts::PI as Smpl * 442.0 * index as Smpl;
+                (phase / sample_rate as Smpl).sin() * 0.5
+            })
+            .collect::<Vec<_>>();
+
+        let mut note = None;
+        for frame in samples.chunks_exact(hop_size) {
+            note = tuner.do_result(frame).unwrap();
+        }
+
+        let note = note.unwrap();
+        assert_eq!(note.name, "A");
+        assert_eq!(note.octave, 4);
+        assert!(note.cents.abs() < 5.0);
+
+        assert_eq!(tuner.do_result([0.0; 512].as_ref()).unwrap(), None);
+    }
+}
@@ -1,39 +1,156 @@
-use std::{
-    error::Error as StdError,
+use core::{
+    ffi::c_char,
     fmt::{Display, Formatter, Result as FmtResult},
-    os::raw::c_char,
     result::Result as StdResult,
 };
 
+use alloc::string::String;
+
 /**
  * The error type
  */
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Error {
     /**
      * Failed to initialize object
      */
     FailedInit,
 
+    /**
+     * Failed to initialize object with reason reported by library
+     */
+    FailedInitReason(String),
+
     /**
      * Data size mismatched
      */
     MismatchSize,
 
+    /**
+     * Data size is out of range accepted by object
+     */
+    InvalidSize {
+        /// Name of data
+        name: &'static str,
+        /// Passed size
+        size: usize,
+        /// Minimum accepted size
+        min: usize,
+        /// Maximum accepted size
+        max: usize,
+    },
+
     /**
      * Invalid argument
      */
     InvalidArg,
+
+    /**
+     * Invalid parameter of object
+     */
+    InvalidParameter {
+        /// Name of parameter
+        name: &'static str,
+        /// Passed value
+        value: usize,
+        /// Why the value is not acceptable
+        reason: &'static str,
+    },
+
+    /**
+     * Functionality is not compiled in library
+     */
+    NotCompiled {
+        /// Name of missing functionality
+        feature: &'static str,
+    },
+
+    /**
+     * Error reported by backend (like media decoder)
+     */
+    Backend(String),
+}
+
+impl Error {
+    /**
+     * Check that object creation failed
+     */
+    pub fn is_init(&self) -> bool {
+        matches!(self, Error::FailedInit | Error::FailedInitReason(_))
+    }
+
+    /**
+     * Check that size of data is not accepted
+     */
+    pub fn is_size(&self) -> bool {
+        matches!(self, Error::MismatchSize | Error::InvalidSize { .. })
+    }
+
+    /**
+     * Check that argument or parameter is not accepted
+     */
+    pub fn is_invalid(&self) -> bool {
+        matches!(self, Error::InvalidArg | Error::InvalidParameter { .. })
+    }
+
+    /**
+     * Check that functionality is not compiled in library
+     */
+    pub fn is_not_compiled(&self) -> bool {
+        matches!(self, Error::NotCompiled { .. })
+    }
+
+    /**
+     * Check that error is reported by backend
+     */
+    pub fn is_backend(&self) -> bool {
+        matches!(self, Error::Backend(_))
+    }
+
+    /**
+     * Check that error is caused by configuration of objects or library
+     *
+     * Such errors can't be fixed by passing other data.
+     */
+    pub fn is_config(&self) -> bool {
+        self.is_init() || self.is_not_compiled() || matches!(self, Error::InvalidParameter { .. })
+    }
 }
 
-impl StdError for Error {}
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for Error {}
+
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         use self::Error::*;
         match self {
             FailedInit => "creation error".fmt(f),
+            FailedInitReason(reason) => write!(f, "creation error: {}", reason),
             MismatchSize => "data size mismatch".fmt(f),
+            InvalidSize {
+                name,
+                size,
+                min,
+                max,
+            } => {
+                if min == max {
+                    write!(f, "invalid size of {}: {} (expected {})", name, size, min)
+                } else {
+                    write!(
+                        f,
+                        "invalid size of {}: {} (expected from {} to {})",
+                        name, size, min, max
+                    )
+                }
+            }
             InvalidArg => "invalid argument".fmt(f),
+            InvalidParameter {
+                name,
+                value,
+                reason,
+            } => write!(f, "invalid parameter {} = {}: {}", name, value, reason),
+            NotCompiled { feature } => write!(f, "{} is not compiled in library", feature),
+            Backend(error) => write!(f, "backend error: {}", error),
         }
     }
 }
@@ -74,10 +191,109 @@ pub trait AsNativeStr {
     }
 }
 
-pub(crate) fn check_init<T>(ptr: *mut T) -> Status {
+/**
+ * Check that parameter is not zero
+ */
+pub(crate) fn check_non_zero(name: &'static str, value: usize) -> Status {
+    if value == 0 {
+        Err(Error::InvalidParameter {
+            name,
+            value,
+            reason: "should be greater than zero",
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/**
+ * Check that buffer size is at least two samples
+ */
+pub(crate) fn check_buf_size(name: &'static str, value: usize) -> Status {
+    if value < 2 {
+        Err(Error::InvalidParameter {
+            name,
+            value,
+            reason: "should be at least 2",
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/**
+ * Check that hop size is non-zero and not greater than buffer size
+ */
+pub(crate) fn check_window(name: &'static str, buf_size: usize, hop_size: usize) -> Status {
+    check_buf_size(name, buf_size)?;
+    check_non_zero("hop_size", hop_size)?;
+    if hop_size > buf_size {
+        Err(Error::InvalidParameter {
+            name: "hop_size",
+            value: hop_size,
+            reason: "should not be greater than buffer size",
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/**
+ * Check buffer size, hop size and sampling rate of analysis object
+ */
+pub(crate) fn check_params(buf_size: usize, hop_size: usize, sample_rate: u32) -> Status {
+    check_window("buf_size", buf_size, hop_size)?;
+    check_non_zero("sample_rate", sample_rate as usize)
+}
+
+/**
+ * Create object and check that creation succeed
+ *
+ * The error messages which library reports while creating
+ * is attached to returned error.
+ */
+#[cfg(not(feature = "no_std"))]
+pub(crate) fn try_init<T>(create: impl FnOnce() -> *mut T) -> Result<*mut T> {
+    let (ptr, reason) = crate::log::capture_errors(create);
+
+    if ptr.is_null() {
+        Err(reason.map_or(Error::FailedInit, Error::FailedInitReason))
+    } else {
+        Ok(ptr)
+    }
+}
+
+#[cfg(feature = "no_std")]
+pub(crate) fn try_init<T>(create: impl FnOnce() -> *mut T) -> Result<*mut T> {
+    let ptr = create();
+
     if ptr.is_null() {
         Err(Error::FailedInit)
     } else {
-        Ok(())
+        Ok(ptr)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_error_kind() {
+        let error = Error::NotCompiled { feature: "source" };
+        assert!(error.is_not_compiled() && error.is_config());
+        assert_eq!(error.to_string(), "source is not compiled in library");
+
+        let error = Error::InvalidSize {
+            name: "input",
+            size: 3,
+            min: 1,
+            max: 2,
+        };
+        assert!(error.is_size() && !error.is_config());
+
+        assert!(Error::Backend("eof".into()).is_backend());
+        assert!(Error::FailedInit.is_init());
+        assert!(Error::InvalidArg.is_invalid());
     }
 }
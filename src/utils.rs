@@ -1,7 +1,7 @@
 use crate::{
     ffi,
-    vec::{FVec, FVecMut},
-    Smpl,
+    vec::{check_overlap, CVec, CVecMut, FMat, FMatMut, FVec, FVecMut, LVec, LVecMut, Memory},
+    Error, Smpl, Status,
 };
 
 /**
@@ -244,6 +244,33 @@ where
     unsafe { ffi::aubio_level_detection(input.as_ptr(), threshold) }
 }
 
+/**
+ * Release global resources allocated by the library
+ *
+ * When _aubio_ is built with __fftw3__ the FFT plans are kept in a global state
+ * which lives until this function is called. Long-running processes can use it to
+ * release those resources after all analysis objects have been dropped.
+ *
+ * The cleanup waits until the objects which is created by other threads
+ * is completely constructed and blocks creating of new ones until it finished.
+ * The logger itself is not affected, so it can be set or reset in any order
+ * with cleanup.
+ *
+ * # Safety
+ *
+ * - No objects of this crate (`FFT`, `PVoc`, `Onset`, `Tempo`, etc.) should be alive
+ *   at the moment of call, even in other threads.
+ * - With `no_std` no objects should be created concurrently while cleanup is in progress.
+ */
+#[inline]
+pub unsafe fn cleanup() {
+    #[cfg(not(feature = "no_std"))]
+    crate::log::without_creation(|| ffi::aubio_cleanup());
+
+    #[cfg(feature = "no_std")]
+    ffi::aubio_cleanup();
+}
+
 impl<'a> FVec<'a> {
     /**
      * Clamp the values of a vector within the range -abs(max) ..= abs(max)
@@ -260,3 +287,349 @@ impl<'a> FVec<'a> {
         unsafe { ffi::fvec_clamp(input.as_mut_ptr(), absmax) };
     }
 }
+
+impl<'a> LVec<'a> {
+    /**
+     * Set all elements of a double precision vector to a given value
+     *
+     * - `input` Vector to modify
+     * - `value` Value to set elements to
+     */
+    #[inline]
+    pub fn set_all<'i, I>(input: I, value: Smpl)
+    where
+        I: Into<LVecMut<'i>>,
+    {
+        let mut input = input.into();
+        unsafe { ffi::lvec_set_all(input.as_mut_ptr(), value) };
+    }
+
+    /**
+     * Set all elements of a double precision vector to zero
+     *
+     * - `input` Vector to clear
+     */
+    #[inline]
+    pub fn zeros<'i, I>(input: I)
+    where
+        I: Into<LVecMut<'i>>,
+    {
+        let mut input = input.into();
+        unsafe { ffi::lvec_zeros(input.as_mut_ptr()) };
+    }
+
+    /**
+     * Set all elements of a double precision vector to one
+     *
+     * - `input` Vector to modify
+     */
+    #[inline]
+    pub fn ones<'i, I>(input: I)
+    where
+        I: Into<LVecMut<'i>>,
+    {
+        let mut input = input.into();
+        unsafe { ffi::lvec_ones(input.as_mut_ptr()) };
+    }
+}
+
+impl<'a> CVec<'a> {
+    /**
+     * Take logarithmic magnitude of a complex vector
+     *
+     * Each norm element is replaced by `log(1 + lambda * norm)`.
+     *
+     * - `input` Complex vector to process
+     * - `lambda` Compression factor
+     */
+    #[inline]
+    pub fn logmag<'i, I>(input: I, lambda: Smpl)
+    where
+        I: Into<CVecMut<'i>>,
+    {
+        let mut input = input.into();
+        unsafe { ffi::cvec_logmag(input.as_mut_ptr(), lambda) };
+    }
+
+    /**
+     * Set all norm and phas elements of a complex vector to zero
+     *
+     * - `input` Complex vector to clear
+     */
+    #[inline]
+    pub fn zeros<'i, I>(input: I)
+    where
+        I: Into<CVecMut<'i>>,
+    {
+        let mut input = input.into();
+        unsafe { ffi::cvec_zeros(input.as_mut_ptr()) };
+    }
+
+    /**
+     * Set all norm elements of a complex vector to a given value
+     *
+     * - `input` Complex vector to modify
+     * - `value` Value to set norm elements to
+     */
+    #[inline]
+    pub fn norm_set_all<'i, I>(input: I, value: Smpl)
+    where
+        I: Into<CVecMut<'i>>,
+    {
+        let mut input = input.into();
+        unsafe { ffi::cvec_norm_set_all(input.as_mut_ptr(), value) };
+    }
+
+    /**
+     * Set all phas elements of a complex vector to a given value
+     *
+     * - `input` Complex vector to modify
+     * - `value` Value to set phas elements to
+     */
+    #[inline]
+    pub fn phas_set_all<'i, I>(input: I, value: Smpl)
+    where
+        I: Into<CVecMut<'i>>,
+    {
+        let mut input = input.into();
+        unsafe { ffi::cvec_phas_set_all(input.as_mut_ptr(), value) };
+    }
+
+    /**
+     * Copy the norm and phas elements of a complex vector into another one
+     *
+     * - `input` Source complex vector
+     * - `output` Destination complex vector of the same size
+     */
+    #[inline]
+    pub fn copy<'i, 'o, I, O>(input: I, output: O) -> Status
+    where
+        I: Into<CVec<'i>>,
+        O: Into<CVecMut<'o>>,
+    {
+        let input = input.into();
+        let mut output = output.into();
+
+        if input.size() != output.size() {
+            return Err(Error::MismatchSize);
+        }
+        input.check_overlap(&output)?;
+
+        unsafe { ffi::cvec_copy(input.as_ptr(), output.as_mut_ptr()) };
+        Ok(())
+    }
+}
+
+impl<'a> FMat<'a, ()> {
+    /**
+     * Set all elements of a matrix to a given value
+     *
+     * - `input` Matrix to modify
+     * - `value` Value to set elements to
+     */
+    #[inline]
+    pub fn set<'i, I, Y>(input: I, value: Smpl)
+    where
+        I: Into<FMatMut<'i, Y>>,
+    {
+        let mut input = input.into();
+        unsafe { ffi::fmat_set(input.as_mut_ptr(), value) };
+    }
+
+    /**
+     * Set all elements of a matrix to zero
+     *
+     * - `input` Matrix to clear
+     */
+    #[inline]
+    pub fn zeros<'i, I, Y>(input: I)
+    where
+        I: Into<FMatMut<'i, Y>>,
+    {
+        let mut input = input.into();
+        unsafe { ffi::fmat_zeros(input.as_mut_ptr()) };
+    }
+
+    /**
+     * Set all elements of a matrix to one
+     *
+     * - `input` Matrix to modify
+     */
+    #[inline]
+    pub fn ones<'i, I, Y>(input: I)
+    where
+        I: Into<FMatMut<'i, Y>>,
+    {
+        let mut input = input.into();
+        unsafe { ffi::fmat_ones(input.as_mut_ptr()) };
+    }
+
+    /**
+     * Revert order of elements in each row of a matrix
+     *
+     * - `input` Matrix to modify
+     */
+    #[inline]
+    pub fn rev<'i, I, Y>(input: I)
+    where
+        I: Into<FMatMut<'i, Y>>,
+    {
+        let mut input = input.into();
+        unsafe { ffi::fmat_rev(input.as_mut_ptr()) };
+    }
+
+    /**
+     * Copy the elements of a matrix into another one
+     *
+     * - `input` Source matrix
+     * - `output` Destination matrix of the same dimensions
+     */
+    #[inline]
+    pub fn copy<'i, 'o, I, O, X, Y>(input: I, output: O) -> Status
+    where
+        I: Into<FMat<'i, X>>,
+        O: Into<FMatMut<'o, Y>>,
+    {
+        let input = input.into();
+        let mut output = output.into();
+
+        if input.height() != output.height() || input.length() != output.length() {
+            return Err(Error::MismatchSize);
+        }
+        check_overlap(input.memory(), output.memory())?;
+
+        unsafe { ffi::fmat_copy(input.as_ptr(), output.as_mut_ptr()) };
+        Ok(())
+    }
+
+    /**
+     * Compute the product of a matrix by a vector
+     *
+     * - `input` Matrix of `height` rows by `length` columns
+     * - `scale` Vector of `length` elements
+     * - `output` Vector of `height` elements to store the result
+     */
+    #[inline]
+    pub fn vecmul<'i, 's, 'o, I, S, O, X>(input: I, scale: S, output: O) -> Status
+    where
+        I: Into<FMat<'i, X>>,
+        S: Into<FVec<'s>>,
+        O: Into<FVecMut<'o>>,
+    {
+        let input = input.into();
+        let scale = scale.into();
+        let mut output = output.into();
+
+        if scale.size() != input.length() || output.size() != input.height() {
+            return Err(Error::MismatchSize);
+        }
+        check_overlap(input.memory(), output.memory())?;
+        scale.check_overlap(&output)?;
+
+        unsafe { ffi::fmat_vecmul(input.as_ptr(), scale.as_ptr(), output.as_mut_ptr()) };
+        Ok(())
+    }
+}
+
+/**
+ * Get median of values
+ *
+ * The values will be reordered.
+ */
+pub(crate) fn median(values: &mut [Smpl]) -> Option<Smpl> {
+    if values.is_empty() {
+        return None;
+    }
+
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+
+    let middle = values.len() / 2;
+
+    Some(if values.len() % 2 == 1 {
+        values[middle]
+    } else {
+        (values[middle - 1] + values[middle]) * 0.5
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        utils::median,
+        vec::{check_overlap, CVec, FMat, FMatMut},
+        *,
+    };
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(&mut []), None);
+        assert_eq!(median(&mut [3.0, 1.0, 2.0]), Some(2.0));
+        assert_eq!(median(&mut [4.0, 1.0, 2.0, 3.0]), Some(2.5));
+    }
+
+    #[test]
+    fn test_cvec_ops() {
+        let mut spec = carr!(8);
+        let mut copy = carr!(8);
+
+        CVec::norm_set_all(spec.as_mut(), 2.0);
+        CVec::phas_set_all(spec.as_mut(), 1.0);
+        CVec::copy(spec.as_ref(), copy.as_mut()).unwrap();
+        assert_eq!(spec, copy);
+
+        CVec::zeros(spec.as_mut());
+        assert_eq!(spec, carr!(8));
+
+        assert_eq!(
+            CVec::copy(copy.as_ref(), carr!(4).as_mut()),
+            Err(Error::MismatchSize)
+        );
+    }
+
+    #[test]
+    fn test_fmat_ops() {
+        let mut rows = [[0.; 3]; 2];
+        {
+            let mut rows_mut: Vec<&mut [Smpl]> = rows.iter_mut().map(|r| &mut r[..]).collect();
+            FMat::set(rows_mut.as_mut_slice(), 2.0);
+        }
+        assert_eq!(rows, [[2.; 3]; 2]);
+
+        let x: &[&[Smpl]] = &[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]];
+        let mut out = [0.; 2];
+        FMat::vecmul(x, [1.0, 1.0, 1.0].as_ref(), out.as_mut()).unwrap();
+        assert_eq!(out, [6.0, 15.0]);
+
+        assert_eq!(
+            FMat::vecmul(x, [1.0, 1.0].as_ref(), out.as_mut()),
+            Err(Error::MismatchSize)
+        );
+    }
+
+    #[test]
+    fn test_fmat_overlap() {
+        let mut data = [1.; 8];
+        let ptr = data.as_mut_ptr();
+        // aliasing views of the same data
+        let view = |start, len| unsafe { core::slice::from_raw_parts_mut(ptr.add(start), len) };
+
+        let x: &[&[Smpl]] = &[view(0, 2), view(4, 2)];
+
+        assert_eq!(
+            FMat::vecmul(x, [1.0, 1.0].as_ref(), view(5, 2)),
+            Err(Error::InvalidArg)
+        );
+        assert_eq!(
+            FMat::copy(x, [view(2, 2), view(5, 2)].as_mut()),
+            Err(Error::InvalidArg)
+        );
+
+        // the rows placed between the rows of other matrix does not overlap
+        let mut rows = [view(2, 2), view(6, 2)];
+        assert!(check_overlap(
+            FMat::from(x).memory(),
+            FMatMut::from(rows.as_mut()).memory()
+        )
+        .is_ok());
+    }
+}
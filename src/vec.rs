@@ -2,16 +2,95 @@
  * Vector data wrappers
  */
 
-use crate::{ffi, Error, Result, Smpl, Status};
+use crate::{ffi, Error, LSmpl, Result, Smpl, Status};
 
-use std::{
+use core::{
+    fmt,
+    iter::FromIterator,
     marker::PhantomData,
-    ops::{Deref, DerefMut},
+    ops::{Deref, DerefMut, Index, IndexMut, Range},
     ptr::null_mut,
+    slice::{Iter, IterMut, SliceIndex},
 };
 
+use alloc::{vec, vec::Vec};
+
+/**
+ * Memory which is occupied by data of vector
+ *
+ * The library functions does not support overlapping of input and output data,
+ * so the vectors should be checked before passing it to library.
+ */
+pub(crate) trait Memory {
+    /**
+     * Get ranges of addresses of data parts
+     */
+    fn memory(&self) -> [Range<usize>; 2];
+
+    /**
+     * Check that data does not overlap with data of other vector
+     */
+    fn check_overlap(&self, other: &impl Memory) -> Status {
+        check_overlap(self.memory(), other.memory())
+    }
+}
+
+/**
+ * Check that ranges of addresses does not overlap
+ */
+pub(crate) fn check_overlap<A, B>(this: A, other: B) -> Status
+where
+    A: IntoIterator<Item = Range<usize>>,
+    B: IntoIterator<Item = Range<usize>>,
+    B::IntoIter: Clone,
+{
+    let other = other.into_iter();
+
+    for this in this {
+        for other in other.clone() {
+            if this.start < other.end && other.start < this.end {
+                return Err(Error::InvalidArg);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn memory<T>(data: *const T, length: usize) -> Range<usize> {
+    if data.is_null() {
+        0..0
+    } else {
+        let start = data as usize;
+        start..start + length * core::mem::size_of::<T>()
+    }
+}
+
 /**
  * Immutable floating point vector
+ *
+ * The vector borrows the data, so it cannot outlive its owner:
+
+```compile_fail
+use aubio::{vec::FVec, Smpl};
+
+fn escape() -> FVec<'static> {
+    FVec::from(&vec![0.0 as Smpl; 4])
+}
+```
+
+```compile_fail
+use aubio::{vec::FVec, Smpl};
+
+fn escape() -> &'static [Smpl] {
+    FVec::from(&vec![0.0 as Smpl; 4]).as_slice()
+}
+```
+
+```compile_fail
+use aubio::{vec::FVec, Smpl};
+
+let fvec = FVec::from(vec![0.0 as Smpl; 4]);
+```
  */
 #[repr(transparent)]
 pub struct FVec<'a> {
@@ -28,16 +107,40 @@ impl<'a> FVec<'a> {
         self.fvec.length as usize
     }
 
-    #[cfg(not(feature = "check-size"))]
+    /**
+     * Get vector data as slice
+     */
+    pub fn as_slice(&self) -> &[Smpl] {
+        unsafe { core::slice::from_raw_parts(self.fvec.data, self.size()) }
+    }
+
+    /**
+     * Iterate over vector elements
+     */
+    pub fn iter(&self) -> Iter<'_, Smpl> {
+        self.as_slice().iter()
+    }
+
+    /**
+     * Compare vectors with given absolute tolerance
+     *
+     * Returns `true` when both vectors have same size and each pair of elements
+     * differs by not more than `epsilon`.
+     */
+    pub fn approx_eq<'b, T: Into<FVec<'b>>>(&self, other: T, epsilon: Smpl) -> bool {
+        approx_eq_slices(self.as_slice(), other.into().as_slice(), epsilon)
+    }
+
+    #[cfg(feature = "unchecked-size")]
     #[inline]
     pub(crate) fn check_size(&self, _min_size: usize) -> Status {
         Ok(())
     }
 
-    #[cfg(feature = "check-size")]
+    #[cfg(not(feature = "unchecked-size"))]
     #[inline]
     pub(crate) fn check_size(&self, min_size: usize) -> Status {
-        if self.fvec.length < min_size as _ {
+        if (self.fvec.length as usize) < min_size {
             Err(Error::MismatchSize)
         } else {
             Ok(())
@@ -45,8 +148,14 @@ impl<'a> FVec<'a> {
     }
 }
 
-impl<'a, T: AsRef<[Smpl]>> From<T> for FVec<'a> {
-    fn from(data: T) -> Self {
+impl<'a> Memory for FVec<'a> {
+    fn memory(&self) -> [Range<usize>; 2] {
+        [memory(self.fvec.data, self.size()), 0..0]
+    }
+}
+
+impl<'a, T: AsRef<[Smpl]> + ?Sized> From<&'a T> for FVec<'a> {
+    fn from(data: &'a T) -> Self {
         let data = data.as_ref();
         Self {
             fvec: ffi::fvec_t {
@@ -58,6 +167,44 @@ impl<'a, T: AsRef<[Smpl]>> From<T> for FVec<'a> {
     }
 }
 
+impl<'a, I: SliceIndex<[Smpl]>> Index<I> for FVec<'a> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b FVec<'a> {
+    type Item = &'b Smpl;
+    type IntoIter = Iter<'b, Smpl>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, 'b> PartialEq<FVec<'b>> for FVec<'a> {
+    fn eq(&self, other: &FVec<'b>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<'a> PartialEq<[Smpl]> for FVec<'a> {
+    fn eq(&self, other: &[Smpl]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<'a> fmt::Debug for FVec<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FVec")
+            .field("size", &self.size())
+            .field("data", &Truncated(self.as_slice()))
+            .finish()
+    }
+}
+
 /**
  * Mutable floating point vector
  */
@@ -76,16 +223,44 @@ impl<'a> FVecMut<'a> {
         self.fvec.length as usize
     }
 
-    #[cfg(not(feature = "check-size"))]
+    /**
+     * Get vector data as slice
+     */
+    pub fn as_slice(&self) -> &[Smpl] {
+        unsafe { core::slice::from_raw_parts(self.fvec.data, self.size()) }
+    }
+
+    /**
+     * Get vector data as mutable slice
+     */
+    pub fn as_mut_slice(&mut self) -> &mut [Smpl] {
+        unsafe { core::slice::from_raw_parts_mut(self.fvec.data, self.size()) }
+    }
+
+    /**
+     * Iterate over vector elements
+     */
+    pub fn iter(&self) -> Iter<'_, Smpl> {
+        self.as_slice().iter()
+    }
+
+    /**
+     * Iterate over vector elements for modification
+     */
+    pub fn iter_mut(&mut self) -> IterMut<'_, Smpl> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    #[cfg(feature = "unchecked-size")]
     #[inline]
     pub(crate) fn check_size(&self, _min_size: usize) -> Status {
         Ok(())
     }
 
-    #[cfg(feature = "check-size")]
+    #[cfg(not(feature = "unchecked-size"))]
     #[inline]
     pub(crate) fn check_size(&self, min_size: usize) -> Status {
-        if self.fvec.length < min_size as _ {
+        if (self.fvec.length as usize) < min_size {
             Err(Error::MismatchSize)
         } else {
             Ok(())
@@ -93,8 +268,14 @@ impl<'a> FVecMut<'a> {
     }
 }
 
-impl<'a, T: AsMut<[Smpl]>> From<T> for FVecMut<'a> {
-    fn from(mut data: T) -> Self {
+impl<'a> Memory for FVecMut<'a> {
+    fn memory(&self) -> [Range<usize>; 2] {
+        [memory(self.fvec.data, self.size()), 0..0]
+    }
+}
+
+impl<'a, T: AsMut<[Smpl]> + ?Sized> From<&'a mut T> for FVecMut<'a> {
+    fn from(data: &'a mut T) -> Self {
         let data = data.as_mut();
         Self {
             fvec: ffi::fvec_t {
@@ -106,219 +287,951 @@ impl<'a, T: AsMut<[Smpl]>> From<T> for FVecMut<'a> {
     }
 }
 
-/**
- * Immutable complex floating point vector
- */
-#[repr(transparent)]
-pub struct CVec<'a> {
-    cvec: ffi::cvec_t,
-    _pd: PhantomData<&'a ()>,
-}
+impl<'a, I: SliceIndex<[Smpl]>> Index<I> for FVecMut<'a> {
+    type Output = I::Output;
 
-impl<'a> CVec<'a> {
-    pub fn from_parts<T: AsRef<[Smpl]>>(norm: T, phas: T) -> Result<Self> {
-        let norm = norm.as_ref();
-        let phas = phas.as_ref();
-        #[cfg(feature = "check-size")]
-        {
-            if norm.len() != phas.len() {
-                return Err(Error::MismatchSize);
-            }
-        }
-        Ok(Self {
-            cvec: ffi::cvec_t {
-                length: norm.len() as ffi::uint_t,
-                norm: norm.as_ptr() as *mut _,
-                phas: phas.as_ptr() as *mut _,
-            },
-            _pd: PhantomData,
-        })
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
     }
+}
 
-    pub(crate) fn as_ptr(&'a self) -> *const ffi::cvec_t {
-        &self.cvec
+impl<'a, I: SliceIndex<[Smpl]>> IndexMut<I> for FVecMut<'a> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut_slice()[index]
     }
+}
 
-    pub fn size(&self) -> usize {
-        self.cvec.length as usize
-    }
+impl<'a, 'b> IntoIterator for &'b FVecMut<'a> {
+    type Item = &'b Smpl;
+    type IntoIter = Iter<'b, Smpl>;
 
-    pub fn norm(&self) -> &[Smpl] {
-        unsafe { std::slice::from_raw_parts(self.cvec.norm, self.size()) }
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
+}
 
-    pub fn phas(&self) -> &[Smpl] {
-        unsafe { std::slice::from_raw_parts(self.cvec.phas, self.size()) }
+impl<'a, 'b> IntoIterator for &'b mut FVecMut<'a> {
+    type Item = &'b mut Smpl;
+    type IntoIter = IterMut<'b, Smpl>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
+}
 
-    #[cfg(not(feature = "check-size"))]
-    #[inline]
-    pub(crate) fn check_size(&self, _min_size: usize) -> Status {
-        Ok(())
+impl<'a, 'b> PartialEq<FVecMut<'b>> for FVecMut<'a> {
+    fn eq(&self, other: &FVecMut<'b>) -> bool {
+        self.as_slice() == other.as_slice()
     }
+}
 
-    #[cfg(feature = "check-size")]
-    #[inline]
-    pub(crate) fn check_size(&self, min_size: usize) -> Status {
-        if (self.cvec.length - 1) * 2 < min_size as _ {
-            Err(Error::MismatchSize)
-        } else {
-            Ok(())
-        }
+impl<'a> PartialEq<[Smpl]> for FVecMut<'a> {
+    fn eq(&self, other: &[Smpl]) -> bool {
+        self.as_slice() == other
     }
 }
 
-impl<'a, T: AsRef<[Smpl]>> From<T> for CVec<'a> {
-    fn from(data: T) -> Self {
-        let data = data.as_ref();
-        let (norm, phas) = data.split_at(data.len() / 2);
-        Self::from_parts(norm, phas).unwrap()
+impl<'a> fmt::Debug for FVecMut<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FVecMut")
+            .field("size", &self.size())
+            .field("data", &Truncated(self.as_slice()))
+            .finish()
     }
 }
 
 /**
- * Mutable complex floating point vector
+ * Owned floating point vector
+ *
+ * The buffer can be passed to _aubio_ functions as a reference, i.e. `&buf`
+ * converts to `FVec` and `&mut buf` converts to `FVecMut`.
  */
-#[repr(transparent)]
-pub struct CVecMut<'a> {
-    cvec: ffi::cvec_t,
-    _pd: PhantomData<&'a mut ()>,
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FVecBuf {
+    data: Vec<Smpl>,
 }
 
-impl<'a> CVecMut<'a> {
-    pub fn from_parts<T: AsMut<[Smpl]>>(mut norm: T, mut phas: T) -> Result<Self> {
-        let norm = norm.as_mut();
-        let phas = phas.as_mut();
-        #[cfg(feature = "check-size")]
-        {
-            if norm.len() != phas.len() {
-                return Err(Error::MismatchSize);
-            }
+impl FVecBuf {
+    /**
+     * Create a vector filled with zeros
+     */
+    pub fn new(size: usize) -> Self {
+        Self {
+            data: vec![0.; size],
         }
-        Ok(Self {
-            cvec: ffi::cvec_t {
-                length: norm.len() as ffi::uint_t,
-                norm: norm.as_mut_ptr(),
-                phas: phas.as_mut_ptr(),
-            },
-            _pd: PhantomData,
-        })
     }
 
-    pub(crate) fn from_norm(norm: &mut [Smpl]) -> Self {
-        Self {
-            cvec: ffi::cvec_t {
-                length: norm.len() as ffi::uint_t,
-                norm: norm.as_mut_ptr(),
-                phas: null_mut(),
-            },
-            _pd: PhantomData,
-        }
+    /**
+     * Create a vector from signed 16-bit integer samples
+     *
+     * Samples are scaled to the `[-1, 1)` range.
+     */
+    pub fn from_i16(data: &[i16]) -> Self {
+        const SCALE: Smpl = 1.0 / (1u32 << 15) as Smpl;
+        data.iter().map(|&sample| sample as Smpl * SCALE).collect()
     }
 
-    pub(crate) fn from_phas(phas: &mut [Smpl]) -> Self {
-        Self {
-            cvec: ffi::cvec_t {
-                length: phas.len() as ffi::uint_t,
-                norm: null_mut(),
-                phas: phas.as_mut_ptr(),
-            },
-            _pd: PhantomData,
-        }
+    /**
+     * Create a vector from signed 32-bit integer samples
+     *
+     * Samples are scaled to the `[-1, 1)` range.
+     */
+    pub fn from_i32(data: &[i32]) -> Self {
+        const SCALE: f64 = 1.0 / (1u64 << 31) as f64;
+        data.iter()
+            .map(|&sample| (sample as f64 * SCALE) as Smpl)
+            .collect()
     }
 
-    pub(crate) fn as_mut_ptr(&'a mut self) -> *mut ffi::cvec_t {
-        &mut self.cvec
+    /**
+     * Create a vector from unsigned 8-bit integer samples
+     *
+     * Samples are offset by `128` (the zero level of 8-bit audio)
+     * and scaled to the `[-1, 1)` range.
+     */
+    pub fn from_u8(data: &[u8]) -> Self {
+        const SCALE: Smpl = 1.0 / 128.0;
+        data.iter()
+            .map(|&sample| (sample as Smpl - 128.0) * SCALE)
+            .collect()
     }
 
     pub fn size(&self) -> usize {
-        self.cvec.length as usize
+        self.data.len()
     }
 
-    #[cfg(not(feature = "check-size"))]
-    #[inline]
-    pub(crate) fn check_size(&self, _min_size: usize) -> Status {
-        Ok(())
+    /**
+     * Get vector data as slice
+     */
+    pub fn as_slice(&self) -> &[Smpl] {
+        &self.data
     }
 
-    #[cfg(feature = "check-size")]
-    #[inline]
-    pub(crate) fn check_size(&self, min_size: usize) -> Status {
-        if (self.cvec.length - 1) * 2 < min_size as _ {
-            Err(Error::MismatchSize)
-        } else {
-            Ok(())
-        }
+    /**
+     * Get vector data as mutable slice
+     */
+    pub fn as_mut_slice(&mut self) -> &mut [Smpl] {
+        &mut self.data
+    }
+
+    /**
+     * Convert vector into underlying data
+     */
+    pub fn into_vec(self) -> Vec<Smpl> {
+        self.data
     }
 }
 
-impl<'a, T: AsMut<[Smpl]>> From<T> for CVecMut<'a> {
-    fn from(mut data: T) -> Self {
-        let data = data.as_mut();
-        let (norm, phas) = data.split_at_mut(data.len() / 2);
-        Self::from_parts(norm, phas).unwrap()
+impl From<Vec<Smpl>> for FVecBuf {
+    fn from(data: Vec<Smpl>) -> Self {
+        Self { data }
     }
 }
 
-/**
- * Mutable complex floating point vector with norm part only
- */
-#[repr(transparent)]
-pub struct CVecNormMut<'a> {
-    cvec: CVecMut<'a>,
+impl FromIterator<Smpl> for FVecBuf {
+    fn from_iter<I: IntoIterator<Item = Smpl>>(iter: I) -> Self {
+        Self {
+            data: iter.into_iter().collect(),
+        }
+    }
 }
 
-impl<'a> Deref for CVecNormMut<'a> {
-    type Target = CVecMut<'a>;
+impl<'a> FromIterator<&'a Smpl> for FVecBuf {
+    fn from_iter<I: IntoIterator<Item = &'a Smpl>>(iter: I) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
 
-    fn deref(&self) -> &Self::Target {
-        &self.cvec
+impl Extend<Smpl> for FVecBuf {
+    fn extend<I: IntoIterator<Item = Smpl>>(&mut self, iter: I) {
+        self.data.extend(iter)
     }
 }
 
-impl<'a> DerefMut for CVecNormMut<'a> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.cvec
+impl<'a> Extend<&'a Smpl> for FVecBuf {
+    fn extend<I: IntoIterator<Item = &'a Smpl>>(&mut self, iter: I) {
+        self.data.extend(iter)
     }
 }
 
-impl<'a, T: AsMut<[Smpl]>> From<T> for CVecNormMut<'a> {
-    fn from(mut data: T) -> Self {
-        let norm = data.as_mut();
-        Self {
-            cvec: CVecMut::from_norm(norm),
-        }
+impl AsRef<[Smpl]> for FVecBuf {
+    fn as_ref(&self) -> &[Smpl] {
+        &self.data
+    }
+}
+
+impl AsMut<[Smpl]> for FVecBuf {
+    fn as_mut(&mut self) -> &mut [Smpl] {
+        &mut self.data
     }
 }
 
 /**
- * Mutable complex floating point vector with phas part only
+ * Fixed-size floating point vector
+ *
+ * The array carries its size in the type, so it can be used instead of `farr!()`
+ * when buffer sizes should be checked at compile-time.
+ * The `&arr` converts to `FVec` and `&mut arr` converts to `FVecMut`.
  */
 #[repr(transparent)]
-pub struct CVecPhasMut<'a> {
-    cvec: CVecMut<'a>,
-}
+#[derive(Clone, Copy, PartialEq)]
+pub struct FArr<const N: usize>([Smpl; N]);
 
-impl<'a> Deref for CVecPhasMut<'a> {
-    type Target = CVecMut<'a>;
+impl<const N: usize> FArr<N> {
+    /**
+     * Size of vector
+     */
+    pub const SIZE: usize = N;
 
-    fn deref(&self) -> &Self::Target {
-        &self.cvec
+    /**
+     * Create a vector filled with zeros
+     */
+    pub const fn new() -> Self {
+        Self([0.; N])
     }
-}
 
-impl<'a> DerefMut for CVecPhasMut<'a> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.cvec
+    pub const fn size(&self) -> usize {
+        N
     }
 }
 
-impl<'a, T: AsMut<[Smpl]>> From<T> for CVecPhasMut<'a> {
-    fn from(mut data: T) -> Self {
-        let phas = data.as_mut();
-        Self {
-            cvec: CVecMut::from_phas(phas),
-        }
+impl<const N: usize> Default for FArr<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> From<[Smpl; N]> for FArr<N> {
+    fn from(data: [Smpl; N]) -> Self {
+        Self(data)
+    }
+}
+
+impl<const N: usize> Deref for FArr<N> {
+    type Target = [Smpl; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for FArr<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> AsRef<[Smpl]> for FArr<N> {
+    fn as_ref(&self) -> &[Smpl] {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsMut<[Smpl]> for FArr<N> {
+    fn as_mut(&mut self) -> &mut [Smpl] {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> fmt::Debug for FArr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FArr").field(&Truncated(&self.0)).finish()
+    }
+}
+
+/**
+ * Fixed-size complex floating point vector
+ *
+ * The `N` is a size of FFT window, so the vector contains `N / 2 + 1` norm
+ * values followed by the same number of phase values. It can be used instead
+ * of `carr!()` when buffer sizes should be checked at compile-time.
+ * The `&arr` converts to `CVec` and `&mut arr` converts to `CVecMut`.
+ */
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct CArr<const N: usize> {
+    data: [Smpl; N],
+    tail: [Smpl; 2],
+}
+
+impl<const N: usize> CArr<N> {
+    /**
+     * Size of FFT window
+     */
+    pub const SIZE: usize = N;
+
+    /**
+     * Create a vector filled with zeros
+     */
+    pub const fn new() -> Self {
+        Self {
+            data: [0.; N],
+            tail: [0.; 2],
+        }
+    }
+
+    /**
+     * Get the whole vector data as slice
+     */
+    pub fn as_slice(&self) -> &[Smpl] {
+        // both arrays are laid out contiguously due to `repr(C)`
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const Smpl, N + 2) }
+    }
+
+    /**
+     * Get the whole vector data as mutable slice
+     */
+    pub fn as_mut_slice(&mut self) -> &mut [Smpl] {
+        unsafe { core::slice::from_raw_parts_mut(self as *mut Self as *mut Smpl, N + 2) }
+    }
+
+    /**
+     * Get norm part
+     */
+    pub fn norm(&self) -> &[Smpl] {
+        &self.as_slice()[..N / 2 + 1]
+    }
+
+    /**
+     * Get norm part for modification
+     */
+    pub fn norm_mut(&mut self) -> &mut [Smpl] {
+        &mut self.as_mut_slice()[..N / 2 + 1]
+    }
+
+    /**
+     * Get phase part
+     */
+    pub fn phas(&self) -> &[Smpl] {
+        &self.as_slice()[N / 2 + 1..]
+    }
+
+    /**
+     * Get phase part for modification
+     */
+    pub fn phas_mut(&mut self) -> &mut [Smpl] {
+        &mut self.as_mut_slice()[N / 2 + 1..]
+    }
+}
+
+impl<const N: usize> Default for CArr<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> AsRef<[Smpl]> for CArr<N> {
+    fn as_ref(&self) -> &[Smpl] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> AsMut<[Smpl]> for CArr<N> {
+    fn as_mut(&mut self) -> &mut [Smpl] {
+        self.as_mut_slice()
+    }
+}
+
+impl<const N: usize> fmt::Debug for CArr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CArr")
+            .field("norm", &Truncated(self.norm()))
+            .field("phas", &Truncated(self.phas()))
+            .finish()
+    }
+}
+
+/**
+ * Immutable double precision floating point vector
+ */
+#[repr(transparent)]
+pub struct LVec<'a> {
+    lvec: ffi::lvec_t,
+    _pd: PhantomData<&'a ()>,
+}
+
+impl<'a> LVec<'a> {
+    /**
+     * Create a non-owned vector from existing `lvec_t` pointer
+     *
+     * The lifetime should be bounded by the object which owns the vector.
+     */
+    pub(crate) unsafe fn from_raw_ptr(ptr: *const ffi::lvec_t) -> Self {
+        Self {
+            lvec: *ptr,
+            _pd: PhantomData,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.lvec.length as usize
+    }
+
+    pub fn as_slice(&self) -> &[LSmpl] {
+        unsafe { core::slice::from_raw_parts(self.lvec.data, self.size()) }
+    }
+}
+
+impl<'a, T: AsRef<[LSmpl]> + ?Sized> From<&'a T> for LVec<'a> {
+    fn from(data: &'a T) -> Self {
+        let data = data.as_ref();
+        Self {
+            lvec: ffi::lvec_t {
+                length: data.len() as ffi::uint_t,
+                data: data.as_ptr() as *mut _,
+            },
+            _pd: PhantomData,
+        }
+    }
+}
+
+/**
+ * Mutable double precision floating point vector
+ */
+#[repr(transparent)]
+pub struct LVecMut<'a> {
+    lvec: ffi::lvec_t,
+    _pd: PhantomData<&'a mut ()>,
+}
+
+impl<'a> LVecMut<'a> {
+    /**
+     * Create a non-owned vector from existing `lvec_t` pointer
+     *
+     * The lifetime should be bounded by the object which owns the vector.
+     */
+    pub(crate) unsafe fn from_raw_ptr(ptr: *mut ffi::lvec_t) -> Self {
+        Self {
+            lvec: *ptr,
+            _pd: PhantomData,
+        }
+    }
+
+    pub(crate) fn as_mut_ptr(&'a mut self) -> *mut ffi::lvec_t {
+        &mut self.lvec
+    }
+
+    pub fn size(&self) -> usize {
+        self.lvec.length as usize
+    }
+
+    pub fn as_slice(&self) -> &[LSmpl] {
+        unsafe { core::slice::from_raw_parts(self.lvec.data, self.size()) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [LSmpl] {
+        unsafe { core::slice::from_raw_parts_mut(self.lvec.data, self.size()) }
+    }
+}
+
+impl<'a, T: AsMut<[LSmpl]> + ?Sized> From<&'a mut T> for LVecMut<'a> {
+    fn from(data: &'a mut T) -> Self {
+        let data = data.as_mut();
+        Self {
+            lvec: ffi::lvec_t {
+                length: data.len() as ffi::uint_t,
+                data: data.as_mut_ptr(),
+            },
+            _pd: PhantomData,
+        }
+    }
+}
+
+/**
+ * Spectral bin in polar form
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bin {
+    /**
+     * Magnitude
+     */
+    pub norm: Smpl,
+
+    /**
+     * Phase
+     */
+    pub phas: Smpl,
+}
+
+/**
+ * Immutable complex floating point vector
+ */
+#[repr(transparent)]
+pub struct CVec<'a> {
+    cvec: ffi::cvec_t,
+    _pd: PhantomData<&'a ()>,
+}
+
+impl<'a> CVec<'a> {
+    pub fn from_parts<T: AsRef<[Smpl]>>(norm: T, phas: T) -> Result<Self> {
+        let norm = norm.as_ref();
+        let phas = phas.as_ref();
+        #[cfg(not(feature = "unchecked-size"))]
+        {
+            if norm.len() != phas.len() {
+                return Err(Error::MismatchSize);
+            }
+        }
+        Ok(Self {
+            cvec: ffi::cvec_t {
+                length: norm.len() as ffi::uint_t,
+                norm: norm.as_ptr() as *mut _,
+                phas: phas.as_ptr() as *mut _,
+            },
+            _pd: PhantomData,
+        })
+    }
+
+    pub(crate) fn from_norm(norm: &[Smpl]) -> Self {
+        Self {
+            cvec: ffi::cvec_t {
+                length: norm.len() as ffi::uint_t,
+                norm: norm.as_ptr() as *mut _,
+                phas: null_mut(),
+            },
+            _pd: PhantomData,
+        }
+    }
+
+    pub(crate) fn from_phas(phas: &[Smpl]) -> Self {
+        Self {
+            cvec: ffi::cvec_t {
+                length: phas.len() as ffi::uint_t,
+                norm: null_mut(),
+                phas: phas.as_ptr() as *mut _,
+            },
+            _pd: PhantomData,
+        }
+    }
+
+    pub(crate) fn as_ptr(&'a self) -> *const ffi::cvec_t {
+        &self.cvec
+    }
+
+    pub fn size(&self) -> usize {
+        self.cvec.length as usize
+    }
+
+    pub fn norm(&self) -> &[Smpl] {
+        unsafe { core::slice::from_raw_parts(self.cvec.norm, self.size()) }
+    }
+
+    pub fn phas(&self) -> &[Smpl] {
+        unsafe { core::slice::from_raw_parts(self.cvec.phas, self.size()) }
+    }
+
+    /**
+     * Get spectral bin by index
+     */
+    pub fn bin(&self, index: usize) -> Option<Bin> {
+        if index < self.size() {
+            Some(Bin {
+                norm: self.norm()[index],
+                phas: self.phas()[index],
+            })
+        } else {
+            None
+        }
+    }
+
+    /**
+     * Iterate over spectral bins
+     */
+    pub fn bins(&self) -> impl Iterator<Item = Bin> + '_ {
+        self.norm()
+            .iter()
+            .zip(self.phas().iter())
+            .map(|(&norm, &phas)| Bin { norm, phas })
+    }
+
+    /**
+     * Compare spectrums with given absolute tolerance
+     *
+     * Both norm and phase parts are compared element-wise.
+     */
+    pub fn approx_eq<'b, T: Into<CVec<'b>>>(&self, other: T, epsilon: Smpl) -> bool {
+        let other = other.into();
+        approx_eq_slices(self.norm(), other.norm(), epsilon)
+            && approx_eq_slices(self.phas(), other.phas(), epsilon)
+    }
+
+    #[cfg(feature = "unchecked-size")]
+    #[inline]
+    pub(crate) fn check_size(&self, _min_size: usize) -> Status {
+        Ok(())
+    }
+
+    #[cfg(not(feature = "unchecked-size"))]
+    #[inline]
+    pub(crate) fn check_size(&self, min_size: usize) -> Status {
+        if (self.cvec.length as usize).saturating_sub(1) * 2 < min_size {
+            Err(Error::MismatchSize)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'a> Memory for CVec<'a> {
+    fn memory(&self) -> [Range<usize>; 2] {
+        [
+            memory(self.cvec.norm, self.size()),
+            memory(self.cvec.phas, self.size()),
+        ]
+    }
+}
+
+impl<'a, T: AsRef<[Smpl]> + ?Sized> From<&'a T> for CVec<'a> {
+    fn from(data: &'a T) -> Self {
+        let data = data.as_ref();
+        let (norm, phas) = data.split_at(data.len() / 2);
+        Self::from_parts(norm, phas).unwrap()
+    }
+}
+
+impl<'a, 'b> PartialEq<CVec<'b>> for CVec<'a> {
+    fn eq(&self, other: &CVec<'b>) -> bool {
+        self.norm() == other.norm() && self.phas() == other.phas()
+    }
+}
+
+impl<'a> fmt::Debug for CVec<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CVec")
+            .field("size", &self.size())
+            .field("norm", &Truncated(self.norm()))
+            .field("phas", &Truncated(self.phas()))
+            .finish()
+    }
+}
+
+/**
+ * Mutable complex floating point vector
+ */
+#[repr(transparent)]
+pub struct CVecMut<'a> {
+    cvec: ffi::cvec_t,
+    _pd: PhantomData<&'a mut ()>,
+}
+
+impl<'a> CVecMut<'a> {
+    pub fn from_parts<T: AsMut<[Smpl]>>(mut norm: T, mut phas: T) -> Result<Self> {
+        let norm = norm.as_mut();
+        let phas = phas.as_mut();
+        #[cfg(not(feature = "unchecked-size"))]
+        {
+            if norm.len() != phas.len() {
+                return Err(Error::MismatchSize);
+            }
+        }
+        Ok(Self {
+            cvec: ffi::cvec_t {
+                length: norm.len() as ffi::uint_t,
+                norm: norm.as_mut_ptr(),
+                phas: phas.as_mut_ptr(),
+            },
+            _pd: PhantomData,
+        })
+    }
+
+    pub(crate) fn from_norm(norm: &mut [Smpl]) -> Self {
+        Self {
+            cvec: ffi::cvec_t {
+                length: norm.len() as ffi::uint_t,
+                norm: norm.as_mut_ptr(),
+                phas: null_mut(),
+            },
+            _pd: PhantomData,
+        }
+    }
+
+    pub(crate) fn from_phas(phas: &mut [Smpl]) -> Self {
+        Self {
+            cvec: ffi::cvec_t {
+                length: phas.len() as ffi::uint_t,
+                norm: null_mut(),
+                phas: phas.as_mut_ptr(),
+            },
+            _pd: PhantomData,
+        }
+    }
+
+    pub(crate) fn as_mut_ptr(&'a mut self) -> *mut ffi::cvec_t {
+        &mut self.cvec
+    }
+
+    pub fn size(&self) -> usize {
+        self.cvec.length as usize
+    }
+
+    #[cfg(feature = "unchecked-size")]
+    #[inline]
+    pub(crate) fn check_size(&self, _min_size: usize) -> Status {
+        Ok(())
+    }
+
+    #[cfg(not(feature = "unchecked-size"))]
+    #[inline]
+    pub(crate) fn check_size(&self, min_size: usize) -> Status {
+        if (self.cvec.length as usize).saturating_sub(1) * 2 < min_size {
+            Err(Error::MismatchSize)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'a> Memory for CVecMut<'a> {
+    fn memory(&self) -> [Range<usize>; 2] {
+        [
+            memory(self.cvec.norm, self.size()),
+            memory(self.cvec.phas, self.size()),
+        ]
+    }
+}
+
+impl<'a, T: AsMut<[Smpl]> + ?Sized> From<&'a mut T> for CVecMut<'a> {
+    fn from(data: &'a mut T) -> Self {
+        let data = data.as_mut();
+        let (norm, phas) = data.split_at_mut(data.len() / 2);
+        Self::from_parts(norm, phas).unwrap()
+    }
+}
+
+/**
+ * Immutable complex floating point vector with norm part only
+ *
+ * This view is useful for functions which only read the magnitudes of a spectrum.
+ */
+#[repr(transparent)]
+pub struct CVecNorm<'a> {
+    cvec: CVec<'a>,
+}
+
+impl<'a> CVecNorm<'a> {
+    /**
+     * Create view from norm data
+     */
+    pub fn new(norm: &'a [Smpl]) -> Self {
+        Self {
+            cvec: CVec::from_norm(norm),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.cvec.size()
+    }
+
+    /**
+     * Get norm part
+     */
+    pub fn norm(&self) -> &[Smpl] {
+        self.cvec.norm()
+    }
+}
+
+impl<'a, T: AsRef<[Smpl]> + ?Sized> From<&'a T> for CVecNorm<'a> {
+    fn from(data: &'a T) -> Self {
+        let norm = data.as_ref();
+        Self {
+            cvec: CVec::from_norm(norm),
+        }
+    }
+}
+
+impl<'a> fmt::Debug for CVecNorm<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CVecNorm")
+            .field("size", &self.size())
+            .field("norm", &Truncated(self.norm()))
+            .finish()
+    }
+}
+
+/**
+ * Immutable complex floating point vector with phas part only
+ *
+ * This view is useful for functions which only read the phases of a spectrum.
+ */
+#[repr(transparent)]
+pub struct CVecPhas<'a> {
+    cvec: CVec<'a>,
+}
+
+impl<'a> CVecPhas<'a> {
+    /**
+     * Create view from phas data
+     */
+    pub fn new(phas: &'a [Smpl]) -> Self {
+        Self {
+            cvec: CVec::from_phas(phas),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.cvec.size()
+    }
+
+    /**
+     * Get phas part
+     */
+    pub fn phas(&self) -> &[Smpl] {
+        self.cvec.phas()
+    }
+}
+
+impl<'a, T: AsRef<[Smpl]> + ?Sized> From<&'a T> for CVecPhas<'a> {
+    fn from(data: &'a T) -> Self {
+        let phas = data.as_ref();
+        Self {
+            cvec: CVec::from_phas(phas),
+        }
+    }
+}
+
+impl<'a> fmt::Debug for CVecPhas<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CVecPhas")
+            .field("size", &self.size())
+            .field("phas", &Truncated(self.phas()))
+            .finish()
+    }
+}
+
+/**
+ * Mutable complex floating point vector with norm part only
+ *
+ * This view is used as output of functions which compute the magnitudes
+ * of a spectrum only, like `FFT::get_norm()`.
+ */
+#[repr(transparent)]
+pub struct CVecNormMut<'a> {
+    cvec: CVecMut<'a>,
+}
+
+impl<'a> CVecNormMut<'a> {
+    /**
+     * Create view from norm data
+     */
+    pub fn new(norm: &'a mut [Smpl]) -> Self {
+        Self {
+            cvec: CVecMut::from_norm(norm),
+        }
+    }
+
+    /**
+     * Get norm part
+     */
+    pub fn norm(&self) -> &[Smpl] {
+        unsafe { core::slice::from_raw_parts(self.cvec.cvec.norm, self.size()) }
+    }
+
+    /**
+     * Get norm part for modification
+     */
+    pub fn norm_mut(&mut self) -> &mut [Smpl] {
+        unsafe { core::slice::from_raw_parts_mut(self.cvec.cvec.norm, self.size()) }
+    }
+}
+
+impl<'a> Deref for CVecNormMut<'a> {
+    type Target = CVecMut<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cvec
+    }
+}
+
+impl<'a> DerefMut for CVecNormMut<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.cvec
+    }
+}
+
+impl<'a, T: AsMut<[Smpl]> + ?Sized> From<&'a mut T> for CVecNormMut<'a> {
+    fn from(data: &'a mut T) -> Self {
+        let norm = data.as_mut();
+        Self {
+            cvec: CVecMut::from_norm(norm),
+        }
+    }
+}
+
+impl<'a> fmt::Debug for CVecNormMut<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CVecNormMut")
+            .field("size", &self.size())
+            .field("norm", &Truncated(self.norm()))
+            .finish()
+    }
+}
+
+/**
+ * Mutable complex floating point vector with phas part only
+ *
+ * This view is used as output of functions which compute the phases
+ * of a spectrum only, like `FFT::get_phas()`.
+ */
+#[repr(transparent)]
+pub struct CVecPhasMut<'a> {
+    cvec: CVecMut<'a>,
+}
+
+impl<'a> CVecPhasMut<'a> {
+    /**
+     * Create view from phas data
+     */
+    pub fn new(phas: &'a mut [Smpl]) -> Self {
+        Self {
+            cvec: CVecMut::from_phas(phas),
+        }
+    }
+
+    /**
+     * Get phas part
+     */
+    pub fn phas(&self) -> &[Smpl] {
+        unsafe { core::slice::from_raw_parts(self.cvec.cvec.phas, self.size()) }
+    }
+
+    /**
+     * Get phas part for modification
+     */
+    pub fn phas_mut(&mut self) -> &mut [Smpl] {
+        unsafe { core::slice::from_raw_parts_mut(self.cvec.cvec.phas, self.size()) }
+    }
+}
+
+impl<'a> Deref for CVecPhasMut<'a> {
+    type Target = CVecMut<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cvec
+    }
+}
+
+impl<'a> DerefMut for CVecPhasMut<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.cvec
+    }
+}
+
+impl<'a, T: AsMut<[Smpl]> + ?Sized> From<&'a mut T> for CVecPhasMut<'a> {
+    fn from(data: &'a mut T) -> Self {
+        let phas = data.as_mut();
+        Self {
+            cvec: CVecMut::from_phas(phas),
+        }
+    }
+}
+
+impl<'a> fmt::Debug for CVecPhasMut<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CVecPhasMut")
+            .field("size", &self.size())
+            .field("phas", &Truncated(self.phas()))
+            .finish()
     }
 }
 
@@ -332,117 +1245,890 @@ pub struct FMat<'a, X> {
     _pd: PhantomData<&'a ()>,
 }
 
-impl<'a, X> FMat<'a, X> {
-    pub(crate) fn as_ptr(&'a self) -> *const ffi::fmat_t {
-        &self.fmat
+impl<'a, X> FMat<'a, X> {
+    pub(crate) fn as_ptr(&'a self) -> *const ffi::fmat_t {
+        &self.fmat
+    }
+
+    pub fn length(&self) -> usize {
+        self.fmat.length as usize
+    }
+
+    pub fn height(&self) -> usize {
+        self.fmat.height as usize
+    }
+
+    /// Read sample value in a buffer
+    pub fn get_sample(&self, channel: usize, position: usize) -> Result<Smpl> {
+        if channel >= self.height() || position >= self.length() {
+            return Err(Error::InvalidArg);
+        }
+        Ok(unsafe {
+            ffi::fmat_get_sample(
+                self.as_ptr(),
+                channel as ffi::uint_t,
+                position as ffi::uint_t,
+            )
+        })
+    }
+
+    /**
+     * Compare matrices with given absolute tolerance
+     *
+     * Returns `true` when both matrices have same dimensions and each pair of
+     * elements differs by not more than `epsilon`.
+     */
+    pub fn approx_eq<Y>(&self, other: &FMat<'_, Y>, epsilon: Smpl) -> bool {
+        self.height() == other.height()
+            && (0..self.height())
+                .all(|i| approx_eq_slices(self.row_slice(i), other.row_slice(i), epsilon))
+    }
+
+    /**
+     * Get row by index
+     */
+    pub fn row(&self, index: usize) -> Option<&[Smpl]> {
+        if index < self.height() {
+            Some(self.row_slice(index))
+        } else {
+            None
+        }
+    }
+
+    /**
+     * Iterate over matrix rows
+     */
+    pub fn rows(&self) -> impl Iterator<Item = &[Smpl]> + '_ {
+        (0..self.height()).map(move |index| self.row_slice(index))
+    }
+
+    fn row_slice(&self, index: usize) -> &[Smpl] {
+        unsafe { core::slice::from_raw_parts(*self.fmat.data.add(index), self.length()) }
+    }
+
+    /**
+     * Get ranges of addresses of matrix rows
+     *
+     * The rows may be placed anywhere, so each row is checked separately.
+     */
+    pub(crate) fn memory(&self) -> impl Iterator<Item = Range<usize>> + Clone + '_ {
+        (0..self.height()).map(move |index| memory(self.row_slice(index).as_ptr(), self.length()))
+    }
+
+    /**
+     * Get matrix rows as mutable slices
+     *
+     * __Deprecated__: This method creates mutable slices from a shared reference,
+     * which is unsound. Use `FMat::rows()` to read the data or `FMatBuf::rows_mut()`
+     * to modify an owned matrix.
+     */
+    #[deprecated(note = "Use `rows()` or `FMatBuf::rows_mut()` instead")]
+    pub fn get_vec(&self) -> Vec<&mut [Smpl]> {
+        let mut vec = Vec::with_capacity(self.height());
+        let mut ptr = self.fmat.data;
+        let end = self.fmat.data.wrapping_add(self.height());
+
+        while ptr != end {
+            vec.push(unsafe { core::slice::from_raw_parts_mut(*ptr, self.length()) });
+            ptr = ptr.wrapping_add(1);
+        }
+        vec
+    }
+}
+
+impl<'a> FMat<'a, ()> {
+    /**
+     * Create a matrix from an already existing `fmat_t` pointer.
+     *
+     * The matrix is non-owned: the header of `fmat_t` is copied but the data is shared.
+     * The lifetime `'a` is not tied to anything, so this function is intended for
+     * advanced interoperability only. Prefer `FMat::from_raw_ptr_of()` which ties
+     * the view to the object which owns the matrix.
+     *
+     * # Safety
+     *
+     * - The `ptr` must not be `null`.
+     * - The `ptr` should points to already initialized matrix data.
+     * - The matrix data should stay alive and should not be modified for the whole `'a`.
+     */
+    #[allow(clippy::missing_safety_doc)] // TODO: Remove after closing https://github.com/rust-lang/rust-clippy/issues/5593
+    pub unsafe fn from_raw_ptr(ptr: *const ffi::fmat_t) -> Self {
+        FMat {
+            fmat: *ptr,
+            _x: (),
+            _pd: PhantomData,
+        }
+    }
+
+    /**
+     * Create a view of matrix owned by some object
+     *
+     * The view borrows the `owner`, so it can't outlive it and the owner
+     * can't be modified while the view exists (like `FilterBank::get_coeffs()`).
+     *
+     * # Safety
+     *
+     * - The `ptr` must not be `null`.
+     * - The `ptr` should points to initialized matrix data owned by `owner`.
+     */
+    pub unsafe fn from_raw_ptr_of<O: ?Sized>(ptr: *const ffi::fmat_t, _owner: &'a O) -> Self {
+        Self::from_raw_ptr(ptr)
+    }
+}
+
+pub type FMatVecs = Vec<*const Smpl>;
+
+/**
+ * Owned storage of matrix rows
+ *
+ * Keeps both the row data and the table of row pointers alive
+ * as long as the matrix exists.
+ */
+pub struct FMatRows {
+    rows: Vec<Vec<Smpl>>,
+    _ptrs: FMatVecs,
+}
+
+impl FMat<'static, FMatRows> {
+    /**
+     * Create a matrix which owns its rows
+     *
+     * - `rows` Rows of matrix, should have the same length
+     *
+     * Unlike conversion from slices of rows the caller does not need to keep
+     * any borrows alive while the matrix is in use.
+     */
+    pub fn from_rows(rows: Vec<Vec<Smpl>>) -> Result<Self> {
+        let length = rows.first().map(|row| row.len()).unwrap_or(0);
+
+        if rows.iter().any(|row| row.len() != length) {
+            return Err(Error::MismatchSize);
+        }
+
+        let ptrs = rows.iter().map(|row| row.as_ptr()).collect::<Vec<_>>();
+
+        Ok(Self {
+            fmat: ffi::fmat_t {
+                height: rows.len() as _,
+                length: length as _,
+                data: ptrs.as_ptr() as _,
+            },
+            _x: FMatRows { rows, _ptrs: ptrs },
+            _pd: PhantomData,
+        })
+    }
+
+    /**
+     * Iterate over matrix rows for modification
+     */
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [Smpl]> + '_ {
+        self._x.rows.iter_mut().map(|row| row.as_mut_slice())
+    }
+
+    /**
+     * Convert matrix back into its rows
+     */
+    pub fn into_rows(self) -> Vec<Vec<Smpl>> {
+        self._x.rows
+    }
+}
+
+impl<'a, T: AsRef<[&'a [Smpl]]>> From<T> for FMat<'a, FMatVecs> {
+    /**
+     * Create a matrix from a `FMatVecs`
+     *
+     * Matrix's horizontal height is the `Vec`'s len, and
+     * its vertical length the slice's len.
+     */
+    fn from(data: T) -> Self {
+        let data = data.as_ref();
+
+        #[cfg(not(feature = "unchecked-size"))]
+        {
+            let mut vecs = data.iter();
+            if let Some(fst) = vecs.next() {
+                let len = fst.len();
+                if len == 0 {
+                    panic!("No values in slice");
+                }
+                if vecs.any(|nxt| nxt.len() != len) {
+                    panic!("Slices have different lengths");
+                }
+            } else {
+                panic!("No slices in vec");
+            }
+        }
+
+        let array = data.iter().map(|v| v.as_ptr()).collect::<Vec<_>>();
+
+        Self {
+            fmat: ffi::fmat_t {
+                height: data.len() as _,
+                length: data[0].len() as _,
+                data: array.as_ptr() as _,
+            },
+            _x: array,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'b, X, Y> PartialEq<FMat<'b, Y>> for FMat<'a, X> {
+    fn eq(&self, other: &FMat<'b, Y>) -> bool {
+        self.height() == other.height()
+            && (0..self.height()).all(|i| self.row_slice(i) == other.row_slice(i))
+    }
+}
+
+impl<'a, X> fmt::Debug for FMat<'a, X> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = (0..self.height())
+            .map(|i| Truncated(self.row_slice(i)))
+            .collect::<Vec<_>>();
+        f.debug_struct("FMat")
+            .field("height", &self.height())
+            .field("length", &self.length())
+            .field("rows", &Truncated(&rows))
+            .finish()
+    }
+}
+
+/**
+ * Mutable matrix of real valued data.
+ */
+#[repr(C)]
+pub struct FMatMut<'a, X> {
+    fmat: ffi::fmat_t,
+    _x: X,
+    _pd: PhantomData<&'a mut ()>,
+}
+
+impl<'a, X> FMatMut<'a, X> {
+    pub(crate) fn as_mut_ptr(&'a mut self) -> *mut ffi::fmat_t {
+        &mut self.fmat
+    }
+
+    pub fn length(&self) -> usize {
+        self.fmat.length as usize
+    }
+
+    pub fn height(&self) -> usize {
+        self.fmat.height as usize
+    }
+
+    /**
+     * Get ranges of addresses of matrix rows
+     */
+    pub(crate) fn memory(&self) -> impl Iterator<Item = Range<usize>> + Clone + '_ {
+        (0..self.height())
+            .map(move |index| memory(unsafe { *self.fmat.data.add(index) }, self.length()))
+    }
+}
+
+pub type FMatVecsMut = Vec<*mut Smpl>;
+
+impl<'a, T: AsMut<[&'a mut [Smpl]]>> From<T> for FMatMut<'a, FMatVecsMut> {
+    /**
+     * Create a mutable matrix from a `FMatVecsMut`
+     *
+     * Matrix's horizontal height is the `Vec`'s len, and
+     * its vertical length the slice's len.
+     */
+    fn from(mut data: T) -> Self {
+        let data = data.as_mut();
+
+        #[cfg(not(feature = "unchecked-size"))]
+        {
+            let mut vecs = data.iter();
+            if let Some(fst) = vecs.next() {
+                let len = fst.len();
+                if len == 0 {
+                    panic!("No values in slice");
+                }
+                if vecs.any(|nxt| nxt.len() != len) {
+                    panic!("Slices have different lengths");
+                }
+            } else {
+                panic!("No slices in vec");
+            }
+        }
+
+        let mut array = data.iter_mut().map(|v| v.as_mut_ptr()).collect::<Vec<_>>();
+
+        Self {
+            fmat: ffi::fmat_t {
+                height: data.len() as _,
+                length: data[0].len() as _,
+                data: array.as_mut_ptr(),
+            },
+            _x: array,
+            _pd: PhantomData,
+        }
+    }
+}
+
+/**
+ * Owned matrix of real valued data.
+ *
+ * The matrix keeps its samples in a single contiguous buffer in row-major order
+ * and owns the table of row pointers, so it can be safely passed to _aubio_
+ * functions as long as it is borrowed.
+ */
+#[derive(Clone)]
+pub struct FMatBuf {
+    data: FMatData,
+    height: usize,
+    length: usize,
+}
+
+/**
+ * Contiguous samples with the table of pointers to its rows
+ *
+ * The table is rebuilt each time the samples is reallocated (i.e. on cloning),
+ * so it always points into the samples.
+ */
+struct FMatData {
+    data: Vec<Smpl>,
+    rows: Vec<*mut Smpl>,
+}
+
+// the row pointers refer to the owned samples only
+unsafe impl Send for FMatData {}
+unsafe impl Sync for FMatData {}
+
+impl FMatData {
+    fn new(mut data: Vec<Smpl>, height: usize, length: usize) -> Self {
+        let ptr = data.as_mut_ptr();
+        let rows = (0..height)
+            .map(|row| ptr.wrapping_add(row * length))
+            .collect();
+
+        Self { data, rows }
+    }
+}
+
+impl Clone for FMatData {
+    fn clone(&self) -> Self {
+        let height = self.rows.len();
+        let length = self.data.len().checked_div(height).unwrap_or(0);
+
+        Self::new(self.data.clone(), height, length)
+    }
+}
+
+impl FMatBuf {
+    /**
+     * Create a matrix filled with zeros
+     *
+     * - `height` Number of rows
+     * - `length` Number of samples in each row
+     */
+    pub fn new(height: usize, length: usize) -> Self {
+        Self::from_data(height, length, vec![0.; height * length])
+    }
+
+    /**
+     * Create a matrix from contiguous data in row-major order
+     *
+     * - `height` Number of rows
+     * - `length` Number of samples in each row
+     * - `data` Samples, should contain exactly `height * length` values
+     */
+    pub fn from_vec(height: usize, length: usize, data: Vec<Smpl>) -> Result<Self> {
+        if data.len() != height * length {
+            return Err(Error::MismatchSize);
+        }
+        Ok(Self::from_data(height, length, data))
+    }
+
+    fn from_data(height: usize, length: usize, data: Vec<Smpl>) -> Self {
+        Self {
+            data: FMatData::new(data, height, length),
+            height,
+            length,
+        }
     }
 
     pub fn length(&self) -> usize {
-        self.fmat.length as usize
+        self.length
     }
 
     pub fn height(&self) -> usize {
-        self.fmat.height as usize
+        self.height
     }
 
-    /// Read sample value in a buffer
-    pub fn get_sample(&self, channel: usize, position: usize) -> Result<Smpl> {
-        if channel >= self.height() || position >= self.length() {
-            return Err(Error::InvalidArg);
-        }
-        Ok(unsafe {
-            ffi::fmat_get_sample(
-                self.as_ptr(),
-                channel as ffi::uint_t,
-                position as ffi::uint_t,
-            )
-        })
+    /**
+     * Get all samples in row-major order
+     */
+    pub fn as_slice(&self) -> &[Smpl] {
+        &self.data.data
     }
 
-    pub fn get_vec(&self) -> Vec<&mut [Smpl]> {
-        let mut vec = Vec::with_capacity(self.height());
-        let mut ptr = self.fmat.data;
-        let end = self.fmat.data.wrapping_add(self.height());
+    /**
+     * Get all samples in row-major order for modification
+     */
+    pub fn as_mut_slice(&mut self) -> &mut [Smpl] {
+        &mut self.data.data
+    }
 
-        while ptr != end {
-            vec.push(unsafe { std::slice::from_raw_parts_mut(*ptr, self.length()) });
-            ptr = ptr.wrapping_add(1);
+    /**
+     * Get row by index
+     */
+    pub fn row(&self, index: usize) -> Option<&[Smpl]> {
+        if index < self.height() {
+            let length = self.length();
+            Some(&self.data.data[index * length..(index + 1) * length])
+        } else {
+            None
         }
-        vec
     }
-}
 
-impl<'a> FMat<'a, ()> {
     /**
-     * Create a matrix from an already existing `fmat_t` pointer.
-     *
-     * The matrix is non-owned; useful to avoid double-frees for `FilterBank`,
-     * for instance.
-     *
-     * # Safety
+     * Get row by index for modification
+     */
+    pub fn row_mut(&mut self, index: usize) -> Option<&mut [Smpl]> {
+        if index < self.height() {
+            let length = self.length();
+            Some(&mut self.data.data[index * length..(index + 1) * length])
+        } else {
+            None
+        }
+    }
+
+    /**
+     * Iterate over matrix rows
+     */
+    pub fn rows(&self) -> impl Iterator<Item = &[Smpl]> + '_ {
+        (0..self.height()).map(move |index| self.row(index).unwrap())
+    }
+
+    /**
+     * Iterate over matrix rows for modification
      *
-     * - The `ptr` must not be `null`.
-     * - The `ptr` should points to already initialized matrix data.
+     * Yields `height` rows even when rows are empty.
      */
-    #[allow(clippy::missing_safety_doc)] // TODO: Remove after closing https://github.com/rust-lang/rust-clippy/issues/5593
-    pub unsafe fn from_raw_ptr(ptr: *const ffi::fmat_t) -> Self {
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [Smpl]> + '_ {
+        let height = self.height();
+        let mut chunks = self.data.data.chunks_exact_mut(self.length.max(1));
+        (0..height).map(move |_| chunks.next().unwrap_or(&mut []))
+    }
+
+    /**
+     * Get matrix which borrows the samples and the table of rows
+     */
+    pub fn as_fmat(&self) -> FMat<'_, ()> {
         FMat {
-            fmat: *ptr,
+            fmat: ffi::fmat_t {
+                height: self.height as _,
+                length: self.length as _,
+                data: self.data.rows.as_ptr() as _,
+            },
+            _x: (),
+            _pd: PhantomData,
+        }
+    }
+
+    /**
+     * Get mutable matrix which borrows the samples and the table of rows
+     */
+    pub fn as_fmat_mut(&mut self) -> FMatMut<'_, ()> {
+        FMatMut {
+            fmat: ffi::fmat_t {
+                height: self.height as _,
+                length: self.length as _,
+                data: self.data.rows.as_mut_ptr(),
+            },
             _x: (),
             _pd: PhantomData,
         }
     }
+
+    /**
+     * Convert matrix into contiguous data in row-major order
+     */
+    pub fn into_vec(self) -> Vec<Smpl> {
+        self.data.data
+    }
 }
 
-pub type FMatVecs = Vec<*const Smpl>;
+impl<'a> From<&'a FMatBuf> for FMat<'a, ()> {
+    fn from(mat: &'a FMatBuf) -> Self {
+        mat.as_fmat()
+    }
+}
+
+impl<'a> From<&'a mut FMatBuf> for FMatMut<'a, ()> {
+    fn from(mat: &'a mut FMatBuf) -> Self {
+        mat.as_fmat_mut()
+    }
+}
+
+#[cfg(feature = "num-complex")]
+mod complex {
+    use super::{Bin, CVec, CVecMut};
+    use crate::{Error, Smpl, Status};
+    use alloc::{vec, vec::Vec};
+    use num_complex::Complex;
+
+    impl From<Bin> for Complex<Smpl> {
+        fn from(bin: Bin) -> Self {
+            Complex::from_polar(bin.norm, bin.phas)
+        }
+    }
+
+    impl From<Complex<Smpl>> for Bin {
+        fn from(value: Complex<Smpl>) -> Self {
+            let (norm, phas) = value.to_polar();
+            Bin { norm, phas }
+        }
+    }
+
+    impl<'a> CVec<'a> {
+        /**
+         * Convert spectrum to complex form
+         */
+        pub fn to_complex(&self) -> Vec<Complex<Smpl>> {
+            self.bins().map(Complex::from).collect()
+        }
+    }
+
+    impl<'a> CVecMut<'a> {
+        /**
+         * Fill spectrum from complex values
+         *
+         * - `data` Complex values, should have same length as spectrum
+         */
+        pub fn set_complex(&mut self, data: &[Complex<Smpl>]) -> Status {
+            if data.len() != self.size() || self.cvec.norm.is_null() || self.cvec.phas.is_null() {
+                return Err(Error::MismatchSize);
+            }
+
+            let norm = unsafe { core::slice::from_raw_parts_mut(self.cvec.norm, self.size()) };
+            let phas = unsafe { core::slice::from_raw_parts_mut(self.cvec.phas, self.size()) };
+
+            for ((norm, phas), value) in norm.iter_mut().zip(phas.iter_mut()).zip(data) {
+                let bin = Bin::from(*value);
+                *norm = bin.norm;
+                *phas = bin.phas;
+            }
+
+            Ok(())
+        }
+    }
 
-impl<'a, T: AsRef<[&'a [Smpl]]>> From<T> for FMat<'a, FMatVecs> {
     /**
-     * Create a matrix from a `FMatVecs`
+     * Convert complex values to spectrum buffer
      *
-     * Matrix's horizontal height is the `Vec`'s len, and
-     * its vertical length the slice's len.
+     * Returns buffer which contains the norm part followed by the phas part
+     * (the layout expected by `CVec::from()` and `carr!()`).
      */
-    fn from(data: T) -> Self {
-        let data = data.as_ref();
+    pub fn complex_to_polar(data: &[Complex<Smpl>]) -> Vec<Smpl> {
+        let mut output = vec![0.; data.len() * 2];
+        let (norm, phas) = output.split_at_mut(data.len());
+        CVecMut::from_parts(norm, phas)
+            .and_then(|mut spectrum| spectrum.set_complex(data))
+            .unwrap();
+        output
+    }
 
-        #[cfg(feature = "check-size")]
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn test_complex() {
+            let data = [Complex::new(1.0, 0.0), Complex::new(0.0, 2.0)];
+            let polar = complex_to_polar(&data);
+            let spectrum: CVec = polar.as_slice().into();
+
+            assert!((spectrum.norm()[1] - 2.0).abs() < 1e-6);
+            assert!((spectrum.phas()[1] - core::f32::consts::FRAC_PI_2 as Smpl).abs() < 1e-6);
+
+            for (a, b) in spectrum.to_complex().iter().zip(data.iter()) {
+                assert!((a - b).norm() < 1e-6);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "num-complex")]
+pub use self::complex::complex_to_polar;
+
+#[cfg(feature = "dasp")]
+mod samples {
+    use super::FVecBuf;
+    use crate::Smpl;
+    use dasp_frame::Frame;
+    use dasp_sample::{Sample, ToSample};
+
+    impl FVecBuf {
+        /**
+         * Create a vector from samples of any `dasp` sample type
+         *
+         * Samples are converted with correct scaling of the source format.
+         */
+        pub fn from_samples<S>(data: &[S]) -> Self
+        where
+            S: Sample + ToSample<Smpl>,
         {
-            let mut vecs = data.iter();
-            if let Some(fst) = vecs.next() {
-                let len = fst.len();
-                if len == 0 {
-                    panic!("No values in slice");
-                }
-                if vecs.any(|nxt| nxt.len() != len) {
-                    panic!("Slices have different lengths");
-                }
-            } else {
-                panic!("No slices in vec");
+            data.iter()
+                .map(|&sample| sample.to_sample::<Smpl>())
+                .collect()
+        }
+
+        /**
+         * Create a mono vector from multichannel frames
+         *
+         * Each output sample is the average of the channels of single frame.
+         */
+        pub fn from_frames<I>(frames: I) -> Self
+        where
+            I: IntoIterator,
+            I::Item: Frame,
+            <I::Item as Frame>::Sample: ToSample<Smpl>,
+        {
+            let scale = 1.0 / <I::Item as Frame>::CHANNELS as Smpl;
+            frames
+                .into_iter()
+                .map(|frame| {
+                    frame
+                        .channels()
+                        .map(|sample| sample.to_sample::<Smpl>())
+                        .sum::<Smpl>()
+                        * scale
+                })
+                .collect()
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn test_from_dasp() {
+            assert_eq!(
+                FVecBuf::from_samples(&[0i16, 16384, -32768]).as_slice(),
+                &[0.0, 0.5, -1.0]
+            );
+            assert_eq!(
+                FVecBuf::from_frames(vec![[0.5f32, 0.25], [-1.0, 0.0]]).as_slice(),
+                &[0.375, -0.5]
+            );
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytes {
+    use super::{FVec, FVecMut};
+    use crate::{Error, Result, Smpl};
+    use bytemuck::PodCastError;
+
+    fn cast_error(error: PodCastError) -> Error {
+        match error {
+            PodCastError::OutputSliceWouldHaveSlop | PodCastError::SizeMismatch => {
+                Error::MismatchSize
             }
+            _ => Error::InvalidArg,
         }
+    }
 
-        let array = data.iter().map(|v| v.as_ptr()).collect::<Vec<_>>();
+    impl<'a> FVec<'a> {
+        /**
+         * Create vector from raw bytes without copying
+         *
+         * - `data` Samples in native byte order
+         *
+         * Returns `Error::InvalidArg` when data isn't aligned for `Smpl`
+         * and `Error::MismatchSize` when its length isn't a multiple of sample size.
+         */
+        pub fn from_bytes(data: &'a [u8]) -> Result<Self> {
+            bytemuck::try_cast_slice::<_, Smpl>(data)
+                .map(Self::from)
+                .map_err(cast_error)
+        }
+    }
 
-        Self {
-            fmat: ffi::fmat_t {
-                height: data.len() as _,
-                length: data[0].len() as _,
-                data: array.as_ptr() as _,
-            },
-            _x: array,
-            _pd: PhantomData,
+    impl<'a> FVecMut<'a> {
+        /**
+         * Create mutable vector from raw bytes without copying
+         *
+         * - `data` Samples in native byte order
+         *
+         * Returns `Error::InvalidArg` when data isn't aligned for `Smpl`
+         * and `Error::MismatchSize` when its length isn't a multiple of sample size.
+         */
+        pub fn from_bytes_mut(data: &'a mut [u8]) -> Result<Self> {
+            bytemuck::try_cast_slice_mut::<_, Smpl>(data)
+                .map(Self::from)
+                .map_err(cast_error)
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn test_from_bytes() {
+            let data: Vec<Smpl> = vec![1.0, 2.0, 3.0];
+            let bytes: &[u8] = bytemuck::cast_slice(&data);
+
+            let fvec = FVec::from_bytes(bytes).unwrap();
+            assert_eq!(fvec.as_slice(), &[1.0, 2.0, 3.0]);
+
+            assert_eq!(
+                FVec::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err(),
+                Error::MismatchSize
+            );
+            assert_eq!(
+                FVec::from_bytes(&bytes[1..5]).unwrap_err(),
+                Error::InvalidArg
+            );
+
+            let mut data = data;
+            let mut fvec = FVecMut::from_bytes_mut(bytemuck::cast_slice_mut(&mut data)).unwrap();
+            fvec[0] = 4.0;
+            assert_eq!(data, [4.0, 2.0, 3.0]);
+        }
+    }
+}
+
+/// Maximum number of elements printed by `Debug` implementations
+const DEBUG_ELEMENTS: usize = 8;
+
+struct Truncated<'a, T>(&'a [T]);
+
+impl<'a, T: fmt::Debug> fmt::Debug for Truncated<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        list.entries(self.0.iter().take(DEBUG_ELEMENTS));
+        if self.0.len() > DEBUG_ELEMENTS {
+            list.entry(&format_args!("..{} more", self.0.len() - DEBUG_ELEMENTS));
+        }
+        list.finish()
+    }
+}
+
+fn approx_eq_slices(a: &[Smpl], b: &[Smpl], epsilon: Smpl) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b.iter())
+            .all(|(a, b)| (a - b).abs() <= epsilon)
+}
+
+fn check_interleaved(data: &[Smpl], channels: usize) -> Status {
+    if channels == 0 {
+        Err(Error::InvalidArg)
+    } else if !data.chunks_exact(channels).remainder().is_empty() {
+        Err(Error::MismatchSize)
+    } else {
+        Ok(())
+    }
+}
+
+/**
+ * Split interleaved multichannel samples into separate channels
+ *
+ * - `data` Interleaved samples (`l r l r ...` for stereo)
+ * - `channels` Number of channels
+ *
+ * Returns a matrix where each row contains the samples of single channel,
+ * so rows can be passed as input vectors to the analysis objects.
+ */
+pub fn deinterleave(data: &[Smpl], channels: usize) -> Result<FMatBuf> {
+    check_interleaved(data, channels)?;
+
+    let length = data.len() / channels;
+    let mut mat = FMatBuf::new(channels, length);
+
+    for channel in 0..channels {
+        let row = mat.row_mut(channel).unwrap();
+        for (dst, src) in row
+            .iter_mut()
+            .zip(data.iter().skip(channel).step_by(channels))
+        {
+            *dst = *src;
         }
     }
+
+    Ok(mat)
+}
+
+/**
+ * Mix interleaved multichannel samples down to mono
+ *
+ * - `data` Interleaved samples (`l r l r ...` for stereo)
+ * - `channels` Number of channels
+ *
+ * Each output sample is the average of the samples of all channels in a frame.
+ */
+pub fn downmix(data: &[Smpl], channels: usize) -> Result<Vec<Smpl>> {
+    check_interleaved(data, channels)?;
+
+    let mut output = vec![0.; data.len() / channels];
+    downmix_into(data, channels, &mut output)?;
+    Ok(output)
+}
+
+/**
+ * Mix interleaved multichannel samples down to mono into existing buffer
+ *
+ * - `data` Interleaved samples (`l r l r ...` for stereo)
+ * - `channels` Number of channels
+ * - `output` Output buffer which should have `data.len() / channels` length
+ */
+pub fn downmix_into(data: &[Smpl], channels: usize, output: &mut [Smpl]) -> Status {
+    check_interleaved(data, channels)?;
+
+    if output.len() != data.len() / channels {
+        return Err(Error::MismatchSize);
+    }
+
+    let scale = 1.0 / channels as Smpl;
+
+    for (dst, frame) in output.iter_mut().zip(data.chunks_exact(channels)) {
+        *dst = frame.iter().sum::<Smpl>() * scale;
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_overlap() {
+        let mut data = [0.; 16];
+        let (norm, phas) = data.split_at_mut(8);
+        let spectrum = CVecMut::from_parts(norm, phas).unwrap();
+
+        // aliasing view of the same data
+        let compspec = unsafe { core::slice::from_raw_parts(spectrum.cvec.norm, 14) };
+
+        assert_eq!(
+            FVec::from(&compspec[4..]).check_overlap(&spectrum),
+            Err(Error::InvalidArg)
+        );
+        assert_eq!(
+            crate::FFT::get_spectrum(compspec, spectrum),
+            Err(Error::InvalidArg)
+        );
+
+        let other = [0.; 16];
+        assert!(FVec::from(&other)
+            .check_overlap(&FVec::from(&compspec[..0]))
+            .is_ok());
+        assert!(FVec::from(&other)
+            .check_overlap(&FVec::from(compspec))
+            .is_ok());
+    }
+
     #[test]
     #[should_panic]
-    #[cfg(feature = "check-size")]
+    #[cfg(not(feature = "unchecked-size"))]
     fn test_from_fmat_wrong_size() {
         let x: &[&[Smpl]] = &[&[1.0, 2.0], &[4.0, 5.0, 6.0], &[7.0, 8.0, 9.0]];
         let _fmat: FMat<_> = x.into();
@@ -469,11 +2155,51 @@ mod test {
         let x: &[&[Smpl]] = &[&[1.0, 2.0], &[4.0, 5.0], &[7.0, 8.0]];
         let fmat: FMat<_> = x.into();
 
+        #[allow(deprecated)]
         let matrix = fmat.get_vec();
 
         assert_eq!(matrix, vec![&[1.0, 2.0], &[4.0, 5.0], &[7.0, 8.0]]);
     }
 
+    #[test]
+    fn test_from_rows() {
+        let mut fmat =
+            FMat::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+
+        assert_eq!(fmat.height(), 3);
+        assert_eq!(fmat.length(), 2);
+        assert_eq!(fmat.get_sample(2, 1), Ok(6.0));
+        assert_eq!(fmat.row(1), Some(&[3.0, 4.0][..]));
+
+        fmat.rows_mut().for_each(|row| row[0] = 0.0);
+        assert_eq!(fmat.get_sample(1, 0), Ok(0.0));
+        assert_eq!(fmat.into_rows()[0], vec![0.0, 2.0]);
+
+        assert!(FMat::from_rows(vec![vec![1.0, 2.0], vec![3.0]]).is_err());
+    }
+
+    #[test]
+    fn test_rows() {
+        let x: &[&[Smpl]] = &[&[1.0, 2.0], &[4.0, 5.0], &[7.0, 8.0]];
+        let fmat: FMat<_> = x.into();
+
+        assert_eq!(
+            fmat.rows().collect::<Vec<_>>(),
+            vec![&[1.0, 2.0], &[4.0, 5.0], &[7.0, 8.0]]
+        );
+        assert_eq!(fmat.row(1), Some(&[4.0, 5.0][..]));
+        assert_eq!(fmat.row(3), None);
+
+        let mut buf = FMatBuf::new(2, 3);
+        for (index, row) in buf.rows_mut().enumerate() {
+            row.iter_mut().for_each(|value| *value = index as Smpl);
+        }
+        assert_eq!(
+            buf.rows().collect::<Vec<_>>(),
+            vec![&[0.0, 0.0, 0.0], &[1.0, 1.0, 1.0]]
+        );
+    }
+
     #[test]
     fn test_get_sample_fmat_wrong_size() {
         let x: &[&[Smpl]] = &[&[1.0, 2.0], &[4.0, 5.0], &[7.0, 8.0]];
@@ -490,5 +2216,223 @@ mod test {
         {
             let _non_owned_fmat: FMat<()> = unsafe { FMat::from_raw_ptr(fmat.as_ptr()) };
         }
+
+        let view = unsafe { FMat::from_raw_ptr_of(fmat.as_ptr(), &fmat) };
+        assert_eq!(view.row(1), Some(&[4.0, 5.0][..]));
+    }
+
+    #[test]
+    fn test_fmat_buf() {
+        let mut mat = FMatBuf::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        assert_eq!(mat.height(), 2);
+        assert_eq!(mat.length(), 3);
+        assert_eq!(mat.row(1), Some(&[4.0, 5.0, 6.0][..]));
+        assert_eq!(mat.row(2), None);
+
+        mat.row_mut(0).unwrap()[1] = 7.0;
+
+        let copy = mat.clone();
+        drop(mat);
+
+        let fmat: FMat<_> = (&copy).into();
+        assert_eq!(fmat.get_sample(0, 1), Ok(7.0));
+        assert_eq!(fmat.get_sample(1, 2), Ok(6.0));
+
+        assert!(FMatBuf::from_vec(2, 2, vec![1.0]).is_err());
+    }
+
+    #[test]
+    fn test_fmat_buf_modified() {
+        let mut mat = FMatBuf::new(2, 2);
+        let _ = FMatMut::from(&mut mat);
+
+        mat.as_mut_slice().copy_from_slice(&[1.0, 2.0, 3.0, 4.0]);
+        mat.row_mut(1).unwrap()[0] = 5.0;
+
+        let fmat = FMat::from(&mat);
+        assert_eq!(fmat.get_sample(0, 1), Ok(2.0));
+        assert_eq!(fmat.get_sample(1, 0), Ok(5.0));
+        assert_eq!(fmat.row(1), Some(&[5.0, 4.0][..]));
+
+        let mut empty = FMatBuf::new(3, 0);
+        assert_eq!(empty.rows_mut().count(), 3);
+        assert_eq!(empty.rows().count(), 3);
+    }
+
+    #[test]
+    fn test_fmat_buf_table() {
+        let mut mat = FMatBuf::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        // the table of rows is not rebuilt on borrowing
+        let table = mat.as_fmat().fmat.data;
+        assert_eq!(FMat::from(&mat).fmat.data, table);
+        assert_eq!(mat.as_fmat_mut().fmat.data, table);
+
+        let copy = mat.clone();
+        assert_ne!(copy.as_fmat().fmat.data, table);
+
+        mat.as_mut_slice()[2] = 5.0;
+        assert_eq!(mat.as_fmat().row(1), Some(&[5.0, 4.0][..]));
+        drop(mat);
+        assert_eq!(copy.as_fmat().row(1), Some(&[3.0, 4.0][..]));
+    }
+
+    #[test]
+    fn test_fvec_access() {
+        let data = [1.0, 2.0, 3.0];
+        let fvec: FVec = data.as_ref().into();
+        assert_eq!(fvec[1], 2.0);
+        assert_eq!(&fvec[1..], &[2.0, 3.0]);
+        assert_eq!(fvec.into_iter().sum::<Smpl>(), 6.0);
+
+        let mut data = [1.0, 2.0, 3.0];
+        let mut fvec: FVecMut = data.as_mut().into();
+        fvec[0] = 4.0;
+        for x in &mut fvec {
+            *x *= 2.0;
+        }
+        assert_eq!(fvec.as_slice(), &[8.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn test_cvec_bins() {
+        let data = [1.0, 2.0, 3.0, 0.1, 0.2, 0.3];
+        let cvec: CVec = data.as_ref().into();
+        assert_eq!(
+            cvec.bin(1),
+            Some(Bin {
+                norm: 2.0,
+                phas: 0.2
+            })
+        );
+        assert_eq!(cvec.bin(3), None);
+        assert_eq!(cvec.bins().count(), 3);
+        assert_eq!(
+            cvec.bins()
+                .max_by(|a, b| a.norm.partial_cmp(&b.norm).unwrap())
+                .map(|bin| bin.phas),
+            Some(0.3)
+        );
+    }
+
+    #[test]
+    fn test_interleaved() {
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+        let planar = deinterleave(&data, 2).unwrap();
+        assert_eq!(planar.row(0), Some(&[1.0, 3.0, 5.0][..]));
+        assert_eq!(planar.row(1), Some(&[2.0, 4.0, 6.0][..]));
+
+        assert_eq!(downmix(&data, 2).unwrap(), vec![1.5, 3.5, 5.5]);
+        assert_eq!(downmix(&data, 3).unwrap(), vec![2.0, 5.0]);
+
+        assert_eq!(downmix(&data, 4), Err(Error::MismatchSize));
+        assert!(deinterleave(&data, 0).is_err());
+    }
+
+    #[test]
+    fn test_fvec_buf_from_int() {
+        assert_eq!(
+            FVecBuf::from_i16(&[0, 16384, -32768, 32767]).as_slice(),
+            &[0.0, 0.5, -1.0, 32767.0 / 32768.0]
+        );
+        assert_eq!(
+            FVecBuf::from_i32(&[0, 1 << 30, i32::MIN]).as_slice(),
+            &[0.0, 0.5, -1.0]
+        );
+        assert_eq!(
+            FVecBuf::from_u8(&[128, 192, 0]).as_slice(),
+            &[0.0, 0.5, -1.0]
+        );
+
+        let mut buf = FVecBuf::new(4);
+        FVecMut::from(&mut buf)[1] = 1.0;
+        assert_eq!(FVec::from(&buf).as_slice(), &[0.0, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_fvec_buf_collect() {
+        let mut buf: FVecBuf = (0..3).map(|i| i as Smpl).collect();
+        assert_eq!(buf.as_slice(), &[0.0, 1.0, 2.0]);
+
+        buf.extend(&[3.0, 4.0]);
+        buf.extend(std::iter::once(5.0));
+        assert_eq!(buf.size(), 6);
+        assert_eq!(FVec::from(&buf).as_slice()[3..], [3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_fixed_arrays() {
+        let mut input = FArr::<8>::new();
+        input[1] = 1.0;
+        assert_eq!(FArr::<8>::SIZE, 8);
+        assert_eq!(FVec::from(&input).size(), 8);
+        FVecMut::from(&mut input)[2] = 2.0;
+        assert_eq!(&input[..3], &[0.0, 1.0, 2.0]);
+
+        let mut spectrum = CArr::<8>::default();
+        spectrum.norm_mut()[0] = 1.0;
+        spectrum.phas_mut()[4] = 0.5;
+        assert_eq!(spectrum.as_slice().len(), 10);
+
+        let cvec = CVec::from(&spectrum);
+        assert_eq!(cvec.size(), 5);
+        assert_eq!(cvec.norm(), &[1.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(cvec.phas(), &[0.0, 0.0, 0.0, 0.0, 0.5]);
+        assert_eq!(CVecMut::from(&mut spectrum).size(), 5);
+    }
+
+    #[test]
+    fn test_norm_phas_views() {
+        let norm = [1.0, 2.0, 3.0];
+        let view = CVecNorm::new(&norm);
+        assert_eq!(view.size(), 3);
+        assert_eq!(view.norm(), &norm);
+
+        let view = CVecPhas::from(&norm[1..]);
+        assert_eq!(view.phas(), &[2.0, 3.0]);
+
+        let mut data = [0.0; 3];
+        let mut view = CVecNormMut::new(&mut data);
+        view.norm_mut()[1] = 1.0;
+        assert_eq!(view.norm(), &[0.0, 1.0, 0.0]);
+
+        let mut view = CVecPhasMut::from(&mut data);
+        view.phas_mut()[2] = 0.5;
+        assert_eq!(view.phas(), &[0.0, 1.0, 0.5]);
+    }
+
+    #[test]
+    fn test_debug_eq() {
+        let data: Vec<Smpl> = (0..10).map(|i| i as Smpl).collect();
+        let fvec = FVec::from(&data);
+        assert_eq!(
+            format!("{:?}", fvec),
+            "FVec { size: 10, data: [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, ..2 more] }"
+        );
+        assert_eq!(fvec, FVec::from(&data[..]));
+        assert_eq!(fvec, data[..]);
+
+        let mut other = data.clone();
+        other[3] += 1e-4;
+        assert_ne!(fvec, FVec::from(&other));
+        assert!(fvec.approx_eq(&other, 1e-3));
+        assert!(!fvec.approx_eq(&other[1..], 1e-3));
+
+        let spectrum = CVec::from(&[1.0, 2.0, 0.5, 0.25]);
+        assert_eq!(
+            format!("{:?}", spectrum),
+            "CVec { size: 2, norm: [1.0, 2.0], phas: [0.5, 0.25] }"
+        );
+        assert!(spectrum.approx_eq(&[1.0, 2.0, 0.5, 0.2501], 1e-3));
+
+        let rows: Vec<&[Smpl]> = vec![&[1.0, 2.0], &[3.0, 4.0]];
+        let mat = FMat::from(&rows);
+        assert_eq!(
+            format!("{:?}", mat),
+            "FMat { height: 2, length: 2, rows: [[1.0, 2.0], [3.0, 4.0]] }"
+        );
+        let buf = FMatBuf::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        assert_eq!(mat, FMat::from(&buf));
+        assert!(mat.approx_eq(&FMat::from(&buf), 0.0));
     }
 }
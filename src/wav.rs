@@ -0,0 +1,158 @@
+use crate::{vec::downmix_into, MediaSource, Smpl};
+
+use hound::{SampleFormat, WavReader};
+
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use alloc::vec::Vec;
+
+/**
+ * WAV decoder based on _hound_
+ *
+ * Reads integer or floating-point WAV files, down-mixes channels and slices
+ * the signal into frames of `hop_size` samples.
+ */
+pub struct WavSource<R> {
+    reader: WavReader<R>,
+    sample_rate: u32,
+    channels: usize,
+    format: SampleFormat,
+    scale: f64,
+    hop_size: usize,
+    samples: Vec<Smpl>,
+    frame: Vec<Smpl>,
+}
+
+impl WavSource<BufReader<File>> {
+    /**
+     * Open WAV file
+     *
+     * - `path` Path to WAV file
+     * - `hop_size` Number of samples in each frame
+     */
+    pub fn open(path: impl AsRef<Path>, hop_size: usize) -> Result<Self, hound::Error> {
+        Ok(Self::new(WavReader::open(path)?, hop_size))
+    }
+}
+
+impl<R: Read> WavSource<R> {
+    /**
+     * Create decoder using opened reader
+     *
+     * - `reader` WAV reader
+     * - `hop_size` Number of samples in each frame
+     */
+    pub fn new(reader: WavReader<R>, hop_size: usize) -> Self {
+        let spec = reader.spec();
+
+        Self {
+            reader,
+            sample_rate: spec.sample_rate,
+            channels: (spec.channels as usize).max(1),
+            format: spec.sample_format,
+            scale: (1u64 << (spec.bits_per_sample.clamp(1, 32) - 1)) as f64,
+            hop_size,
+            samples: Vec::with_capacity(hop_size * spec.channels as usize),
+            frame: Vec::with_capacity(hop_size),
+        }
+    }
+
+    /**
+     * Get hop size
+     */
+    pub fn get_hop(&self) -> usize {
+        self.hop_size
+    }
+
+    /**
+     * Unwrap reader
+     */
+    pub fn into_inner(self) -> WavReader<R> {
+        self.reader
+    }
+}
+
+impl<R: Read> MediaSource for WavSource<R> {
+    type Error = hound::Error;
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /**
+     * Read the next frame of `hop_size` samples
+     *
+     * The last frame can be shorter.
+     */
+    fn read_frame(&mut self) -> Result<Option<&[Smpl]>, Self::Error> {
+        let length = self.hop_size * self.channels;
+
+        self.samples.clear();
+        match self.format {
+            SampleFormat::Float => read_samples(
+                self.reader.samples::<f32>().take(length),
+                1.0,
+                &mut self.samples,
+            )?,
+            SampleFormat::Int => read_samples(
+                self.reader.samples::<i32>().take(length),
+                self.scale,
+                &mut self.samples,
+            )?,
+        }
+
+        // the incomplete trailing frame is dropped
+        let length = self.samples.len() - self.samples.len() % self.channels;
+        self.frame.resize(length / self.channels, 0.0);
+        downmix_into(&self.samples[..length], self.channels, &mut self.frame)
+            .expect("samples are interleaved");
+
+        Ok(if self.frame.is_empty() {
+            None
+        } else {
+            Some(&self.frame)
+        })
+    }
+}
+
+/**
+ * Convert samples to `Smpl` and append it to `output`
+ */
+fn read_samples<S, I>(samples: I, scale: f64, output: &mut Vec<Smpl>) -> hound::Result<()>
+where
+    S: Into<f64>,
+    I: Iterator<Item = hound::Result<S>>,
+{
+    for sample in samples {
+        output.push((sample?.into() / scale) as Smpl);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::read_samples;
+
+    #[test]
+    fn test_read_samples() {
+        let mut output = Vec::new();
+
+        read_samples(
+            [16384i32, 0, -32768].iter().map(|&sample| Ok(sample)),
+            32768.0,
+            &mut output,
+        )
+        .unwrap();
+        read_samples([0.25f32].iter().map(|&sample| Ok(sample)), 1.0, &mut output).unwrap();
+        assert_eq!(output, vec![0.5, 0.0, -1.0, 0.25]);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{check_params, ffi, try_init, vec::CVecMut, Result, Smpl, Status};
+
+/**
+ * Spectral adaptive whitening object
+ *
+ * This object computes a running peak of each spectral bin and normalizes
+ * the input spectrum by that peak, so that every frequency band contributes
+ * evenly to the subsequent detection functions.
+ *
+ * The relaxation time and the floor parameters allow to tune the whitening
+ * for percussive or sustained material.
+ *
+ * __Note__: The `Onset` object uses its own internal whitening with default
+ * parameters of the C library when enabled via `Onset::set_awhitening()`,
+ * until the library exposes the setters for it. This object should be used
+ * to apply the whitening with custom parameters on spectral frames computed
+ * by `PVoc` before passing them to `SpecDesc`.
+ *
+ * Dan Stowell and Mark D. Plumbley, "Adaptive whitening for improved real-time audio onset detection", International Computer Music Conference (ICMC), 2007.
+ */
+pub struct SpectralWhitening {
+    whitening: *mut ffi::aubio_spectral_whitening_t,
+    buf_size: usize,
+    hop_size: usize,
+    sample_rate: u32,
+}
+
+impl Drop for SpectralWhitening {
+    fn drop(&mut self) {
+        unsafe { ffi::del_aubio_spectral_whitening(self.whitening) }
+    }
+}
+
+unsafe impl Send for SpectralWhitening {}
+unsafe impl Sync for SpectralWhitening {}
+
+impl SpectralWhitening {
+    /**
+     * Create spectral whitening object
+     *
+     * - `buf_size` Window size of input grains
+     * - `hop_size` Number of samples between two consecutive input grains
+     * - `sample_rate` Sampling rate of the input signal
+     */
+    pub fn new(buf_size: usize, hop_size: usize, sample_rate: u32) -> Result<Self> {
+        check_params(buf_size, hop_size, sample_rate)?;
+
+        let whitening = try_init(|| unsafe {
+            ffi::new_aubio_spectral_whitening(
+                buf_size as ffi::uint_t,
+                hop_size as ffi::uint_t,
+                sample_rate as ffi::uint_t,
+            )
+        })?;
+
+        Ok(Self {
+            whitening,
+            buf_size,
+            hop_size,
+            sample_rate,
+        })
+    }
+
+    /**
+     * Create new spectral whitening object with the same parameters
+     *
+     * The state of analysis is not copied.
+     */
+    pub fn try_clone(&self) -> Result<Self> {
+        Ok(Self::new(self.buf_size, self.hop_size, self.sample_rate)?
+            .with_relax_time(self.get_relax_time())
+            .with_floor(self.get_floor()))
+    }
+
+    /**
+     * Set relaxation time in seconds
+     */
+    pub fn with_relax_time(mut self, relax_time: Smpl) -> Self {
+        self.set_relax_time(relax_time);
+        self
+    }
+
+    /**
+     * Set floor value
+     */
+    pub fn with_floor(mut self, floor: Smpl) -> Self {
+        self.set_floor(floor);
+        self
+    }
+
+    /**
+     * Execute spectral adaptive whitening, in-place
+     *
+     * - `fftgrain` Input signal spectrum as computed by `PVoc::do_()` or `FFT::do_()`
+     */
+    pub fn do_<'io, IO>(&mut self, fftgrain: IO) -> Status
+    where
+        IO: Into<CVecMut<'io>>,
+    {
+        let mut fftgrain = fftgrain.into();
+
+        fftgrain.check_size(self.buf_size)?;
+
+        unsafe { ffi::aubio_spectral_whitening_do(self.whitening, fftgrain.as_mut_ptr()) }
+        Ok(())
+    }
+
+    /**
+     * Reset spectral whitening
+     */
+    pub fn reset(&mut self) {
+        unsafe {
+            ffi::aubio_spectral_whitening_reset(self.whitening);
+        }
+    }
+
+    /**
+     * Set relaxation time in seconds
+     *
+     * Typically between 20 and 500, defaults to 250.
+     */
+    pub fn set_relax_time(&mut self, relax_time: Smpl) {
+        unsafe {
+            ffi::aubio_spectral_whitening_set_relax_time(self.whitening, relax_time);
+        }
+    }
+
+    /**
+     * Get relaxation time in seconds
+     */
+    pub fn get_relax_time(&self) -> Smpl {
+        unsafe { ffi::aubio_spectral_whitening_get_relax_time(self.whitening) }
+    }
+
+    /**
+     * Set floor value
+     *
+     * Typically between 1e-6 and 0.2, defaults to 1e-4.
+     */
+    pub fn set_floor(&mut self, floor: Smpl) {
+        unsafe {
+            ffi::aubio_spectral_whitening_set_floor(self.whitening, floor);
+        }
+    }
+
+    /**
+     * Get floor value
+     */
+    pub fn get_floor(&self) -> Smpl {
+        unsafe { ffi::aubio_spectral_whitening_get_floor(self.whitening) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_params() {
+        let whitening = SpectralWhitening::new(1024, 512, 44100).unwrap();
+        assert_eq!(whitening.get_relax_time(), 250.0);
+        assert_eq!(whitening.get_floor(), 1e-4);
+
+        let whitening = whitening.with_relax_time(50.0).with_floor(0.01);
+        assert_eq!(whitening.get_relax_time(), 50.0);
+        assert_eq!(whitening.get_floor(), 0.01);
+
+        let clone = whitening.try_clone().unwrap();
+        assert_eq!(clone.get_relax_time(), 50.0);
+        assert_eq!(clone.get_floor(), 0.01);
+    }
+
+    #[test]
+    fn test_floor() {
+        let mut whitening = SpectralWhitening::new(8, 4, 44100).unwrap();
+        let mut grain = carr!(8);
+        grain[..3].copy_from_slice(&[2.0, 0.5, 1e-6]);
+        whitening.do_(grain.as_mut()).unwrap();
+        // the bins below floor is normalized by floor
+        assert!(grain[..3]
+            .iter()
+            .zip(&[1.0, 1.0, 0.01])
+            .all(|(norm, expected)| (norm - expected).abs() < 1e-4));
+
+        let mut whitening = SpectralWhitening::new(8, 4, 44100).unwrap().with_floor(1.0);
+        let mut grain = carr!(8);
+        grain[..3].copy_from_slice(&[2.0, 0.5, 1e-6]);
+        whitening.do_(grain.as_mut()).unwrap();
+        assert!(grain[..3]
+            .iter()
+            .zip(&[1.0, 0.5, 1e-6])
+            .all(|(norm, expected)| (norm - expected).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_relax_time() {
+        // normalized value of quiet frame after loud one
+        let relax = |relax_time| {
+            let mut whitening = SpectralWhitening::new(8, 8, 100)
+                .unwrap()
+                .with_relax_time(relax_time);
+            let mut grain = carr!(8);
+            grain[0] = 1.0;
+            whitening.do_(grain.as_mut()).unwrap();
+            grain[0] = 0.1;
+            whitening.do_(grain.as_mut()).unwrap();
+            grain[0]
+        };
+
+        // the peak decays quickly, so the quiet frame is normalized by itself
+        assert!((relax(0.1) - 1.0).abs() < 1e-4);
+        // the peak holds, so the quiet frame stays quiet
+        assert!((relax(500.0) - 0.1).abs() < 1e-3);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{ffi, vec::FVecMut, AsNativeStr, Error, Result};
 
-use std::{
+use core::{
     fmt::{Display, Formatter, Result as FmtResult},
     str::FromStr,
 };
@@ -17,6 +17,8 @@ use std::{
  *   [pdf](http://www.cs.princeton.edu/courses/archive/spr09/cos325/Bernardini.pdf)
  */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum WindowType {
     Ones,
     Rectangle,
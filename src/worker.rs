@@ -0,0 +1,147 @@
+use crate::{Analyzer, Error, Result, Smpl, StreamingAnalyzer};
+
+use std::{
+    sync::mpsc::{sync_channel, Sender},
+    thread,
+};
+
+use alloc::vec::Vec;
+
+/**
+ * Event which is sent by analysis worker
+ */
+pub(crate) trait WorkerEvent<T> {
+    /**
+     * Result of analysis of frame at `position` in samples
+     */
+    fn frame(position: usize, output: T) -> Self;
+
+    /**
+     * Analysis failed, no more frames will be delivered
+     */
+    fn error(error: Error) -> Self;
+}
+
+/**
+ * Receiving side of channel which passes the samples to analysis worker
+ */
+pub(crate) trait WorkerInput: Sized + Send + 'static {
+    type Sender: Send + 'static;
+
+    /**
+     * Create channel on worker thread
+     */
+    fn channel(hop_size: usize, sample_rate: u32) -> (Self::Sender, Self);
+
+    /**
+     * Wait for the next samples
+     *
+     * Returns `false` when the sender is dropped.
+     */
+    fn read(&mut self, samples: &mut Vec<Smpl>) -> bool;
+}
+
+#[cfg(any(feature = "cpal", feature = "portaudio"))]
+impl WorkerInput for crate::ring::Consumer {
+    type Sender = crate::ring::Producer;
+
+    fn channel(hop_size: usize, sample_rate: u32) -> (Self::Sender, Self) {
+        // at least one second of signal
+        crate::ring::channel((hop_size * 16).max(sample_rate as usize))
+    }
+
+    fn read(&mut self, samples: &mut Vec<Smpl>) -> bool {
+        crate::ring::Consumer::read(self, samples)
+    }
+}
+
+#[cfg(feature = "rodio")]
+impl WorkerInput for std::sync::mpsc::Receiver<Vec<Smpl>> {
+    type Sender = Sender<Vec<Smpl>>;
+
+    fn channel(_hop_size: usize, _sample_rate: u32) -> (Self::Sender, Self) {
+        std::sync::mpsc::channel()
+    }
+
+    fn read(&mut self, samples: &mut Vec<Smpl>) -> bool {
+        match self.recv() {
+            Ok(data) => {
+                *samples = data;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+/**
+ * Create analyzer on dedicated worker thread
+ *
+ * - `sample_rate` Sampling rate which is passed to factory
+ * - `factory` Function which creates the analyzer
+ * - `events` Sender of analysis events
+ *
+ * The analyzer is created and used by worker thread only, so it does not need
+ * to be `Send`. The worker analyzes the samples until the returned sender is dropped,
+ * then flushes the trailing samples.
+ *
+ * Returns the sender of mono samples with the hop size of analyzer.
+ */
+pub(crate) fn spawn_worker<A, F, I, E>(
+    sample_rate: u32,
+    factory: F,
+    events: Sender<E>,
+) -> Result<(I::Sender, usize)>
+where
+    A: Analyzer,
+    F: FnOnce(u32) -> Result<A> + Send + 'static,
+    I: WorkerInput,
+    E: WorkerEvent<A::Output> + Send + 'static,
+{
+    let (ready_tx, ready_rx) = sync_channel(1);
+
+    thread::spawn(move || {
+        let analyzer = match factory(sample_rate) {
+            Ok(analyzer) => analyzer,
+            Err(error) => {
+                let _ = ready_tx.send(Err(error));
+                return;
+            }
+        };
+
+        let hop_size = analyzer.hop_size();
+        let (samples_tx, mut samples_rx) = I::channel(hop_size, sample_rate);
+        let _ = ready_tx.send(Ok((samples_tx, hop_size)));
+
+        let mut analyzer = StreamingAnalyzer::new(analyzer);
+        let mut samples = Vec::new();
+
+        while samples_rx.read(&mut samples) {
+            let result = analyzer.push_with_position(&samples, |position, output| {
+                let _ = events.send(E::frame(position, output));
+            });
+
+            if let Err(error) = result {
+                let _ = events.send(E::error(error));
+                return;
+            }
+        }
+
+        let position = analyzer.get_position();
+        match analyzer.flush() {
+            Ok(Some(output)) => {
+                let _ = events.send(E::frame(position, output));
+            }
+            Ok(None) => {}
+            Err(error) => {
+                let _ = events.send(E::error(error));
+            }
+        }
+    });
+
+    match ready_rx.recv() {
+        Ok(Ok((samples, hop_size))) => Ok((samples, hop_size.max(1))),
+        Ok(Err(error)) => Err(error),
+        Err(_) => Err(Error::FailedInit),
+    }
+}
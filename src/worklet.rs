@@ -0,0 +1,209 @@
+use crate::{Analyzer, Result, Smpl, StreamingAnalyzer};
+
+use alloc::vec::Vec;
+
+/**
+ * Number of frames in single block of Web Audio rendering
+ */
+pub const RENDER_QUANTUM: usize = 128;
+
+/**
+ * Encoding of analysis result as fixed number of numbers
+ */
+pub trait EventEncoding {
+    /**
+     * Number of values produced by `encode()`
+     */
+    const WIDTH: usize;
+
+    /**
+     * Append encoded values to output
+     */
+    fn encode(&self, output: &mut Vec<f64>);
+}
+
+impl EventEncoding for f32 {
+    const WIDTH: usize = 1;
+
+    fn encode(&self, output: &mut Vec<f64>) {
+        output.push(*self as f64);
+    }
+}
+
+impl EventEncoding for f64 {
+    const WIDTH: usize = 1;
+
+    fn encode(&self, output: &mut Vec<f64>) {
+        output.push(*self);
+    }
+}
+
+impl<T: EventEncoding> EventEncoding for Option<T> {
+    const WIDTH: usize = T::WIDTH + 1;
+
+    /**
+     * The presence flag (`1` or `0`) followed by value (or zeros)
+     */
+    fn encode(&self, output: &mut Vec<f64>) {
+        match self {
+            Some(value) => {
+                output.push(1.0);
+                value.encode(output);
+            }
+            None => output.extend((0..Self::WIDTH).map(|_| 0.0)),
+        }
+    }
+}
+
+/**
+ * Adapter of analyzer for _AudioWorklet_ processors
+ *
+ * Accepts the blocks of `RENDER_QUANTUM` frames passed to `process()` method
+ * of worklet, rebuffers it to the hop size of analyzer and encodes the results
+ * into flat array of numbers which can be posted to the main thread as
+ * `Float64Array` without serialization overhead.
+ *
+ * Each event occupies `get_stride()` numbers: the position of frame in samples
+ * followed by `EventEncoding::WIDTH` values of result.
+ *
+ * ```js
+ * const stride = analyzer.stride();
+ * for (let i = 0; i < events.length; i += stride) {
+ *     const position = events[i], value = events[i + 1];
+ * }
+ * ```
+ */
+pub struct WorkletAnalyzer<A: Analyzer> {
+    analyzer: StreamingAnalyzer<A>,
+    mono: Vec<Smpl>,
+    events: Vec<f64>,
+}
+
+impl<A> WorkletAnalyzer<A>
+where
+    A: Analyzer,
+    A::Output: EventEncoding,
+{
+    /**
+     * Wrap analyzer
+     */
+    pub fn new(analyzer: A) -> Self {
+        Self {
+            analyzer: StreamingAnalyzer::new(analyzer),
+            mono: Vec::with_capacity(RENDER_QUANTUM),
+            events: Vec::new(),
+        }
+    }
+
+    /**
+     * Get number of values per event
+     */
+    pub fn get_stride(&self) -> usize {
+        1 + A::Output::WIDTH
+    }
+
+    /**
+     * Process single block of mono input
+     *
+     * Returns the number of events produced by this block. The events are
+     * available using `WorkletAnalyzer::get_events()` until the next call.
+     */
+    pub fn process(&mut self, input: &[Smpl]) -> Result<usize> {
+        self.events.clear();
+
+        let mut count = 0;
+        let events = &mut self.events;
+
+        self.analyzer
+            .push_with_position(input, |position, output| {
+                events.push(position as f64);
+                output.encode(events);
+                count += 1;
+            })?;
+
+        Ok(count)
+    }
+
+    /**
+     * Process single block of multichannel input
+     *
+     * The channels are down-mixed before analysis. Missing input (empty list
+     * of channels, i.e. disconnected input) is processed as silence.
+     */
+    pub fn process_channels(&mut self, channels: &[&[Smpl]]) -> Result<usize> {
+        let mut mono = core::mem::take(&mut self.mono);
+        mono.clear();
+
+        match channels {
+            [] => mono.resize(RENDER_QUANTUM, 0.0),
+            [channel] => mono.extend_from_slice(channel),
+            _ => {
+                let length = channels.iter().map(|channel| channel.len()).min().unwrap();
+                let scale = 1.0 / channels.len() as Smpl;
+                mono.extend((0..length).map(|index| {
+                    channels.iter().map(|channel| channel[index]).sum::<Smpl>() * scale
+                }));
+            }
+        }
+
+        let result = self.process(&mono);
+        self.mono = mono;
+        result
+    }
+
+    /**
+     * Get events produced by the last processed block
+     */
+    pub fn get_events(&self) -> &[f64] {
+        &self.events
+    }
+
+    /**
+     * Get reference to wrapped analyzer
+     */
+    pub fn get_ref(&self) -> &A {
+        self.analyzer.get_ref()
+    }
+
+    /**
+     * Get mutable reference to wrapped analyzer
+     */
+    pub fn get_mut(&mut self) -> &mut A {
+        self.analyzer.get_mut()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::testing::Sum;
+    use crate::*;
+
+    #[test]
+    fn test_worklet() {
+        let mut worklet = WorkletAnalyzer::new(Sum(256));
+        assert_eq!(worklet.get_stride(), 2);
+
+        assert_eq!(worklet.process(&[1.0; RENDER_QUANTUM]).unwrap(), 0);
+        assert_eq!(worklet.get_events(), &[] as &[f64]);
+
+        assert_eq!(
+            worklet
+                .process_channels(&[&[1.0; RENDER_QUANTUM], &[0.0; RENDER_QUANTUM]])
+                .unwrap(),
+            1
+        );
+        assert_eq!(worklet.get_events(), &[0.0, 192.0]);
+
+        assert_eq!(worklet.process_channels(&[]).unwrap(), 0);
+        assert_eq!(worklet.process_channels(&[]).unwrap(), 1);
+        assert_eq!(worklet.get_events(), &[256.0, 0.0]);
+    }
+
+    #[test]
+    fn test_encoding() {
+        let mut output = Vec::new();
+        Some(0.5f32).encode(&mut output);
+        None::<f32>.encode(&mut output);
+        assert_eq!(output, vec![1.0, 0.5, 0.0, 0.0]);
+    }
+}
@@ -62,7 +62,7 @@ fn main() {
         }
     }
 
-    if !try_find_and_use_library() {
+    if target_is_wasm() || !try_find_and_use_library() {
         let lib_dir = out_dir;
 
         build_library(src_dir, lib_dir);
@@ -77,7 +77,7 @@ fn generate_bindings<P: AsRef<Path>>(
     out_file: impl AsRef<Path>,
     double: bool,
 ) {
-    let bindings = bindgen::Builder::default()
+    let mut builder = bindgen::Builder::default()
         .detect_include_paths(true)
         .clang_args(
             inc_dirs
@@ -85,9 +85,14 @@ fn generate_bindings<P: AsRef<Path>>(
                 .map(|dir| format!("-I{}", dir.as_ref().display())),
         )
         .clang_arg(if double { "-DHAVE_AUBIO_DOUBLE" } else { "" })
-        .header_contents("library.h", "#include <aubio.h>")
-        .generate()
-        .expect("Generated bindings.");
+        .header_contents("library.h", "#include <aubio.h>");
+
+    if cfg!(feature = "no_std") {
+        // the pre-generated bindings refers to std so generate it using core
+        builder = builder.use_core().ctypes_prefix("::core::ffi");
+    }
+
+    let bindings = builder.generate().expect("Generated bindings.");
 
     bindings.write_to_file(out_file).expect("Written bindings.");
 }
@@ -117,6 +122,34 @@ fn update_bindings(bind_file: impl AsRef<Path>, dest_file: impl AsRef<Path>, dou
     }
 }
 
+fn target_is_wasm() -> bool {
+    use std::env;
+
+    // the bare wasm target has no system libraries and headers
+    env::var("CARGO_CFG_TARGET_ARCH").unwrap() == "wasm32"
+        && env::var("CARGO_CFG_TARGET_OS").unwrap() == "unknown"
+}
+
+fn wasm_sysroot() -> std::path::PathBuf {
+    use std::env;
+
+    println!("cargo:rerun-if-env-changed=WASI_SYSROOT");
+
+    env::var_os("WASI_SYSROOT")
+        .map(Into::into)
+        .unwrap_or_else(|| "/opt/wasi-sdk/share/wasi-sysroot".into())
+}
+
+fn new_build() -> cc::Build {
+    let mut build = cc::Build::new();
+
+    if target_is_wasm() {
+        build.flag(format!("--sysroot={}", wasm_sysroot().display()));
+    }
+
+    build
+}
+
 fn add_lib(name: &str, static_: bool) {
     println!(
         "cargo:rustc-link-lib={}{}",
@@ -242,7 +275,7 @@ fn build_library(src_dir: &Path, lib_dir: &Path) {
         let tmp_src = lib_dir.join("_tmp_.c");
         write(&tmp_src, code.as_ref()).unwrap();
 
-        let mut build = cc::Build::new();
+        let mut build = new_build();
         build.file(&tmp_src);
         with(&mut build);
         let has = build.try_compile("_tmp_").is_ok();
@@ -278,7 +311,7 @@ fn build_library(src_dir: &Path, lib_dir: &Path) {
         has
     }
 
-    let mut build = cc::Build::new();
+    let mut build = new_build();
 
     let _target_vendor = env::var("CARGO_CFG_TARGET_VENDOR").unwrap();
     let _target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap();
@@ -514,4 +547,10 @@ fn build_library(src_dir: &Path, lib_dir: &Path) {
     build.static_flag(true);
 
     build.compile(LIB_NAME);
+
+    if target_is_wasm() {
+        // the C library depends on libc from the WASI sysroot
+        add_lib_path(wasm_sysroot().join("lib").join("wasm32-wasi"));
+        add_lib("c", true);
+    }
 }
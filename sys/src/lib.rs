@@ -1,4 +1,5 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(feature = "no_std", no_std)]
 #![allow(
     non_upper_case_globals,
     non_camel_case_types,
@@ -0,0 +1,251 @@
+/*!
+ * Golden-output tests against reference results of _aubio_ command line tools
+ *
+ * Each `tests/golden/<name>.wav` fixture can be accompanied by reference
+ * outputs generated once by `tests/golden/generate.sh`:
+ *
+ * - `<name>.onset.txt` Output of `aubioonset` (one time per line)
+ * - `<name>.beat.txt` Output of `aubiotrack` (one time per line)
+ * - `<name>.pitch.txt` Output of `aubiopitch` (time and frequency per line)
+ *
+ * The results of wrappers should reproduce the references within tolerance,
+ * so the regressions in FFI layer and build flags of _aubio_ C library
+ * are caught.
+ */
+
+use aubio::{
+    offline::{detect_onsets, track_beats, OnsetOptions, TempoOptions},
+    Pitch, PitchMode, Smpl,
+};
+
+use hound::{SampleFormat, WavReader};
+
+use std::{
+    fs::{read_dir, read_to_string},
+    path::{Path, PathBuf},
+};
+
+/// The buffer and hop sizes of `aubiopitch` defaults
+const PITCH_BUF_SIZE: usize = 2048;
+const PITCH_HOP_SIZE: usize = 256;
+
+/// Max relative difference of pitches in voiced frames
+const PITCH_TOLERANCE: Smpl = 0.01;
+
+/// Max ratio of frames which pitches does not match
+const PITCH_MISMATCH: Smpl = 0.05;
+
+fn fixtures() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+
+    let mut paths = read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().map(|ext| ext == "wav").unwrap_or(false))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    paths.sort();
+    paths
+}
+
+/**
+ * Read reference output if exists
+ */
+fn reference(wav: &Path, kind: &str) -> Option<Vec<Vec<Smpl>>> {
+    let path = wav.with_extension(format!("{}.txt", kind));
+    let text = read_to_string(path).ok()?;
+    Some(parse_rows(&text))
+}
+
+fn parse_rows(text: &str) -> Vec<Vec<Smpl>> {
+    text.lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|value| value.parse().expect("invalid number in reference"))
+                .collect::<Vec<Smpl>>()
+        })
+        .filter(|row| !row.is_empty())
+        .collect()
+}
+
+/**
+ * Load signal down-mixed to mono
+ */
+fn load(path: &Path) -> (Vec<Smpl>, u32) {
+    let mut reader = WavReader::open(path).expect("unable to open fixture");
+    let spec = reader.spec();
+    let channels = spec.channels as usize;
+
+    let samples: Vec<Smpl> = match spec.sample_format {
+        SampleFormat::Float => reader
+            .samples::<f32>()
+            .map(|sample| sample.unwrap() as Smpl)
+            .collect(),
+        SampleFormat::Int => {
+            let scale = (1u64 << (spec.bits_per_sample - 1)) as Smpl;
+            reader
+                .samples::<i32>()
+                .map(|sample| sample.unwrap() as Smpl / scale)
+                .collect()
+        }
+    };
+
+    let mono = samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<Smpl>() / frame.len() as Smpl)
+        .collect();
+
+    (mono, spec.sample_rate)
+}
+
+/**
+ * Check that each event matches the reference time within tolerance
+ */
+fn assert_times(name: &str, kind: &str, actual: &[Smpl], expected: &[Smpl], tolerance: Smpl) {
+    assert_eq!(
+        actual.len(),
+        expected.len(),
+        "{}: number of {} events differs: {:?} != {:?}",
+        name,
+        kind,
+        actual,
+        expected
+    );
+
+    for (actual, expected) in actual.iter().zip(expected) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{}: {} at {} does not match reference {}",
+            name,
+            kind,
+            actual,
+            expected
+        );
+    }
+}
+
+fn pitches_match(actual: Smpl, expected: Smpl) -> bool {
+    if expected <= 0.0 || actual <= 0.0 {
+        expected <= 0.0 && actual <= 0.0
+    } else {
+        (actual - expected).abs() <= expected * PITCH_TOLERANCE
+    }
+}
+
+fn check_onsets(name: &str, samples: &[Smpl], sample_rate: u32, expected: &[Vec<Smpl>]) {
+    let options = OnsetOptions::default();
+    let tolerance = options.hop_size as Smpl / sample_rate as Smpl;
+    let actual = detect_onsets(samples, sample_rate, options)
+        .unwrap()
+        .iter()
+        .map(|onset| onset.time)
+        .collect::<Vec<_>>();
+    let expected = expected.iter().map(|row| row[0]).collect::<Vec<_>>();
+
+    assert_times(name, "onset", &actual, &expected, tolerance);
+}
+
+fn check_beats(name: &str, samples: &[Smpl], sample_rate: u32, expected: &[Vec<Smpl>]) {
+    let options = TempoOptions::default();
+    let tolerance = options.hop_size as Smpl / sample_rate as Smpl;
+    let actual = track_beats(samples, sample_rate, options)
+        .unwrap()
+        .beats
+        .iter()
+        .map(|beat| beat.time)
+        .collect::<Vec<_>>();
+    let expected = expected.iter().map(|row| row[0]).collect::<Vec<_>>();
+
+    assert_times(name, "beat", &actual, &expected, tolerance);
+}
+
+fn check_pitches(name: &str, samples: &[Smpl], sample_rate: u32, expected: &[Vec<Smpl>]) {
+    let mut pitch = Pitch::new(
+        PitchMode::default(),
+        PITCH_BUF_SIZE,
+        PITCH_HOP_SIZE,
+        sample_rate,
+    )
+    .unwrap();
+
+    let actual = samples
+        .chunks(PITCH_HOP_SIZE)
+        .map(|chunk| {
+            let mut frame = [0.0; PITCH_HOP_SIZE];
+            frame[..chunk.len()].copy_from_slice(chunk);
+            pitch.do_result(frame.as_ref()).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // the last partial frame may be omitted by command line tool
+    assert!(
+        (actual.len() as isize - expected.len() as isize).abs() <= 1,
+        "{}: number of pitch frames differs: {} != {}",
+        name,
+        actual.len(),
+        expected.len()
+    );
+
+    let frames = actual.len().min(expected.len());
+    let mismatches = actual
+        .iter()
+        .zip(expected)
+        .filter(|(actual, expected)| !pitches_match(**actual, expected[1]))
+        .count();
+
+    assert!(
+        mismatches as Smpl <= frames as Smpl * PITCH_MISMATCH,
+        "{}: {} of {} pitch frames does not match reference",
+        name,
+        mismatches,
+        frames
+    );
+}
+
+#[test]
+#[ignore = "requires fixtures generated by tests/golden/generate.sh"]
+fn golden_outputs() {
+    let fixtures = fixtures();
+
+    assert!(
+        !fixtures.is_empty(),
+        "no golden fixtures found, run tests/golden/generate.sh to create it"
+    );
+
+    for path in fixtures {
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        let (samples, sample_rate) = load(&path);
+
+        if let Some(expected) = reference(&path, "onset") {
+            check_onsets(&name, &samples, sample_rate, &expected);
+        }
+
+        if let Some(expected) = reference(&path, "beat") {
+            check_beats(&name, &samples, sample_rate, &expected);
+        }
+
+        if let Some(expected) = reference(&path, "pitch") {
+            check_pitches(&name, &samples, sample_rate, &expected);
+        }
+    }
+}
+
+#[test]
+fn reference_parsing() {
+    assert_eq!(
+        parse_rows("0.000000\n0.500000\n\n"),
+        vec![vec![0.0], vec![0.5]]
+    );
+    assert_eq!(
+        parse_rows("0.005805 440.000000\n"),
+        vec![vec![0.005805, 440.0]]
+    );
+
+    assert!(pitches_match(440.0, 442.0));
+    assert!(pitches_match(0.0, -1.0));
+    assert!(!pitches_match(0.0, 440.0));
+    assert!(!pitches_match(220.0, 440.0));
+}
@@ -0,0 +1,29 @@
+#!/bin/sh
+#
+# Generate fixtures and reference outputs for golden tests
+#
+# Requires `sox` and _aubio_ command line tools (`aubioonset`, `aubiotrack`,
+# `aubiopitch`) built with the same _aubio_ version as bundled by `aubio-sys`.
+# The defaults of tools match the defaults of `OnsetOptions`, `TempoOptions`
+# and the pitch sizes used by tests, so do not pass any options here.
+#
+# Run it once and commit the generated files.
+
+set -e
+
+cd "$(dirname "$0")"
+
+# sine tone for pitch detection
+sox -n -r 44100 -b 16 -c 1 sine-a4.wav synth 2 sine 440
+# plucks at 120 BPM for onset detection and beat tracking
+sox -n -r 44100 -b 16 -c 1 pluck.wav synth 0.1 pluck 440 pad 0 0.4 repeat 15
+# stereo plucks to check down-mixing
+sox -n -r 44100 -b 16 -c 2 pluck-stereo.wav synth 0.1 pluck 330 pluck 660 pad 0 0.4 repeat 15
+
+for wav in *.wav; do
+    name="${wav%.wav}"
+    aubioonset -i "$wav" > "$name.onset.txt"
+    aubiotrack -i "$wav" > "$name.beat.txt"
+done
+
+aubiopitch -i sine-a4.wav > sine-a4.pitch.txt